@@ -274,7 +274,7 @@ impl Gateway {
                     },
                     _ => {
                         return Err(GatewayError::InvalidPayload {
-                            opcode: payload.op as u8,
+                            opcode: payload.op.into(),
                             message: "Expected Hello opcode as first message".to_string(),
                         })
                     }
@@ -295,17 +295,13 @@ impl Gateway {
         info!("Trying to identify with Gateway");
         let payload_data = IdentifyPayloadData {
             token: token.to_owned(),
-            properties: IdentifyConnectionProperties {
-                os: "linux".to_owned(),
-                browser: "disruption".to_owned(),
-                device: "disruption".to_owned(),
-            },
+            properties: IdentifyConnectionProperties::new("linux", "disruption", "disruption"),
             // TODO: Think about useful intents
-            intents: Intents::GUILD_MEMBERS as u64
-                | Intents::GUILD_MESSAGES as u64
-                | Intents::GUILD_MESSAGE_REACTIONS as u64
-                | Intents::DIRECT_MESSAGES as u64
-                | Intents::MESSAGE_CONTENT as u64,
+            intents: Intents::GUILD_MEMBERS
+                | Intents::GUILD_MESSAGES
+                | Intents::GUILD_MESSAGE_REACTIONS
+                | Intents::DIRECT_MESSAGES
+                | Intents::MESSAGE_CONTENT,
             ..Default::default()
         };
 
@@ -332,11 +328,7 @@ impl Gateway {
             session_id, seq_num
         );
 
-        let payload_data = ResumePayloadData {
-            token: token.to_owned(),
-            session_id: session_id.to_owned(),
-            seq: seq_num,
-        };
+        let payload_data = ResumePayloadData::new(token, session_id, seq_num);
 
         let payload = Payload {
             op: GatewayOpcode::Resume,
@@ -0,0 +1,265 @@
+//! URLs of images and other assets hosted on the Discord CDN.
+//!
+//! <https://discord.com/developers/docs/reference#image-formatting>
+
+use std::fmt::Display;
+
+use crate::resources::StickerFormatType;
+
+/// Base URL of the Discord CDN
+pub const CDN_BASE_URL: &str = "https://cdn.discordapp.com";
+
+/// Base URL for GIF stickers, which are not served from the CDN
+pub const MEDIA_BASE_URL: &str = "https://media.discordapp.net";
+
+/// <https://discord.com/developers/docs/reference#image-formatting-image-formats>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageFormat {
+    Jpeg,
+    Png,
+    WebP,
+    Gif,
+    Avif,
+    /// Lottie animations, only used for stickers
+    Lottie,
+}
+
+impl ImageFormat {
+    /// File extension of the format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Jpeg => "jpg",
+            ImageFormat::Png => "png",
+            ImageFormat::WebP => "webp",
+            ImageFormat::Gif => "gif",
+            ImageFormat::Avif => "avif",
+            ImageFormat::Lottie => "json",
+        }
+    }
+}
+
+const STATIC_FORMATS: &[ImageFormat] = &[
+    ImageFormat::Png,
+    ImageFormat::Jpeg,
+    ImageFormat::WebP,
+    ImageFormat::Avif,
+];
+
+const ANIMATED_FORMATS: &[ImageFormat] = &[
+    ImageFormat::Png,
+    ImageFormat::Jpeg,
+    ImageFormat::WebP,
+    ImageFormat::Gif,
+    ImageFormat::Avif,
+];
+
+/// Error for an invalid choice of format or size of a CDN URL
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CdnError {
+    /// the format is not available for this resource
+    UnsupportedFormat(ImageFormat),
+    /// the size is not a power of two between 16 and 4096
+    InvalidSize(u16),
+    /// the resource does not support the size parameter
+    SizeNotSupported,
+    /// the sticker format is not known to this version of the library
+    UnknownStickerFormat(u8),
+}
+
+impl Display for CdnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CdnError::UnsupportedFormat(format) => {
+                write!(f, "format {format:?} is not supported for this resource")
+            }
+            CdnError::InvalidSize(size) => write!(
+                f,
+                "invalid image size {size}, expected a power of two between 16 and 4096"
+            ),
+            CdnError::SizeNotSupported => write!(f, "this resource does not support a size"),
+            CdnError::UnknownStickerFormat(format) => {
+                write!(f, "unknown sticker format type {format}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CdnError {}
+
+/// URL of an asset on the Discord CDN, with the format and size to request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CdnUrl {
+    base: &'static str,
+    path: String,
+    format: ImageFormat,
+    formats: &'static [ImageFormat],
+    animated: bool,
+    sized: bool,
+    size: Option<u16>,
+}
+
+impl CdnUrl {
+    fn image(path: String, animated: bool) -> Self {
+        CdnUrl {
+            base: CDN_BASE_URL,
+            path,
+            format: if animated {
+                ImageFormat::Gif
+            } else {
+                ImageFormat::Png
+            },
+            formats: if animated {
+                ANIMATED_FORMATS
+            } else {
+                STATIC_FORMATS
+            },
+            animated,
+            sized: true,
+            size: None,
+        }
+    }
+
+    fn hashed(path: String, hash: &str) -> Self {
+        Self::image(format!("{path}/{hash}"), hash.starts_with("a_"))
+    }
+
+    /// <https://discord.com/developers/docs/reference#image-formatting-cdn-endpoints> (user avatar)
+    pub fn user_avatar(user_id: &str, hash: &str) -> Self {
+        Self::hashed(format!("avatars/{user_id}"), hash)
+    }
+
+    /// Default avatar of a user without a custom avatar.
+    ///
+    /// Users on the new username system (discriminator `0`) are assigned their avatar by their id,
+    /// legacy users by their discriminator.
+    pub fn default_user_avatar(user_id: &str, discriminator: &str) -> Self {
+        let index = match discriminator.parse::<u64>() {
+            Ok(discriminator) if discriminator != 0 => discriminator % 5,
+            _ => (user_id.parse::<u64>().unwrap_or(0) >> 22) % 6,
+        };
+
+        CdnUrl {
+            formats: &[ImageFormat::Png],
+            sized: false,
+            ..Self::image(format!("embed/avatars/{index}"), false)
+        }
+    }
+
+    /// Guild specific avatar of a guild member.
+    pub fn guild_member_avatar(guild_id: &str, user_id: &str, hash: &str) -> Self {
+        Self::hashed(format!("guilds/{guild_id}/users/{user_id}/avatars"), hash)
+    }
+
+    /// Guild specific banner of a guild member.
+    pub fn guild_member_banner(guild_id: &str, user_id: &str, hash: &str) -> Self {
+        Self::hashed(format!("guilds/{guild_id}/users/{user_id}/banners"), hash)
+    }
+
+    /// Banner of a user.
+    pub fn user_banner(user_id: &str, hash: &str) -> Self {
+        Self::hashed(format!("banners/{user_id}"), hash)
+    }
+
+    /// Icon of a guild.
+    pub fn guild_icon(guild_id: &str, hash: &str) -> Self {
+        Self::hashed(format!("icons/{guild_id}"), hash)
+    }
+
+    /// Banner of a guild.
+    pub fn guild_banner(guild_id: &str, hash: &str) -> Self {
+        Self::hashed(format!("banners/{guild_id}"), hash)
+    }
+
+    /// Invite splash of a guild.
+    pub fn guild_splash(guild_id: &str, hash: &str) -> Self {
+        Self::image(format!("splashes/{guild_id}/{hash}"), false)
+    }
+
+    /// Discovery splash of a guild.
+    pub fn guild_discovery_splash(guild_id: &str, hash: &str) -> Self {
+        Self::image(format!("discovery-splashes/{guild_id}/{hash}"), false)
+    }
+
+    /// Icon of a role.
+    pub fn role_icon(role_id: &str, hash: &str) -> Self {
+        Self::image(format!("role-icons/{role_id}/{hash}"), false)
+    }
+
+    /// Custom emoji, animated emojis default to GIF.
+    pub fn custom_emoji(emoji_id: &str, animated: bool) -> Self {
+        Self::image(format!("emojis/{emoji_id}"), animated)
+    }
+
+    /// Sticker, in the only format it is available in.
+    pub fn sticker(sticker_id: &str, format_type: StickerFormatType) -> Result<Self, CdnError> {
+        let (format, base) = match format_type {
+            StickerFormatType::PNG | StickerFormatType::APNG => (ImageFormat::Png, CDN_BASE_URL),
+            StickerFormatType::LOTTIE => (ImageFormat::Lottie, CDN_BASE_URL),
+            StickerFormatType::GIF => (ImageFormat::Gif, MEDIA_BASE_URL),
+            StickerFormatType::Unknown(format) => {
+                return Err(CdnError::UnknownStickerFormat(format))
+            }
+        };
+
+        Ok(CdnUrl {
+            base,
+            path: format!("stickers/{sticker_id}"),
+            format,
+            formats: match format {
+                ImageFormat::Png => &[ImageFormat::Png],
+                ImageFormat::Lottie => &[ImageFormat::Lottie],
+                _ => &[ImageFormat::Gif],
+            },
+            animated: format_type != StickerFormatType::PNG,
+            sized: format != ImageFormat::Lottie,
+            size: None,
+        })
+    }
+
+    /// Request the asset in another format.
+    pub fn format(mut self, format: ImageFormat) -> Result<Self, CdnError> {
+        if !self.formats.contains(&format) {
+            return Err(CdnError::UnsupportedFormat(format));
+        }
+        self.format = format;
+        Ok(self)
+    }
+
+    /// Request the asset in a size, any power of two between 16 and 4096.
+    pub fn size(mut self, size: u16) -> Result<Self, CdnError> {
+        if !self.sized {
+            return Err(CdnError::SizeNotSupported);
+        }
+        if !(16..=4096).contains(&size) || !size.is_power_of_two() {
+            return Err(CdnError::InvalidSize(size));
+        }
+        self.size = Some(size);
+        Ok(self)
+    }
+
+    /// Whether the asset is animated.
+    pub fn is_animated(&self) -> bool {
+        self.animated
+    }
+
+    /// Formats the asset can be requested in.
+    pub fn formats(&self) -> &'static [ImageFormat] {
+        self.formats
+    }
+}
+
+impl Display for CdnUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}.{}", self.base, self.path, self.format.extension())?;
+
+        let mut separator = '?';
+        if self.animated && self.format == ImageFormat::WebP {
+            write!(f, "{separator}animated=true")?;
+            separator = '&';
+        }
+        if let Some(size) = self.size {
+            write!(f, "{separator}size={size}")?;
+        }
+        Ok(())
+    }
+}
@@ -1,12 +1,12 @@
 use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use crate::entities::UserApiType;
 
 use super::{
-    ChannelTypeApiType, DefaultReactionApiType, ForumTagApiType, OverwritesApiType,
+    ChannelFlags, ChannelTypeApiType, DefaultReactionApiType, ForumTagApiType, OverwritesApiType,
     ThreadMemberApiType, ThreadMetadataApiType,
 };
+use crate::timestamp::Timestamp;
 
 /// <https://discord.com/developers/docs/resources/channel#channel-object>
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -63,7 +63,7 @@ pub struct ChannelApiType {
     pub parent_id: Option<String>,
     /// when the last pinned message was pinned. This may be null in events such as GUILD_CREATE when a message is not pinned.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_pin_timestamp: Option<String>,
+    pub last_pin_timestamp: Option<Timestamp>,
     /// voice region id for the voice channel, automatic when set to null
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rtc_region: Option<String>,
@@ -91,7 +91,7 @@ pub struct ChannelApiType {
     // <https://discord.com/developers/docs/resources/channel#channel-object-channel-flags>
     /// channel flags combined as a bitfield
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub flags: Option<u64>,
+    pub flags: Option<ChannelFlags>,
     /// number of messages ever sent in a thread
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total_message_sent: Option<u64>,
@@ -116,13 +116,18 @@ pub struct ChannelApiType {
     /// for group DM channels: whether the channel is managed by an application via the gdm.join OAuth2 scope
     #[serde(skip_serializing_if = "Option::is_none")]
     pub managed: Option<bool>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/resources/channel#channel-object-video-quality-modes>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Default, Clone)]
-#[repr(u8)]
-pub enum VideoQualityModeApiType {
-    #[default]
-    AUTO = 1,
-    FULL = 2,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/channel#channel-object-video-quality-modes>
+    #[derive(Default)]
+    pub enum VideoQualityModeApiType: u8 {
+        #[default]
+        AUTO = 1,
+        FULL = 2,
+    }
 }
@@ -7,4 +7,8 @@ pub struct FollowedChannelApiType {
     pub channel_id: String,
     /// created target webhook id
     pub webhook_id: String,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
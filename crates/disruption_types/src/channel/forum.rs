@@ -15,6 +15,10 @@ pub struct ForumTagApiType {
     /// the unicode character of the emoji
     #[serde(skip_serializing_if = "Option::is_none")]
     pub emoji_name: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/channel#default-reaction-object>
@@ -26,4 +30,8 @@ pub struct DefaultReactionApiType {
     /// the unicode character of the emoji
     #[serde(skip_serializing_if = "Option::is_none")]
     pub emoji_name: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
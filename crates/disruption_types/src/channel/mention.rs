@@ -14,10 +14,14 @@ pub struct ChannelMentionApiType {
     pub type_: ChannelTypeApiType,
     /// the name of the channel
     pub name: String,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/channel#allowed-mentions-object-allowed-mentions-structure>
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct AllowedMentionsApiType {
     /// An array of allowed mention types to parse from the content.
     pub parse: Vec<String>,
@@ -27,4 +31,8 @@ pub struct AllowedMentionsApiType {
     pub users: Vec<String>,
     /// For replies, whether to mention the author of the message being replied to (default false)
     pub replied_user: bool,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -1,5 +1,4 @@
 use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
 /// <https://discord.com/developers/docs/resources/channel#message-object-message-activity-structure>
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -8,15 +7,19 @@ pub struct MessageActivityApiType {
     pub type_: MessageActivityTypeApiType,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub party_id: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/resources/channel#message-object-message-activity-types>
-#[derive(Serialize_repr, Deserialize_repr, PartialEq, Debug, Clone)]
-#[repr(u8)]
-pub enum MessageActivityTypeApiType {
-    JOIN = 1,
-    SPECTATE = 2,
-    LISTEN = 3,
-    #[allow(non_camel_case_types)]
-    JOIN_REQUEST = 4,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/channel#message-object-message-activity-types>
+    pub enum MessageActivityTypeApiType: u8 {
+        JOIN = 1,
+        SPECTATE = 2,
+        LISTEN = 3,
+        #[allow(non_camel_case_types)]
+        JOIN_REQUEST = 4,
+    }
 }
@@ -39,5 +39,42 @@ pub struct AttachmentApiType {
     pub waveform: Option<String>,
     /// attachment flags combined as a bitfield
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub flags: Option<u64>,
+    pub flags: Option<AttachmentFlags>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Partial attachment object, used to reference existing or newly uploaded files in request bodies
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PartialAttachmentApiType {
+    /// attachment id, or the index of the uploaded file for new attachments
+    pub id: String,
+    /// name of file attached
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    /// description for the file (max 1024 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+flags! {
+    /// <https://discord.com/developers/docs/resources/message#attachment-object-attachment-flags>
+    pub struct AttachmentFlags: u64 {
+        /// this attachment is a clip from a stream
+        const IS_CLIP = 1 << 0;
+        /// this attachment is the thumbnail of a thread in a media channel
+        const IS_THUMBNAIL = 1 << 1;
+        /// this attachment has been edited using the remix feature on mobile
+        const IS_REMIX = 1 << 2;
+        /// this attachment was marked as a spoiler
+        const IS_SPOILER = 1 << 3;
+        /// this attachment is an animated image
+        const IS_ANIMATED = 1 << 5;
+    }
 }
@@ -1,3 +1,4 @@
+use crate::timestamp::Timestamp;
 use serde::{Deserialize, Serialize};
 
 /// <https://discord.com/developers/docs/resources/message#message-call-object>
@@ -7,5 +8,9 @@ pub struct MessageCallApiType {
     pub participants: Vec<String>,
     /// time when call ended
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ended_timestamp: Option<String>,
+    pub ended_timestamp: Option<Timestamp>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -1,11 +1,161 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use serde::{Deserialize, Serialize};
+
+use crate::{channel::ChannelTypeApiType, entities::EmojiApiType};
+
+wire_enum! {
+    /// <https://discord.com/developers/docs/interactions/message-components#component-object-component-types>
+    pub enum MessageComponentApiType: u8 {
+        ActionRow = 1,
+        Button = 2,
+        SelectMenu = 3,
+        TextInput = 4,
+        UserSelect = 5,
+        RoleSelect = 6,
+        MentionableSelect = 7,
+        ChannelSelect = 8,
+    }
+}
 
 /// <https://discord.com/developers/docs/interactions/message-components#component-object>
-#[derive(Serialize_repr, Deserialize_repr, PartialEq, Debug, Clone)]
-#[repr(u8)]
-pub enum MessageComponentApiType {
-    ActionRow = 1,
-    Button = 2,
-    SelectMenu = 3,
-    TextInput = 4,
+/// A single component, the fields that are present depend on its type
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ComponentApiType {
+    /// the type of the component
+    #[serde(rename = "type")]
+    pub type_: MessageComponentApiType,
+    /// optional identifier for the component, unique within the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    /// child components of an action row
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<ComponentApiType>>,
+    /// developer-defined identifier for the component, max 100 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_id: Option<String>,
+    /// style of a button (1-6) or a text input (1-2)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<u8>,
+    /// text that appears on a button (max 80 characters) or above a text input (max 45 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// partial emoji of a button (name, id, and animated)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<EmojiApiType>,
+    /// URL for link-style buttons
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// identifier for a purchasable SKU, only available when using premium-style buttons
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sku_id: Option<String>,
+    /// whether the component is disabled, defaults to false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+    /// specified choices in a string select menu, max 25
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<SelectOptionApiType>>,
+    /// list of channel types to include in a channel select menu
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_types: Option<Vec<ChannelTypeApiType>>,
+    /// placeholder text if nothing is selected or entered, max 150 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+    /// list of default values for auto-populated select menu components
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_values: Option<Vec<SelectDefaultValueApiType>>,
+    /// minimum number of items that must be chosen in a select menu (defaults to 1, min 0, max 25)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_values: Option<u8>,
+    /// maximum number of items that can be chosen in a select menu (defaults to 1, max 25)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_values: Option<u8>,
+    /// minimum input length for a text input (min 0, max 4000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u16>,
+    /// maximum input length for a text input (min 1, max 4000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u16>,
+    /// whether a text input is required to be filled (defaults to true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+    /// pre-filled value for a text input, max 4000 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ComponentApiType {
+    /// Create an empty component of the given type.
+    pub fn new(type_: MessageComponentApiType) -> Self {
+        ComponentApiType {
+            type_,
+            id: None,
+            components: None,
+            custom_id: None,
+            style: None,
+            label: None,
+            emoji: None,
+            url: None,
+            sku_id: None,
+            disabled: None,
+            options: None,
+            channel_types: None,
+            placeholder: None,
+            default_values: None,
+            min_values: None,
+            max_values: None,
+            min_length: None,
+            max_length: None,
+            required: None,
+            value: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
+    }
+
+    /// Create an action row containing the given components.
+    pub fn action_row(components: Vec<ComponentApiType>) -> Self {
+        ComponentApiType {
+            components: Some(components),
+            ..Self::new(MessageComponentApiType::ActionRow)
+        }
+    }
+}
+
+/// <https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-option-structure>
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SelectOptionApiType {
+    /// user-facing name of the option, max 100 characters
+    pub label: String,
+    /// dev-defined value of the option, max 100 characters
+    pub value: String,
+    /// additional description of the option, max 100 characters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// partial emoji (id, name, and animated)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<EmojiApiType>,
+    /// will show this option as selected by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// <https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-default-value-structure>
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SelectDefaultValueApiType {
+    /// ID of a user, role, or channel
+    pub id: String,
+    /// type of value that id represents, either "user", "role", or "channel"
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -1,3 +1,5 @@
+use crate::color::Color;
+use crate::timestamp::Timestamp;
 use serde::{Deserialize, Serialize};
 
 /// <https://discord.com/developers/docs/resources/channel#embed-object>
@@ -17,10 +19,10 @@ pub struct EmbedApiType {
     pub url: Option<String>,
     /// timestamp of embed content
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<String>,
+    pub timestamp: Option<Timestamp>,
     /// color code of the embed
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub color: Option<u64>,
+    pub color: Option<Color>,
     /// footer information
     #[serde(skip_serializing_if = "Option::is_none")]
     pub footer: Option<EmbedFooterApiType>,
@@ -42,6 +44,10 @@ pub struct EmbedApiType {
     /// fields information
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fields: Option<Vec<EmbedFieldApiType>>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/channel#embed-object-embed-footer-structure>
@@ -52,6 +58,10 @@ pub struct EmbedFooterApiType {
     pub icon_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub proxy_icon_url: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/channel#embed-object-embed-image-structure>
@@ -64,6 +74,10 @@ pub struct EmbedImageApiType {
     pub height: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub width: Option<u64>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/channel#embed-object-embed-thumbnail-structure>
@@ -76,6 +90,10 @@ pub struct EmbedThumbnailApiType {
     pub height: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub width: Option<u64>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/channel#embed-object-embed-video-structure>
@@ -89,6 +107,10 @@ pub struct EmbedVideoApiType {
     pub height: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub width: Option<u64>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/channel#embed-object-embed-provider-structure>
@@ -98,6 +120,10 @@ pub struct EmbedProviderApiType {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/channel#embed-object-embed-author-structure>
@@ -110,6 +136,10 @@ pub struct EmbedAuthorApiType {
     pub icon_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub proxy_icon_url: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/channel#embed-object-embed-field-structure>
@@ -119,4 +149,8 @@ pub struct EmbedFieldApiType {
     pub value: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inline: Option<bool>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
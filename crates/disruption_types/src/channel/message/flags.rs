@@ -1,30 +1,31 @@
-/// <https://discord.com/developers/docs/resources/message#message-object-message-flags>
-#[allow(non_camel_case_types)]
-pub enum MessageFlags {
-    /// this message has been published to subscribed channels (via Channel Following)
-    CROSSPOSTED = 1 << 0,
-    /// this message originated from a message in another channel (via Channel Following)
-    IS_CROSSPOST = 1 << 1,
-    /// do not include any embeds when serializing this message
-    SUPPRESS_EMBEDS = 1 << 2,
-    /// the source message for this crosspost has been deleted (via Channel Following)
-    SOURCE_MESSAGE_DELETED = 1 << 3,
-    /// this message came from the urgent message system
-    URGENT = 1 << 4,
-    /// this message has an associated thread, with the same id as the message
-    HAS_THREAD = 1 << 5,
-    /// this message is only visible to the user who invoked the Interaction
-    EPHEMERAL = 1 << 6,
-    /// this message is an Interaction Response and the bot is "thinking"
-    LOADING = 1 << 7,
-    /// this message failed to mention some roles and add their members to the thread
-    FAILED_TO_MENTION_SOME_ROLES_IN_THREAD = 1 << 8,
-    /// this message will not trigger push and desktop notifications
-    SUPPRESS_NOTIFICATIONS = 1 << 12,
-    /// this message is a voice message
-    IS_VOICE_MESSAGE = 1 << 13,
-    /// this message has a snapshot (via Message Forwarding)
-    HAS_SNAPSHOT = 1 << 14,
-    /// allows you to create fully component-driven messages
-    IS_COMPONENTS_V2 = 1 << 15,
+flags! {
+    /// <https://discord.com/developers/docs/resources/message#message-object-message-flags>
+    pub struct MessageFlags: u64 {
+        /// this message has been published to subscribed channels (via Channel Following)
+        const CROSSPOSTED = 1 << 0;
+        /// this message originated from a message in another channel (via Channel Following)
+        const IS_CROSSPOST = 1 << 1;
+        /// do not include any embeds when serializing this message
+        const SUPPRESS_EMBEDS = 1 << 2;
+        /// the source message for this crosspost has been deleted (via Channel Following)
+        const SOURCE_MESSAGE_DELETED = 1 << 3;
+        /// this message came from the urgent message system
+        const URGENT = 1 << 4;
+        /// this message has an associated thread, with the same id as the message
+        const HAS_THREAD = 1 << 5;
+        /// this message is only visible to the user who invoked the Interaction
+        const EPHEMERAL = 1 << 6;
+        /// this message is an Interaction Response and the bot is "thinking"
+        const LOADING = 1 << 7;
+        /// this message failed to mention some roles and add their members to the thread
+        const FAILED_TO_MENTION_SOME_ROLES_IN_THREAD = 1 << 8;
+        /// this message will not trigger push and desktop notifications
+        const SUPPRESS_NOTIFICATIONS = 1 << 12;
+        /// this message is a voice message
+        const IS_VOICE_MESSAGE = 1 << 13;
+        /// this message has a snapshot (via Message Forwarding)
+        const HAS_SNAPSHOT = 1 << 14;
+        /// allows you to create fully component-driven messages
+        const IS_COMPONENTS_V2 = 1 << 15;
+    }
 }
@@ -1,5 +1,4 @@
 use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use crate::entities::UserApiType;
 
@@ -13,20 +12,24 @@ pub struct MessageInteractionApiType {
     pub user: UserApiType,
     // TODO: Add members
     // pub member: Member
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 // TODO: move this to other module
-/// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-type>
-#[derive(Serialize_repr, Deserialize_repr, PartialEq, Debug, Clone)]
-#[repr(u8)]
-pub enum InteractionTypeApiType {
-    PING = 1,
-    #[allow(non_camel_case_types)]
-    APPLICATION_COMMAND = 2,
-    #[allow(non_camel_case_types)]
-    MESSAGE_COMPONENT = 3,
-    #[allow(non_camel_case_types)]
-    APPLICATION_COMMAND_AUTOCOMPLETE = 4,
-    #[allow(non_camel_case_types)]
-    MOADL_SUBMIT = 5,
+wire_enum! {
+    /// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-type>
+    pub enum InteractionTypeApiType: u8 {
+        PING = 1,
+        #[allow(non_camel_case_types)]
+        APPLICATION_COMMAND = 2,
+        #[allow(non_camel_case_types)]
+        MESSAGE_COMPONENT = 3,
+        #[allow(non_camel_case_types)]
+        APPLICATION_COMMAND_AUTOCOMPLETE = 4,
+        #[allow(non_camel_case_types)]
+        MOADL_SUBMIT = 5,
+    }
 }
@@ -31,4 +31,8 @@ pub struct MessageInteractionMetadataApiType {
     /// ID of the message that contained interactive component, present only on messages created from component interactions
     #[serde(skip_serializing_if = "Option::is_none")]
     pub interacted_message_id: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -8,11 +8,13 @@ use crate::{
 };
 
 use super::{
-    AttachmentApiType, EmbedApiType, MessageActivityApiType, MessageCallApiType,
-    MessageComponentApiType, MessageInteractionApiType, MessageInteractionMetadataApiType,
+    AttachmentApiType, ComponentApiType, EmbedApiType, MessageActivityApiType, MessageCallApiType,
+    MessageFlags, MessageInteractionApiType, MessageInteractionMetadataApiType,
     MessageReferenceApiType, MessageSnapshotApiType, MessageTypeApiType, ReactionApiType,
     RoleSubscriptionDataApiType,
 };
+use crate::formatting::ParsedContent;
+use crate::timestamp::Timestamp;
 
 /// <https://discord.com/developers/docs/resources/channel#message-object>
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
@@ -26,9 +28,9 @@ pub struct MessageApiType {
     /// contents of the message
     pub content: String,
     /// when this message was sent
-    pub timestamp: String,
+    pub timestamp: Timestamp,
     /// when this message was edited (or null if never)
-    pub edited_timestamp: Option<String>,
+    pub edited_timestamp: Option<Timestamp>,
     /// whether this was a TTS message
     pub tts: bool,
     /// whether this message mentions everyone
@@ -69,7 +71,7 @@ pub struct MessageApiType {
     pub application_id: Option<String>,
     /// message flags combined as a bitfield
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub flags: Option<u64>,
+    pub flags: Option<MessageFlags>,
     /// data showing the source of a crosspost, channel follow add, pin, or reply message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message_reference: Option<MessageReferenceApiType>,
@@ -90,7 +92,7 @@ pub struct MessageApiType {
     pub thread: Option<ChannelApiType>,
     /// sent if the message contains components like buttons, action rows, or other interactive components
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub components: Option<Vec<MessageComponentApiType>>,
+    pub components: Option<Vec<ComponentApiType>>,
     /// sent if the message contains stickers
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sticker_items: Option<Vec<StickerItemApiType>>,
@@ -112,16 +114,15 @@ pub struct MessageApiType {
     /// the call associated with the message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub call: Option<MessageCallApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-impl<T> From<T> for MessageApiType
-where
-    T: ToString,
-{
-    fn from(value: T) -> Self {
-        MessageApiType {
-            content: value.to_string(),
-            ..Default::default()
-        }
+impl MessageApiType {
+    /// Parse the content of the message into markdown, mentions and links.
+    pub fn parsed_content(&self) -> ParsedContent<'_> {
+        ParsedContent::parse(&self.content)
     }
 }
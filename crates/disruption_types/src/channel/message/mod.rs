@@ -6,9 +6,11 @@ mod embed;
 mod flags;
 mod interaction;
 mod interaction_metadata;
+#[allow(clippy::module_inception)]
 mod message;
 mod reaction;
 mod reference;
+mod request;
 mod snapshot;
 mod subscription;
 mod type_;
@@ -24,6 +26,7 @@ pub use self::interaction_metadata::*;
 pub use self::message::*;
 pub use self::reaction::*;
 pub use self::reference::*;
+pub use self::request::*;
 pub use self::snapshot::*;
 pub use self::subscription::*;
 pub use self::type_::*;
@@ -1,3 +1,6 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
 use crate::entities::EmojiApiType;
@@ -17,6 +20,10 @@ pub struct ReactionApiType {
     pub emoji: EmojiApiType,
     /// HEX colors used for super reaction
     pub burst_colors: Vec<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/message#reaction-count-details-object>
@@ -26,4 +33,144 @@ pub struct ReactionCountDetailsApiType {
     pub normal: u32,
     /// Count of super reactions
     pub burst: u32,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/message#get-reactions-reaction-types>
+    #[derive(Default)]
+    pub enum ReactionTypeApiType: u8 {
+        #[default]
+        NORMAL = 0,
+        BURST = 1,
+    }
+}
+
+/// An emoji to react with.
+///
+/// Parses unicode emojis (`👍`) and custom emojis in the forms `name:id`, `<:name:id>` and
+/// `<a:name:id>`. It is displayed in the form reaction endpoints expect: the unicode emoji or
+/// `name:id`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ReactionType {
+    /// a unicode emoji
+    Unicode(String),
+    /// a custom emoji of a guild or application
+    Custom {
+        /// name of the emoji, may be missing for emojis of reactions of deleted guilds
+        name: Option<String>,
+        /// id of the emoji
+        id: String,
+        /// whether the emoji is animated
+        animated: bool,
+    },
+}
+
+impl ReactionType {
+    /// A unicode emoji.
+    pub fn unicode(emoji: impl ToString) -> Self {
+        ReactionType::Unicode(emoji.to_string())
+    }
+
+    /// A custom emoji.
+    pub fn custom(name: impl ToString, id: impl ToString) -> Self {
+        ReactionType::Custom {
+            name: Some(name.to_string()),
+            id: id.to_string(),
+            animated: false,
+        }
+    }
+}
+
+impl Display for ReactionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReactionType::Unicode(emoji) => f.write_str(emoji),
+            // Discord only looks at the id, but requires a name
+            ReactionType::Custom { name, id, .. } => {
+                write!(f, "{}:{id}", name.as_deref().unwrap_or("_"))
+            }
+        }
+    }
+}
+
+impl FromStr for ReactionType {
+    type Err = ReactionTypeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ReactionTypeParseError {
+            input: s.to_owned(),
+        };
+        let input = s.trim();
+        if input.is_empty() {
+            return Err(error());
+        }
+
+        let (emoji, animated) = match input.strip_prefix('<').and_then(|s| s.strip_suffix('>')) {
+            Some(mention) => match mention.strip_prefix("a:") {
+                Some(emoji) => (emoji, true),
+                None => (mention.strip_prefix(':').unwrap_or(mention), false),
+            },
+            None => (input, false),
+        };
+
+        match emoji.rsplit_once(':') {
+            Some((name, id)) => {
+                if id.is_empty() || !id.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(error());
+                }
+                Ok(ReactionType::Custom {
+                    name: (!name.is_empty()).then(|| name.to_owned()),
+                    id: id.to_owned(),
+                    animated,
+                })
+            }
+            // mentions are only valid for custom emojis
+            None if emoji.len() != input.len() => Err(error()),
+            None => Ok(ReactionType::Unicode(emoji.to_owned())),
+        }
+    }
+}
+
+impl From<&EmojiApiType> for ReactionType {
+    fn from(emoji: &EmojiApiType) -> Self {
+        match &emoji.id {
+            Some(id) => ReactionType::Custom {
+                name: emoji.name.clone(),
+                id: id.clone(),
+                animated: emoji.animated.unwrap_or(false),
+            },
+            None => ReactionType::Unicode(emoji.name.clone().unwrap_or_default()),
+        }
+    }
 }
+
+impl From<EmojiApiType> for ReactionType {
+    fn from(emoji: EmojiApiType) -> Self {
+        ReactionType::from(&emoji)
+    }
+}
+
+impl From<char> for ReactionType {
+    fn from(emoji: char) -> Self {
+        ReactionType::Unicode(emoji.to_string())
+    }
+}
+
+/// Error for a string that is neither a unicode nor a custom emoji
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReactionTypeParseError {
+    /// the input that failed to parse
+    pub input: String,
+}
+
+impl Display for ReactionTypeParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid emoji {:?}", self.input)
+    }
+}
+
+impl std::error::Error for ReactionTypeParseError {}
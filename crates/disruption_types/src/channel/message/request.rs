@@ -0,0 +1,163 @@
+use serde::{Deserialize, Serialize};
+
+use crate::channel::AllowedMentionsApiType;
+use crate::nullable::Nullable;
+use crate::resources::PollCreateRequestApiType;
+
+use super::{
+    ComponentApiType, EmbedApiType, MessageFlags, MessageReferenceApiType, PartialAttachmentApiType,
+};
+
+/// <https://discord.com/developers/docs/resources/message#create-message-jsonform-params>
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CreateMessageApiType {
+    /// message contents (up to 2000 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// can be used to verify a message was sent (up to 25 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<serde_json::Value>, // Can be integer or string
+    /// true if this is a TTS message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tts: Option<bool>,
+    /// up to 10 rich embeds (up to 6000 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<EmbedApiType>>,
+    /// allowed mentions for the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentionsApiType>,
+    /// include to make your message a reply or a forward
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_reference: Option<MessageReferenceApiType>,
+    /// components to include with the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<ComponentApiType>>,
+    /// ids of up to 3 stickers in the server to send in the message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sticker_ids: Option<Vec<String>>,
+    /// attachment objects with filename and description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<PartialAttachmentApiType>>,
+    /// message flags combined as a bitfield (only SUPPRESS_EMBEDS, SUPPRESS_NOTIFICATIONS and IS_COMPONENTS_V2 can be set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<MessageFlags>,
+    /// if true and nonce is present, it will be checked for uniqueness in the past few minutes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enforce_nonce: Option<bool>,
+    /// a poll!
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<PollCreateRequestApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl CreateMessageApiType {
+    /// Create a message with the given content.
+    pub fn new(content: impl ToString) -> Self {
+        CreateMessageApiType {
+            content: Some(content.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Send this message as a reply to the given message.
+    pub fn reply_to(mut self, message_id: impl ToString) -> Self {
+        self.message_reference = Some(MessageReferenceApiType {
+            message_id: Some(message_id.to_string()),
+            ..Default::default()
+        });
+        self
+    }
+}
+
+impl From<&str> for CreateMessageApiType {
+    fn from(content: &str) -> Self {
+        CreateMessageApiType::new(content)
+    }
+}
+
+impl From<String> for CreateMessageApiType {
+    fn from(content: String) -> Self {
+        CreateMessageApiType {
+            content: Some(content),
+            ..Default::default()
+        }
+    }
+}
+
+/// <https://discord.com/developers/docs/resources/message#edit-message-jsonform-params>
+/// All fields are optional, fields set to null are removed from the message
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct EditMessageApiType {
+    /// message contents (up to 2000 characters)
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub content: Nullable<String>,
+    /// up to 10 rich embeds (up to 6000 characters)
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub embeds: Nullable<Vec<EmbedApiType>>,
+    /// edit the flags of a message (only SUPPRESS_EMBEDS can currently be set/unset)
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub flags: Nullable<MessageFlags>,
+    /// allowed mentions for the message
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub allowed_mentions: Nullable<AllowedMentionsApiType>,
+    /// components to include with the message
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub components: Nullable<Vec<ComponentApiType>>,
+    /// attached files to keep and possible descriptions for new files
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub attachments: Nullable<Vec<PartialAttachmentApiType>>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl EditMessageApiType {
+    /// Replace the content of the message.
+    pub fn new(content: impl ToString) -> Self {
+        EditMessageApiType {
+            content: Nullable::Value(content.to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&str> for EditMessageApiType {
+    fn from(content: &str) -> Self {
+        EditMessageApiType::new(content)
+    }
+}
+
+impl From<String> for EditMessageApiType {
+    fn from(content: String) -> Self {
+        EditMessageApiType {
+            content: Nullable::Value(content),
+            ..Default::default()
+        }
+    }
+}
+
+/// <https://discord.com/developers/docs/resources/message#bulk-delete-messages-json-params>
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct BulkDeleteMessagesApiType {
+    /// an array of message ids to delete (2-100)
+    pub messages: Vec<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl BulkDeleteMessagesApiType {
+    /// Delete the messages with the given ids.
+    pub fn new(messages: impl IntoIterator<Item = impl ToString>) -> Self {
+        BulkDeleteMessagesApiType {
+            messages: messages.into_iter().map(|id| id.to_string()).collect(),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
+    }
+}
@@ -7,4 +7,8 @@ use super::MessageApiType;
 pub struct MessageSnapshotApiType {
     /// minimal subset of fields in the forwarded message
     pub message: MessageApiType,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
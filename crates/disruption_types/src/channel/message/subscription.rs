@@ -11,4 +11,8 @@ pub struct RoleSubscriptionDataApiType {
     pub total_months_subscribed: u32,
     /// whether this notification is for a renewal rather than a new purchase
     pub is_renewal: bool,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -8,4 +8,8 @@ pub struct OverwritesApiType {
     pub type_: u8,
     pub allow: String,
     pub deny: String,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    ChannelTypeApiType, DefaultReactionApiType, ForumTagApiType, OverwritesApiType,
+    VideoQualityModeApiType,
+};
+
+/// <https://discord.com/developers/docs/resources/guild#create-guild-channel-json-params>
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CreateChannelApiType {
+    /// channel name (1-100 characters)
+    pub name: String,
+    /// the type of channel
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<ChannelTypeApiType>,
+    /// channel topic (0-1024 characters)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+    /// the bitrate (in bits) of the voice or stage channel; min 8000
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bitrate: Option<u64>,
+    /// the user limit of the voice channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_limit: Option<u64>,
+    /// amount of seconds a user has to wait before sending another message (0-21600)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_per_user: Option<u64>,
+    /// sorting position of the channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u64>,
+    /// the channel's permission overwrites
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_overwrites: Option<Vec<OverwritesApiType>>,
+    /// id of the parent category for a channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// whether the channel is nsfw
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nsfw: Option<bool>,
+    /// channel voice region id of the voice or stage channel, automatic when set to null
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rtc_region: Option<String>,
+    /// the camera video quality mode of the voice channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_quality_mode: Option<VideoQualityModeApiType>,
+    /// the default duration that the clients use (not the API) for newly created threads in the channel, in minutes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_auto_archive_duration: Option<u64>,
+    /// emoji to show in the add reaction button on a thread in a GUILD_FORUM or a GUILD_MEDIA channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_reaction_emoji: Option<DefaultReactionApiType>,
+    /// set of tags that can be used in a GUILD_FORUM or a GUILD_MEDIA channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_tags: Option<Vec<ForumTagApiType>>,
+    /// the default sort order type used to order posts in GUILD_FORUM and GUILD_MEDIA channels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_sort_order: Option<u8>,
+    /// the default forum layout view used to display posts in GUILD_FORUM channels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_forum_layout: Option<u8>,
+    /// the initial rate_limit_per_user to set on newly created threads in a channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_thread_rate_limit_per_user: Option<u64>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl CreateChannelApiType {
+    /// Create a channel of the given type.
+    pub fn new(name: impl ToString, type_: ChannelTypeApiType) -> Self {
+        CreateChannelApiType {
+            name: name.to_string(),
+            type_: Some(type_),
+            ..Default::default()
+        }
+    }
+}
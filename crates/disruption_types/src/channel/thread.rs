@@ -1,16 +1,23 @@
+use crate::timestamp::Timestamp;
 use serde::{Deserialize, Serialize};
 
+use super::ChannelApiType;
+
 /// <https://discord.com/developers/docs/resources/channel#thread-metadata-object-thread-metadata-structure>
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ThreadMetadataApiType {
     pub archived: bool,
     pub auto_archive_duration: u64,
-    pub archive_timestamp: String,
+    pub archive_timestamp: Timestamp,
     pub locked: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub invitable: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub create_timestamp: Option<String>,
+    pub create_timestamp: Option<Timestamp>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/channel#thread-member-object>
@@ -20,6 +27,25 @@ pub struct ThreadMemberApiType {
     pub id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_id: Option<String>,
-    pub join_timestamp: String,
+    pub join_timestamp: Timestamp,
     pub flags: u64,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// <https://discord.com/developers/docs/resources/channel#list-public-archived-threads-response-body>
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ThreadListApiType {
+    /// the archived threads, most recently archived first
+    pub threads: Vec<ChannelApiType>,
+    /// a thread member object for each returned thread the current user has joined
+    pub members: Vec<ThreadMemberApiType>,
+    /// whether there are potentially additional threads that could be returned on a subsequent call
+    pub has_more: bool,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
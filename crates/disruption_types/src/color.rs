@@ -0,0 +1,139 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// An RGB color as it is used by embeds, roles and user banners.
+///
+/// Colors are serialized as the integer representation of their hexadecimal color code and
+/// displayed as `#RRGGBB`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[serde(transparent)]
+pub struct Color(u32);
+
+impl Color {
+    /// Blurple from the Discord brand palette
+    pub const BLURPLE: Color = Color(0x5865F2);
+    /// Green from the Discord brand palette
+    pub const GREEN: Color = Color(0x57F287);
+    /// Yellow from the Discord brand palette
+    pub const YELLOW: Color = Color(0xFEE75C);
+    /// Fuchsia from the Discord brand palette
+    pub const FUCHSIA: Color = Color(0xEB459E);
+    /// Red from the Discord brand palette
+    pub const RED: Color = Color(0xED4245);
+    /// White from the Discord brand palette
+    pub const WHITE: Color = Color(0xFFFFFF);
+    /// Black from the Discord brand palette
+    pub const BLACK: Color = Color(0x000000);
+
+    /// Create a color from the integer representation of its hexadecimal color code.
+    pub const fn new(value: u32) -> Self {
+        Color(value & 0xFFFFFF)
+    }
+
+    /// Create a color from its red, green and blue components.
+    pub const fn from_rgb(red: u8, green: u8, blue: u8) -> Self {
+        Color(((red as u32) << 16) | ((green as u32) << 8) | blue as u32)
+    }
+
+    /// Parse a hexadecimal color code like `#5865F2`, `5865F2` or `#FFF`.
+    pub fn from_hex(hex: &str) -> Result<Self, ColorParseError> {
+        let error = || ColorParseError {
+            input: hex.to_owned(),
+        };
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(error());
+        }
+
+        match digits.len() {
+            6 => u32::from_str_radix(digits, 16)
+                .map(Color)
+                .map_err(|_| error()),
+            3 => {
+                // shorthand notation, every digit is doubled
+                let value = u32::from_str_radix(digits, 16).map_err(|_| error())?;
+                let expand = |digit: u32| (digit << 4 | digit) as u8;
+                Ok(Color::from_rgb(
+                    expand(value >> 8),
+                    expand(value >> 4 & 0xF),
+                    expand(value & 0xF),
+                ))
+            }
+            _ => Err(error()),
+        }
+    }
+
+    /// The integer representation of the hexadecimal color code.
+    pub const fn value(&self) -> u32 {
+        self.0
+    }
+
+    /// The red component.
+    pub const fn red(&self) -> u8 {
+        (self.0 >> 16) as u8
+    }
+
+    /// The green component.
+    pub const fn green(&self) -> u8 {
+        (self.0 >> 8) as u8
+    }
+
+    /// The blue component.
+    pub const fn blue(&self) -> u8 {
+        self.0 as u8
+    }
+
+    /// The red, green and blue components.
+    pub const fn to_rgb(&self) -> (u8, u8, u8) {
+        (self.red(), self.green(), self.blue())
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:06X}", self.0)
+    }
+}
+
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
+impl From<u32> for Color {
+    fn from(value: u32) -> Self {
+        Color::new(value)
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((red, green, blue): (u8, u8, u8)) -> Self {
+        Color::from_rgb(red, green, blue)
+    }
+}
+
+impl From<Color> for u32 {
+    fn from(color: Color) -> Self {
+        color.value()
+    }
+}
+
+/// Error for a string that is not a valid hexadecimal color code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorParseError {
+    /// the input that failed to parse
+    pub input: String,
+}
+
+impl Display for ColorParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid hexadecimal color {:?}", self.input)
+    }
+}
+
+impl std::error::Error for ColorParseError {}
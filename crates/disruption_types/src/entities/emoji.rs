@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use super::UserApiType;
+use crate::cdn::CdnUrl;
 
 /// <https://discord.com/developers/docs/resources/emoji#emoji-object>
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -19,4 +20,16 @@ pub struct EmojiApiType {
     pub animated: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub available: Option<bool>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl EmojiApiType {
+    /// URL of a custom emoji, `None` for unicode emojis.
+    pub fn url(&self) -> Option<CdnUrl> {
+        let id = self.id.as_ref()?;
+        Some(CdnUrl::custom_emoji(id, self.animated.unwrap_or(false)))
+    }
 }
@@ -1,9 +1,14 @@
 use serde::{Deserialize, Serialize};
 
 use super::{
-    AvatarDecorationDataApiType, EmojiApiType, RoleApiType, UserApiType, WelcomeScreenApiType,
+    AvatarDecorationDataApiType, EmojiApiType, GuildMemberFlags, RoleApiType, SystemChannelFlags,
+    UserApiType, WelcomeScreenApiType,
 };
+use crate::cdn::CdnUrl;
+use crate::locale::Locale;
+use crate::nullable::Nullable;
 use crate::resources::StickerApiType;
+use crate::timestamp::Timestamp;
 
 /// <https://discord.com/developers/docs/resources/guild#guild-object>
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -67,7 +72,7 @@ pub struct GuildApiType {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub system_channel_id: Option<String>,
     /// system channel flags
-    pub system_channel_flags: u32,
+    pub system_channel_flags: SystemChannelFlags,
     /// the id of the channel where Community guilds can display rules and/or guidelines
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rules_channel_id: Option<String>,
@@ -92,7 +97,7 @@ pub struct GuildApiType {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub premium_subscription_count: Option<u32>,
     /// the preferred locale of a Community guild; used in server discovery and notices from Discord
-    pub preferred_locale: String,
+    pub preferred_locale: Locale,
     /// the id of the channel where admins and moderators of Community guilds receive notices from Discord
     #[serde(skip_serializing_if = "Option::is_none")]
     pub public_updates_channel_id: Option<String>,
@@ -124,6 +129,36 @@ pub struct GuildApiType {
     /// the incidents data for this guild
     #[serde(skip_serializing_if = "Option::is_none")]
     pub incidents_data: Option<IncidentsDataApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl GuildApiType {
+    /// URL of the guild's icon.
+    pub fn icon_url(&self) -> Option<CdnUrl> {
+        let hash = self.icon.as_ref()?;
+        Some(CdnUrl::guild_icon(&self.id, hash))
+    }
+
+    /// URL of the guild's banner.
+    pub fn banner_url(&self) -> Option<CdnUrl> {
+        let hash = self.banner.as_ref()?;
+        Some(CdnUrl::guild_banner(&self.id, hash))
+    }
+
+    /// URL of the guild's invite splash.
+    pub fn splash_url(&self) -> Option<CdnUrl> {
+        let hash = self.splash.as_ref()?;
+        Some(CdnUrl::guild_splash(&self.id, hash))
+    }
+
+    /// URL of the guild's discovery splash.
+    pub fn discovery_splash_url(&self) -> Option<CdnUrl> {
+        let hash = self.discovery_splash.as_ref()?;
+        Some(CdnUrl::guild_discovery_splash(&self.id, hash))
+    }
 }
 
 /// <https://discord.com/developers/docs/resources/guild#unavailable-guild-object>
@@ -131,6 +166,10 @@ pub struct GuildApiType {
 pub struct UnavailableGuildApiType {
     pub id: String,
     pub unavailable: bool,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/guild#guild-member-object>
@@ -151,17 +190,17 @@ pub struct GuildMemberApiType {
     /// array of role object ids
     pub roles: Vec<String>,
     /// when the user joined the guild
-    pub joined_at: String,
+    pub joined_at: Timestamp,
     /// when the user started boosting the guild
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub premium_since: Option<String>,
+    pub premium_since: Option<Timestamp>,
     /// whether the user is deafened in voice channels
     pub deaf: bool,
     /// whether the user is muted in voice channels
     pub mute: bool,
     /// guild member flags represented as a bit set, defaults to 0
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub flags: Option<u32>,
+    pub flags: Option<GuildMemberFlags>,
     /// whether the user has not yet passed the guild's Membership Screening requirements
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pending: Option<bool>,
@@ -170,10 +209,126 @@ pub struct GuildMemberApiType {
     pub permissions: Option<String>,
     /// when the user's timeout will expire and the user will be able to communicate in the guild again
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub communication_disabled_until: Option<String>,
+    pub communication_disabled_until: Option<Timestamp>,
     /// data for the member's guild avatar decoration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub avatar_decoration_data: Option<AvatarDecorationDataApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl GuildMemberApiType {
+    /// URL of the member's guild avatar, falling back to the avatar of the user.
+    pub fn avatar_url(&self, guild_id: &str) -> Option<CdnUrl> {
+        let user = self.user.as_ref()?;
+        Some(match &self.avatar {
+            Some(hash) => CdnUrl::guild_member_avatar(guild_id, &user.id, hash),
+            None => user.avatar_url(),
+        })
+    }
+}
+
+/// <https://discord.com/developers/docs/resources/guild#modify-guild-member-json-params>
+/// All fields are optional, fields set to null are reset
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ModifyGuildMemberApiType {
+    /// value to set user's nickname to
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub nick: Nullable<String>,
+    /// array of role ids the member is assigned
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub roles: Nullable<Vec<String>>,
+    /// whether the user is muted in voice channels
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub mute: Nullable<bool>,
+    /// whether the user is deafened in voice channels
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub deaf: Nullable<bool>,
+    /// id of channel to move user to (if they are connected to voice)
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub channel_id: Nullable<String>,
+    /// when the user's timeout will expire (up to 28 days in the future), null to remove the timeout
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub communication_disabled_until: Nullable<Timestamp>,
+    /// guild member flags
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub flags: Nullable<GuildMemberFlags>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ModifyGuildMemberApiType {
+    /// Time out the member until the given time, or remove the timeout with `None`.
+    pub fn timeout(until: Option<Timestamp>) -> Self {
+        ModifyGuildMemberApiType {
+            communication_disabled_until: until.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// <https://discord.com/developers/docs/resources/guild#create-guild-ban-json-params>
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CreateGuildBanApiType {
+    /// number of seconds to delete messages for, between 0 and 604800 (7 days)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_message_seconds: Option<u32>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl CreateGuildBanApiType {
+    /// Ban and delete the messages the user sent in the given number of seconds.
+    pub fn delete_messages(seconds: u32) -> Self {
+        CreateGuildBanApiType {
+            delete_message_seconds: Some(seconds),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
+    }
+}
+
+/// <https://discord.com/developers/docs/resources/guild#bulk-guild-ban-json-params>
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct BulkGuildBanApiType {
+    /// list of user ids to ban (max 200)
+    pub user_ids: Vec<String>,
+    /// number of seconds to delete messages for, between 0 and 604800 (7 days)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_message_seconds: Option<u32>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl BulkGuildBanApiType {
+    /// Ban the users with the given ids.
+    pub fn new(user_ids: impl IntoIterator<Item = impl ToString>) -> Self {
+        BulkGuildBanApiType {
+            user_ids: user_ids.into_iter().map(|id| id.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+/// <https://discord.com/developers/docs/resources/guild#bulk-guild-ban-bulk-ban-response>
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BulkBanResponseApiType {
+    /// list of user ids, that were successfully banned
+    pub banned_users: Vec<String>,
+    /// list of user ids, that were not banned
+    pub failed_users: Vec<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/guild#integration-object>
@@ -211,7 +366,7 @@ pub struct IntegrationApiType {
     pub account: IntegrationAccountApiType,
     /// when this integration was last synced
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub synced_at: Option<String>,
+    pub synced_at: Option<Timestamp>,
     /// how many subscribers this integration has
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subscriber_count: Option<u32>,
@@ -224,6 +379,10 @@ pub struct IntegrationApiType {
     /// the scopes the application has been authorized for
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scopes: Option<Vec<String>>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/guild#integration-account-object>
@@ -233,6 +392,10 @@ pub struct IntegrationAccountApiType {
     pub id: String,
     /// name of the account
     pub name: String,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/guild#ban-object>
@@ -243,6 +406,10 @@ pub struct BanApiType {
     pub reason: Option<String>,
     /// the banned user
     pub user: UserApiType,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/guild#incidents-data-object>
@@ -250,16 +417,20 @@ pub struct BanApiType {
 pub struct IncidentsDataApiType {
     /// when invites get enabled again
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub invites_disabled_until: Option<String>, // ISO8601 timestamp
+    pub invites_disabled_until: Option<Timestamp>,
     /// when direct messages get enabled again
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dms_disabled_until: Option<String>, // ISO8601 timestamp
+    pub dms_disabled_until: Option<Timestamp>,
     /// when the dm spam was detected
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dm_spam_detected_at: Option<String>, // ISO8601 timestamp
+    pub dm_spam_detected_at: Option<Timestamp>,
     /// when the raid was detected
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub raid_detected_at: Option<String>, // ISO8601 timestamp
+    pub raid_detected_at: Option<Timestamp>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/guild#integration-application-object>
@@ -276,4 +447,8 @@ pub struct IntegrationApplicationApiType {
     /// the bot associated with this application
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bot: Option<UserApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -1,5 +1,4 @@
 use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
 /// <https://discord.com/developers/docs/resources/guild#guild-onboarding-object>
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -14,6 +13,10 @@ pub struct GuildOnboardingApiType {
     pub enabled: bool,
     /// Current mode of onboarding
     pub mode: OnboardingMode,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/guild#guild-onboarding-object-onboarding-prompt-structure>
@@ -34,6 +37,10 @@ pub struct OnboardingPromptApiType {
     pub required: bool,
     /// Indicates whether the prompt is present in the onboarding flow
     pub in_onboarding: bool,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/guild#guild-onboarding-object-prompt-option-structure>
@@ -53,6 +60,10 @@ pub struct PromptOptionApiType {
     /// Description of the option
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Emoji structure for prompt options
@@ -67,24 +78,28 @@ pub struct PromptEmojiApiType {
     /// whether this emoji is animated
     #[serde(skip_serializing_if = "Option::is_none")]
     pub animated: Option<bool>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/resources/guild#guild-onboarding-object-onboarding-mode>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-#[allow(non_camel_case_types)]
-pub enum OnboardingMode {
-    /// Counts only Default Channels towards constraints
-    ONBOARDING_DEFAULT = 0,
-    /// Counts Default Channels and Questions towards constraints
-    ONBOARDING_ADVANCED = 1,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/guild#guild-onboarding-object-onboarding-mode>
+    #[allow(non_camel_case_types)]
+    pub enum OnboardingMode: u8 {
+        /// Counts only Default Channels towards constraints
+        ONBOARDING_DEFAULT = 0,
+        /// Counts Default Channels and Questions towards constraints
+        ONBOARDING_ADVANCED = 1,
+    }
 }
 
-/// <https://discord.com/developers/docs/resources/guild#guild-onboarding-object-prompt-types>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-#[allow(non_camel_case_types)]
-pub enum PromptType {
-    MULTIPLE_CHOICE = 0,
-    DROPDOWN = 1,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/guild#guild-onboarding-object-prompt-types>
+    #[allow(non_camel_case_types)]
+    pub enum PromptType: u8 {
+        MULTIPLE_CHOICE = 0,
+        DROPDOWN = 1,
+    }
 }
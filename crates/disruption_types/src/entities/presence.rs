@@ -14,6 +14,10 @@ pub struct PresenceUpdateApiType {
     pub activities: Vec<ActivityApiType>,
     /// user's platform-dependent status
     pub client_status: ClientStatusApiType,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/events/gateway-events#activity-object>
@@ -67,48 +71,31 @@ pub struct ActivityApiType {
     pub instance: Option<bool>,
     /// activity flags ORd together, describes what the payload includes
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub flags: Option<u32>,
+    pub flags: Option<ActivityFlagsApiType>,
     /// the custom buttons shown in the Rich Presence (max 2)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub buttons: Option<Vec<ActivityButtonApiType>>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-types>
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-#[serde(from = "u8", into = "u8")]
-pub enum ActivityTypeApiType {
-    /// Playing {name}
-    Playing = 0,
-    /// Streaming {details}
-    Streaming = 1,
-    /// Listening to {name}
-    Listening = 2,
-    /// Watching {name}
-    Watching = 3,
-    /// {emoji} {state}
-    Custom = 4,
-    /// Competing in {name}
-    Competing = 5,
-}
-
-impl From<u8> for ActivityTypeApiType {
-    fn from(value: u8) -> Self {
-        match value {
-            0 => ActivityTypeApiType::Playing,
-            1 => ActivityTypeApiType::Streaming,
-            2 => ActivityTypeApiType::Listening,
-            3 => ActivityTypeApiType::Watching,
-            4 => ActivityTypeApiType::Custom,
-            5 => ActivityTypeApiType::Competing,
-            _ => ActivityTypeApiType::Playing, // Default to Playing for unknown types
-        }
-    }
-}
-
-impl From<ActivityTypeApiType> for u8 {
-    fn from(value: ActivityTypeApiType) -> Self {
-        value as u8
+wire_enum! {
+    /// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-types>
+    pub enum ActivityTypeApiType: u8 {
+        /// Playing {name}
+        Playing = 0,
+        /// Streaming {details}
+        Streaming = 1,
+        /// Listening to {name}
+        Listening = 2,
+        /// Watching {name}
+        Watching = 3,
+        /// {emoji} {state}
+        Custom = 4,
+        /// Competing in {name}
+        Competing = 5,
     }
 }
 
@@ -121,6 +108,10 @@ pub struct ActivityTimestampsApiType {
     /// unix time (in milliseconds) of when the activity ends
     #[serde(skip_serializing_if = "Option::is_none")]
     pub end: Option<u64>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-emoji>
@@ -134,6 +125,10 @@ pub struct ActivityEmojiApiType {
     /// whether this emoji is animated
     #[serde(skip_serializing_if = "Option::is_none")]
     pub animated: Option<bool>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-party>
@@ -145,6 +140,10 @@ pub struct ActivityPartyApiType {
     /// used to show the party's current and maximum size [current_size, max_size]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub size: Option<[u32; 2]>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-assets>
@@ -168,6 +167,10 @@ pub struct ActivityAssetsApiType {
     /// URL that is opened when clicking on the small image
     #[serde(skip_serializing_if = "Option::is_none")]
     pub small_url: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-secrets>
@@ -182,21 +185,25 @@ pub struct ActivitySecretsApiType {
     /// the secret for a specific instanced match
     #[serde(skip_serializing_if = "Option::is_none", rename = "match")]
     pub match_: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-flags>
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u32)]
-pub enum ActivityFlagsApiType {
-    Instance = 1 << 0,
-    Join = 1 << 1,
-    Spectate = 1 << 2,
-    JoinRequest = 1 << 3,
-    Sync = 1 << 4,
-    Play = 1 << 5,
-    PartyPrivacyFriends = 1 << 6,
-    PartyPrivacyVoiceChannel = 1 << 7,
-    Embedded = 1 << 8,
+flags! {
+    /// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-flags>
+    pub struct ActivityFlagsApiType: u32 {
+        const INSTANCE = 1 << 0;
+        const JOIN = 1 << 1;
+        const SPECTATE = 1 << 2;
+        const JOIN_REQUEST = 1 << 3;
+        const SYNC = 1 << 4;
+        const PLAY = 1 << 5;
+        const PARTY_PRIVACY_FRIENDS = 1 << 6;
+        const PARTY_PRIVACY_VOICE_CHANNEL = 1 << 7;
+        const EMBEDDED = 1 << 8;
+    }
 }
 
 /// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-buttons>
@@ -206,6 +213,10 @@ pub struct ActivityButtonApiType {
     pub label: String,
     /// the url opened when clicking the button (1-512 characters)
     pub url: String,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/events/gateway-events#client-status-object>
@@ -220,4 +231,8 @@ pub struct ClientStatusApiType {
     /// the user's status set for an active web (browser, bot account) application session
     #[serde(skip_serializing_if = "Option::is_none")]
     pub web: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
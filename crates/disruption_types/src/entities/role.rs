@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+use crate::cdn::CdnUrl;
+use crate::color::Color;
+
 /// <https://discord.com/developers/docs/topics/permissions#role-object-role-structure>
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct RoleApiType {
@@ -7,8 +10,11 @@ pub struct RoleApiType {
     pub id: String,
     /// role name
     pub name: String,
-    /// integer representation of hexadecimal color code
-    pub color: u64,
+    /// role color, deprecated in favor of colors
+    pub color: Color,
+    /// the role's colors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub colors: Option<RoleColorsApiType>,
     /// if this role is pinned in the user listing
     pub hoist: bool,
     /// role icon hash
@@ -30,7 +36,101 @@ pub struct RoleApiType {
     pub tags: Option<RoleTagApiType>,
     /// role flags combined as a bitfield
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub flags: Option<u32>,
+    pub flags: Option<RoleFlags>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl RoleApiType {
+    /// URL of the role's icon.
+    pub fn icon_url(&self) -> Option<CdnUrl> {
+        let hash = self.icon.as_ref()?;
+        Some(CdnUrl::role_icon(&self.id, hash))
+    }
+}
+
+/// <https://discord.com/developers/docs/topics/permissions#role-object-role-colors-object>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct RoleColorsApiType {
+    /// the primary color of the role
+    pub primary_color: Color,
+    /// the secondary color of the role, making it a gradient
+    pub secondary_color: Option<Color>,
+    /// the tertiary color of the role, turning the gradient into a holographic style
+    pub tertiary_color: Option<Color>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl RoleColorsApiType {
+    /// A solid color.
+    pub fn solid(color: Color) -> Self {
+        RoleColorsApiType {
+            primary_color: color,
+            ..Default::default()
+        }
+    }
+
+    /// A gradient between two colors.
+    pub fn gradient(primary: Color, secondary: Color) -> Self {
+        RoleColorsApiType {
+            primary_color: primary,
+            secondary_color: Some(secondary),
+            ..Default::default()
+        }
+    }
+
+    /// Whether the role is displayed with a gradient.
+    pub fn is_gradient(&self) -> bool {
+        self.secondary_color.is_some()
+    }
+}
+
+/// <https://discord.com/developers/docs/resources/guild#create-guild-role-json-params>
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CreateRoleApiType {
+    /// name of the role, max 100 characters (default "new role")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// bitwise value of the enabled/disabled permissions (default @everyone permissions in guild)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<String>,
+    /// RGB color value (default 0), deprecated in favor of colors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+    /// the role's colors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub colors: Option<RoleColorsApiType>,
+    /// whether the role should be displayed separately in the sidebar (default false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hoist: Option<bool>,
+    /// the role's icon image (if the guild has the ROLE_ICONS feature)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// the role's unicode emoji as a standard emoji (if the guild has the ROLE_ICONS feature)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unicode_emoji: Option<String>,
+    /// whether the role should be mentionable (default false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mentionable: Option<bool>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl CreateRoleApiType {
+    /// Create a role with the given name.
+    pub fn new(name: impl ToString) -> Self {
+        CreateRoleApiType {
+            name: Some(name.to_string()),
+            ..Default::default()
+        }
+    }
 }
 
 /// <https://discord.com/developers/docs/topics/permissions#role-object-role-tags-structure>
@@ -54,11 +154,16 @@ pub struct RoleTagApiType {
     /// whether this role is a guild's linked role
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_connections: Option<bool>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/topics/permissions#role-object-role-flags>
-#[allow(non_camel_case_types)]
-pub enum RoleFlags {
-    /// role can be selected by members in an onboarding prompt
-    IN_PROMPT = 1 << 0,
+flags! {
+    /// <https://discord.com/developers/docs/topics/permissions#role-object-role-flags>
+    pub struct RoleFlags: u32 {
+        /// role can be selected by members in an onboarding prompt
+        const IN_PROMPT = 1 << 0;
+    }
 }
@@ -1,5 +1,4 @@
 use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use super::UserApiType;
 
@@ -16,6 +15,10 @@ pub struct TeamApiType {
     pub name: String,
     /// the user id of the current team owner
     pub owner_user_id: String,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/topics/teams#data-models-team-member-object>
@@ -28,12 +31,16 @@ pub struct TeamMemberApiType {
     pub team_id: String,
     /// the avatar, discriminator, id, and username of the user
     pub user: UserApiType,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/topics/teams#data-models-membership-state-enum>
-#[derive(Serialize_repr, Deserialize_repr, PartialEq, Debug, Clone)]
-#[repr(u8)]
-pub enum MembershipStateApiType {
-    INVITED = 1,
-    ACCEPTED = 2,
+wire_enum! {
+    /// <https://discord.com/developers/docs/topics/teams#data-models-membership-state-enum>
+    pub enum MembershipStateApiType: u8 {
+        INVITED = 1,
+        ACCEPTED = 2,
+    }
 }
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use super::IntegrationApiType;
+use super::{IntegrationApiType, PremiumType, UserFlags};
+use crate::cdn::CdnUrl;
+use crate::color::Color;
+use crate::locale::Locale;
 
 /// <https://discord.com/developers/docs/resources/user#user-object>
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
@@ -28,12 +31,12 @@ pub struct UserApiType {
     /// the user's banner hash
     #[serde(skip_serializing_if = "Option::is_none")]
     pub banner: Option<String>,
-    /// the user's banner color encoded as an integer representation of hexadecimal color code
+    /// the user's banner color
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub accent_color: Option<u64>,
+    pub accent_color: Option<Color>,
     /// the user's chosen language option
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// whether the email on this account has been verified
     #[serde(skip_serializing_if = "Option::is_none")]
     pub verified: Option<bool>,
@@ -42,13 +45,13 @@ pub struct UserApiType {
     pub email: Option<String>,
     /// the flags on a user's account
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub flags: Option<u64>,
+    pub flags: Option<UserFlags>,
     /// the type of Nitro subscription on a user's account
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub premium_type: Option<u64>,
+    pub premium_type: Option<PremiumType>,
     /// the public flags on a user's account
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub public_flags: Option<u64>,
+    pub public_flags: Option<UserFlags>,
     /// data for the user's avatar decoration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub avatar_decoration_data: Option<AvatarDecorationDataApiType>,
@@ -58,6 +61,31 @@ pub struct UserApiType {
     /// the user's primary guild
     #[serde(skip_serializing_if = "Option::is_none")]
     pub primary_guild: Option<UserPrimaryGuildApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl UserApiType {
+    /// URL of the user's avatar, or of their default avatar if they have none.
+    pub fn avatar_url(&self) -> CdnUrl {
+        match &self.avatar {
+            Some(hash) => CdnUrl::user_avatar(&self.id, hash),
+            None => self.default_avatar_url(),
+        }
+    }
+
+    /// URL of the default avatar of the user.
+    pub fn default_avatar_url(&self) -> CdnUrl {
+        CdnUrl::default_user_avatar(&self.id, &self.discriminator)
+    }
+
+    /// URL of the user's banner.
+    pub fn banner_url(&self) -> Option<CdnUrl> {
+        let hash = self.banner.as_ref()?;
+        Some(CdnUrl::user_banner(&self.id, hash))
+    }
 }
 
 /// <https://discord.com/developers/docs/resources/user#avatar-decoration-data-object>
@@ -67,6 +95,10 @@ pub struct AvatarDecorationDataApiType {
     pub asset: String,
     /// id of the avatar decoration's SKU
     pub sku_id: String,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/user#collectibles>
@@ -75,6 +107,10 @@ pub struct CollectiblesApiType {
     /// object mapping of nameplate data
     #[serde(skip_serializing_if = "Option::is_none")]
     pub nameplate: Option<NameplateApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/user#nameplate-nameplate-structure>
@@ -88,6 +124,10 @@ pub struct NameplateApiType {
     pub label: String,
     /// background color of the nameplate
     pub palette: String,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/user#user-object-user-primary-guild>
@@ -105,6 +145,10 @@ pub struct UserPrimaryGuildApiType {
     /// the server tag badge hash
     #[serde(skip_serializing_if = "Option::is_none")]
     pub badge: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/user#connection-object>
@@ -134,4 +178,8 @@ pub struct ConnectionApiType {
     /// visibility of this connection
     #[serde(skip_serializing_if = "Option::is_none")]
     pub visibility: Option<u8>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
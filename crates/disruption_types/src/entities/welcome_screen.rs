@@ -8,6 +8,10 @@ pub struct WelcomeScreenApiType {
     pub description: Option<String>,
     /// the channels shown in the welcome screen, up to 5
     pub welcome_channels: Vec<WelcomeScreenChannelApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/guild#welcome-screen-object-welcome-screen-channel-structure>
@@ -23,4 +27,8 @@ pub struct WelcomeScreenChannelApiType {
     /// the emoji name if custom, the unicode character if standard, or null if no emoji is set
     #[serde(skip_serializing_if = "Option::is_none")]
     pub emoji_name: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
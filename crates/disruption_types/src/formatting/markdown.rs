@@ -0,0 +1,103 @@
+/// Zero width space, used to break up markup without visibly changing the text
+const ZERO_WIDTH_SPACE: char = '\u{200B}';
+
+/// Characters that have a meaning in Discord markdown
+const MARKDOWN_CHARACTERS: &[char] = &[
+    '\\', '*', '_', '~', '`', '|', '>', '#', '-', '[', ']', '(', ')',
+];
+
+/// **bold** text.
+pub fn bold(text: &str) -> String {
+    format!("**{text}**")
+}
+
+/// *italic* text.
+pub fn italic(text: &str) -> String {
+    format!("*{text}*")
+}
+
+/// Underlined text.
+pub fn underline(text: &str) -> String {
+    format!("__{text}__")
+}
+
+/// ~~strikethrough~~ text.
+pub fn strikethrough(text: &str) -> String {
+    format!("~~{text}~~")
+}
+
+/// Text that is hidden until it is clicked.
+pub fn spoiler(text: &str) -> String {
+    format!("||{text}||")
+}
+
+/// A heading of level 1 to 3, other levels are clamped.
+pub fn heading(level: u8, text: &str) -> String {
+    let level = level.clamp(1, 3) as usize;
+    format!("{} {text}", "#".repeat(level))
+}
+
+/// A link with custom text, `[text](url)`.
+pub fn masked_link(text: &str, url: &str) -> String {
+    format!("[{text}]({url})")
+}
+
+/// `inline code`, using double backticks if the code itself contains backticks.
+pub fn inline_code(code: &str) -> String {
+    if code.contains('`') {
+        format!("`` {code} ``")
+    } else {
+        format!("`{code}`")
+    }
+}
+
+/// A code block with optional syntax highlighting.
+pub fn code_block(language: Option<&str>, code: &str) -> String {
+    let code = code.replace("```", &format!("`{ZERO_WIDTH_SPACE}``"));
+    format!("```{}\n{code}\n```", language.unwrap_or_default())
+}
+
+/// Quote every line of the text.
+pub fn quote(text: &str) -> String {
+    text.lines()
+        .map(|line| format!("> {line}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Quote the text and everything after it.
+pub fn block_quote(text: &str) -> String {
+    format!(">>> {text}")
+}
+
+/// Escape markdown in user supplied text, so it is displayed as is.
+pub fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if MARKDOWN_CHARACTERS.contains(&c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Break up mentions in user supplied text, so they neither ping anyone nor render as mentions.
+///
+/// This neutralises `@everyone`, `@here` and all `<...>` markup like `<@id>` or `<#id>`. Pings can
+/// also be prevented with allowed mentions.
+pub fn escape_mentions(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        escaped.push(c);
+        if c == '@' || c == '<' {
+            escaped.push(ZERO_WIDTH_SPACE);
+        }
+    }
+    escaped
+}
+
+/// Escape both markdown and mentions in user supplied text.
+pub fn escape(text: &str) -> String {
+    escape_markdown(&escape_mentions(text))
+}
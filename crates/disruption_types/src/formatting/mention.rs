@@ -0,0 +1,135 @@
+use crate::channel::ChannelApiType;
+use crate::entities::{EmojiApiType, RoleApiType, UserApiType};
+use crate::interactions::ApplicationCommandApiType;
+use crate::timestamp::Timestamp;
+
+/// Mention of a user, `<@id>`.
+pub fn user_mention(user_id: &str) -> String {
+    format!("<@{user_id}>")
+}
+
+/// Mention of a role, `<@&id>`.
+pub fn role_mention(role_id: &str) -> String {
+    format!("<@&{role_id}>")
+}
+
+/// Mention of a channel, `<#id>`.
+pub fn channel_mention(channel_id: &str) -> String {
+    format!("<#{channel_id}>")
+}
+
+/// Mention of a slash command, `</name:id>`.
+///
+/// To mention a subcommand (group), pass its full name, e.g. `"tag get"`.
+pub fn slash_command_mention(name: &str, command_id: &str) -> String {
+    format!("</{name}:{command_id}>")
+}
+
+/// A custom emoji, `<:name:id>` or `<a:name:id>` if animated.
+pub fn custom_emoji(name: &str, emoji_id: &str, animated: bool) -> String {
+    let prefix = if animated { "a" } else { "" };
+    format!("<{prefix}:{name}:{emoji_id}>")
+}
+
+/// <https://discord.com/developers/docs/reference#message-formatting-timestamp-styles>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimestampStyle {
+    /// 16:20
+    ShortTime,
+    /// 16:20:30
+    LongTime,
+    /// 20/04/2021
+    ShortDate,
+    /// 20 April 2021
+    LongDate,
+    /// 20 April 2021 16:20 (the default)
+    ShortDateTime,
+    /// Tuesday, 20 April 2021 16:20
+    LongDateTime,
+    /// 2 months ago
+    Relative,
+}
+
+impl TimestampStyle {
+    /// The flag of the style used in the timestamp markup.
+    pub fn flag(&self) -> char {
+        match self {
+            TimestampStyle::ShortTime => 't',
+            TimestampStyle::LongTime => 'T',
+            TimestampStyle::ShortDate => 'd',
+            TimestampStyle::LongDate => 'D',
+            TimestampStyle::ShortDateTime => 'f',
+            TimestampStyle::LongDateTime => 'F',
+            TimestampStyle::Relative => 'R',
+        }
+    }
+
+    /// The style of a flag used in the timestamp markup.
+    pub fn from_flag(flag: char) -> Option<Self> {
+        Some(match flag {
+            't' => TimestampStyle::ShortTime,
+            'T' => TimestampStyle::LongTime,
+            'd' => TimestampStyle::ShortDate,
+            'D' => TimestampStyle::LongDate,
+            'f' => TimestampStyle::ShortDateTime,
+            'F' => TimestampStyle::LongDateTime,
+            'R' => TimestampStyle::Relative,
+            _ => return None,
+        })
+    }
+}
+
+/// A timestamp that is displayed in the local time of the reader, `<t:unix>` or `<t:unix:style>`.
+pub fn timestamp_mention(timestamp: Timestamp, style: Option<TimestampStyle>) -> String {
+    match style {
+        Some(style) => format!("<t:{}:{}>", timestamp.unix_secs(), style.flag()),
+        None => format!("<t:{}>", timestamp.unix_secs()),
+    }
+}
+
+/// Something that can be mentioned in message content
+pub trait Mentionable {
+    /// The markup that mentions this entity.
+    fn mention(&self) -> String;
+}
+
+impl Mentionable for UserApiType {
+    fn mention(&self) -> String {
+        user_mention(&self.id)
+    }
+}
+
+impl Mentionable for RoleApiType {
+    fn mention(&self) -> String {
+        role_mention(&self.id)
+    }
+}
+
+impl Mentionable for ChannelApiType {
+    fn mention(&self) -> String {
+        channel_mention(&self.id)
+    }
+}
+
+impl Mentionable for ApplicationCommandApiType {
+    fn mention(&self) -> String {
+        slash_command_mention(&self.name, &self.id)
+    }
+}
+
+impl Mentionable for EmojiApiType {
+    /// Custom emojis are rendered as `<:name:id>`, unicode emojis as the emoji itself.
+    fn mention(&self) -> String {
+        let name = self.name.as_deref().unwrap_or_default();
+        match &self.id {
+            Some(id) => custom_emoji(name, id, self.animated.unwrap_or(false)),
+            None => name.to_owned(),
+        }
+    }
+}
+
+impl Mentionable for Timestamp {
+    fn mention(&self) -> String {
+        timestamp_mention(*self, None)
+    }
+}
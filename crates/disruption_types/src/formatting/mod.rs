@@ -0,0 +1,11 @@
+//! Building and parsing message content with mentions, timestamps and markdown.
+//!
+//! <https://discord.com/developers/docs/reference#message-formatting>
+
+mod markdown;
+mod mention;
+mod parse;
+
+pub use self::markdown::*;
+pub use self::mention::*;
+pub use self::parse::*;
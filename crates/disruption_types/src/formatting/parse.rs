@@ -0,0 +1,588 @@
+use std::ops::Range;
+
+use crate::timestamp::Timestamp;
+
+use super::TimestampStyle;
+
+/// Inline styles of Discord markdown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Style {
+    Bold,
+    Italic,
+    Underline,
+    Strikethrough,
+    Spoiler,
+}
+
+/// A node of parsed message content
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node<'a> {
+    /// what the node represents
+    pub kind: NodeKind<'a>,
+    /// byte range of the node, including its markup, in the parsed content
+    pub span: Range<usize>,
+}
+
+/// The different kinds of nodes in message content
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NodeKind<'a> {
+    /// plain text, escaped characters are text without their backslash
+    Text(&'a str),
+    /// `<@id>` or `<@!id>`, with the id of the user
+    UserMention(&'a str),
+    /// `<@&id>`, with the id of the role
+    RoleMention(&'a str),
+    /// `<#id>`, with the id of the channel
+    ChannelMention(&'a str),
+    /// `@everyone`
+    Everyone,
+    /// `@here`
+    Here,
+    /// `</name:id>`, name includes subcommands
+    SlashCommand { name: &'a str, id: &'a str },
+    /// `<:name:id>` or `<a:name:id>`
+    Emoji {
+        name: &'a str,
+        id: &'a str,
+        animated: bool,
+    },
+    /// `<t:unix>` or `<t:unix:style>`
+    Timestamp {
+        timestamp: Timestamp,
+        style: Option<TimestampStyle>,
+    },
+    /// a bare `http(s)://` link or one in angle brackets
+    Link(&'a str),
+    /// `[text](url)`
+    MaskedLink {
+        url: &'a str,
+        children: Vec<Node<'a>>,
+    },
+    /// `` `code` ``
+    InlineCode(&'a str),
+    /// a fenced code block with optional language
+    CodeBlock {
+        language: Option<&'a str>,
+        code: &'a str,
+    },
+    /// text with an inline style
+    Styled {
+        style: Style,
+        children: Vec<Node<'a>>,
+    },
+    /// `> line` or `>>> everything after`
+    Quote(Vec<Node<'a>>),
+    /// `# line` up to `### line`
+    Heading { level: u8, children: Vec<Node<'a>> },
+    /// `-# line`
+    Subtext(Vec<Node<'a>>),
+}
+
+impl<'a> Node<'a> {
+    /// Nested nodes of styles, links and block elements.
+    pub fn children(&self) -> &[Node<'a>] {
+        match &self.kind {
+            NodeKind::MaskedLink { children, .. }
+            | NodeKind::Styled { children, .. }
+            | NodeKind::Quote(children)
+            | NodeKind::Heading { children, .. }
+            | NodeKind::Subtext(children) => children,
+            _ => &[],
+        }
+    }
+}
+
+/// Message content parsed into Discord markdown, mentions and links
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedContent<'a> {
+    nodes: Vec<Node<'a>>,
+}
+
+impl<'a> ParsedContent<'a> {
+    /// Parse message content.
+    pub fn parse(content: &'a str) -> Self {
+        let parser = Parser { src: content };
+        ParsedContent {
+            nodes: parser.parse(0, content.len(), true),
+        }
+    }
+
+    /// Top-level nodes of the content.
+    pub fn nodes(&self) -> &[Node<'a>] {
+        &self.nodes
+    }
+
+    /// All nodes, including nested ones, in depth-first order.
+    pub fn iter(&self) -> impl Iterator<Item = &Node<'a>> {
+        let mut stack: Vec<&Node<'a>> = self.nodes.iter().rev().collect();
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children().iter().rev());
+            Some(node)
+        })
+    }
+
+    /// Ids of all mentioned users.
+    pub fn user_mentions(&self) -> Vec<&'a str> {
+        self.iter()
+            .filter_map(|node| match node.kind {
+                NodeKind::UserMention(id) => Some(id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Ids of all mentioned roles.
+    pub fn role_mentions(&self) -> Vec<&'a str> {
+        self.iter()
+            .filter_map(|node| match node.kind {
+                NodeKind::RoleMention(id) => Some(id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Ids of all mentioned channels.
+    pub fn channel_mentions(&self) -> Vec<&'a str> {
+        self.iter()
+            .filter_map(|node| match node.kind {
+                NodeKind::ChannelMention(id) => Some(id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// URLs of all links, bare and masked.
+    pub fn links(&self) -> Vec<&'a str> {
+        self.iter()
+            .filter_map(|node| match node.kind {
+                NodeKind::Link(url) | NodeKind::MaskedLink { url, .. } => Some(url),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Whether the content mentions `@everyone` or `@here`.
+    pub fn mentions_everyone(&self) -> bool {
+        self.iter()
+            .any(|node| matches!(node.kind, NodeKind::Everyone | NodeKind::Here))
+    }
+
+    /// The content without any formatting and invisible characters.
+    ///
+    /// Mentions are rendered as `@id`, `@&id` and `#id`, custom emojis as `:name:` and masked links
+    /// as `text (url)`, so hidden parts of the content can not bypass filters.
+    pub fn plain_text(&self) -> String {
+        let mut text = String::new();
+        write_plain_text(&self.nodes, &mut text);
+        text.retain(|c| !is_invisible(c));
+        text
+    }
+}
+
+/// Strip all formatting and invisible characters from message content.
+pub fn to_plain_text(content: &str) -> String {
+    ParsedContent::parse(content).plain_text()
+}
+
+fn write_plain_text(nodes: &[Node], text: &mut String) {
+    for node in nodes {
+        match &node.kind {
+            NodeKind::Text(value) | NodeKind::Link(value) | NodeKind::InlineCode(value) => {
+                text.push_str(value)
+            }
+            NodeKind::CodeBlock { code, .. } => text.push_str(code),
+            NodeKind::UserMention(id) => text.push_str(&format!("@{id}")),
+            NodeKind::RoleMention(id) => text.push_str(&format!("@&{id}")),
+            NodeKind::ChannelMention(id) => text.push_str(&format!("#{id}")),
+            NodeKind::Everyone => text.push_str("@everyone"),
+            NodeKind::Here => text.push_str("@here"),
+            NodeKind::SlashCommand { name, .. } => text.push_str(&format!("/{name}")),
+            NodeKind::Emoji { name, .. } => text.push_str(&format!(":{name}:")),
+            NodeKind::Timestamp { timestamp, .. } => text.push_str(&timestamp.to_string()),
+            NodeKind::MaskedLink { url, children } => {
+                write_plain_text(children, text);
+                text.push_str(&format!(" ({url})"));
+            }
+            NodeKind::Styled { children, .. }
+            | NodeKind::Quote(children)
+            | NodeKind::Heading { children, .. }
+            | NodeKind::Subtext(children) => write_plain_text(children, text),
+        }
+    }
+}
+
+/// Characters that are not rendered, but can be used to break up words
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}'
+            | '\u{034F}'
+            | '\u{061C}'
+            | '\u{115F}'
+            | '\u{1160}'
+            | '\u{17B4}'
+            | '\u{17B5}'
+            | '\u{180E}'
+            | '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{2066}'..='\u{206F}'
+            | '\u{3164}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{FEFF}'
+            | '\u{FFA0}'
+    )
+}
+
+fn is_id(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+fn is_url(value: &str) -> bool {
+    ["https://", "http://"]
+        .iter()
+        .any(|scheme| value.len() > scheme.len() && value.starts_with(scheme))
+}
+
+struct Parser<'a> {
+    src: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn parse(&self, start: usize, end: usize, blocks: bool) -> Vec<Node<'a>> {
+        let mut nodes = Vec::new();
+        let mut text_start = start;
+        let mut pos = start;
+
+        while pos < end {
+            let at_line_start = pos == 0 || self.src.as_bytes()[pos - 1] == b'\n';
+            let parsed = match blocks && at_line_start {
+                true => self.block(pos, end),
+                false => None,
+            }
+            .or_else(|| self.inline(pos, end));
+
+            match parsed {
+                Some(node) => {
+                    self.push_text(&mut nodes, text_start, pos);
+                    pos = node.span.end;
+                    text_start = pos;
+                    nodes.push(node);
+                }
+                None => pos += self.char_len(pos),
+            }
+        }
+
+        self.push_text(&mut nodes, text_start, end);
+        nodes
+    }
+
+    fn push_text(&self, nodes: &mut Vec<Node<'a>>, start: usize, end: usize) {
+        if start < end {
+            nodes.push(Node {
+                kind: NodeKind::Text(&self.src[start..end]),
+                span: start..end,
+            });
+        }
+    }
+
+    fn char_len(&self, pos: usize) -> usize {
+        self.src[pos..].chars().next().map_or(1, char::len_utf8)
+    }
+
+    fn line_end(&self, pos: usize, end: usize) -> usize {
+        self.src[pos..end]
+            .find('\n')
+            .map_or(end, |offset| pos + offset)
+    }
+
+    fn block(&self, pos: usize, end: usize) -> Option<Node<'a>> {
+        let rest = &self.src[pos..end];
+
+        if rest.starts_with(">>> ") {
+            return Some(Node {
+                kind: NodeKind::Quote(self.parse(pos + 4, end, false)),
+                span: pos..end,
+            });
+        }
+
+        let line_end = self.line_end(pos, end);
+        let (prefix, make): (usize, fn(Vec<Node<'a>>) -> NodeKind<'a>) = if rest.starts_with("> ") {
+            (2, NodeKind::Quote)
+        } else if rest.starts_with("-# ") {
+            (3, NodeKind::Subtext)
+        } else if rest.starts_with("### ") {
+            (4, |children| NodeKind::Heading { level: 3, children })
+        } else if rest.starts_with("## ") {
+            (3, |children| NodeKind::Heading { level: 2, children })
+        } else if rest.starts_with("# ") {
+            (2, |children| NodeKind::Heading { level: 1, children })
+        } else {
+            return None;
+        };
+
+        Some(Node {
+            kind: make(self.parse(pos + prefix, line_end, false)),
+            span: pos..line_end,
+        })
+    }
+
+    fn inline(&self, pos: usize, end: usize) -> Option<Node<'a>> {
+        let rest = &self.src[pos..end];
+
+        match rest.as_bytes()[0] {
+            b'\\' => self.escape(pos, end),
+            b'`' => self.code(pos, end),
+            b'<' => self.angle(pos, end),
+            b'@' if rest.starts_with("@everyone") => Some(Node {
+                kind: NodeKind::Everyone,
+                span: pos..pos + 9,
+            }),
+            b'@' if rest.starts_with("@here") => Some(Node {
+                kind: NodeKind::Here,
+                span: pos..pos + 5,
+            }),
+            b'h' if is_url(rest) => self.link(pos, end),
+            b'[' => self.masked_link(pos, end),
+            b'*' | b'_' | b'~' | b'|' => self.styled(pos, end),
+            _ => None,
+        }
+    }
+
+    fn escape(&self, pos: usize, end: usize) -> Option<Node<'a>> {
+        let escaped = self.src[pos + 1..end].chars().next()?;
+        if !escaped.is_ascii_punctuation() {
+            return None;
+        }
+
+        Some(Node {
+            kind: NodeKind::Text(&self.src[pos + 1..pos + 2]),
+            span: pos..pos + 2,
+        })
+    }
+
+    fn code(&self, pos: usize, end: usize) -> Option<Node<'a>> {
+        let rest = &self.src[pos..end];
+
+        if let Some(after_fence) = rest.strip_prefix("```") {
+            let close = after_fence.find("```")?;
+            let inner = &after_fence[..close];
+            let (language, code) = match inner.split_once('\n') {
+                Some((language, code))
+                    if !language.is_empty()
+                        && language
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || "+-_#.".contains(c)) =>
+                {
+                    (Some(language), code)
+                }
+                _ => (None, inner.strip_prefix('\n').unwrap_or(inner)),
+            };
+
+            return Some(Node {
+                kind: NodeKind::CodeBlock {
+                    language,
+                    code: code.strip_suffix('\n').unwrap_or(code),
+                },
+                span: pos..pos + 3 + close + 3,
+            });
+        }
+
+        let ticks = rest.bytes().take_while(|byte| *byte == b'`').count();
+        let fence = &rest[..ticks];
+        let mut search = ticks;
+        while let Some(offset) = rest[search..].find(fence) {
+            let close = search + offset;
+            let close_ticks = rest[close..].bytes().take_while(|b| *b == b'`').count();
+            if close_ticks == ticks && close > ticks {
+                let inner = &rest[ticks..close];
+                let code = match ticks {
+                    2 => inner
+                        .strip_prefix(' ')
+                        .and_then(|inner| inner.strip_suffix(' '))
+                        .unwrap_or(inner),
+                    _ => inner,
+                };
+                return Some(Node {
+                    kind: NodeKind::InlineCode(code),
+                    span: pos..pos + close + ticks,
+                });
+            }
+            search = close + close_ticks;
+        }
+
+        // an unclosed run of backticks is text and must not open code later on
+        Some(Node {
+            kind: NodeKind::Text(fence),
+            span: pos..pos + ticks,
+        })
+    }
+
+    fn angle(&self, pos: usize, end: usize) -> Option<Node<'a>> {
+        let rest = &self.src[pos + 1..end];
+        let close = rest.find(['>', '<', '\n'])?;
+        if rest.as_bytes()[close] != b'>' {
+            return None;
+        }
+        let inner = &rest[..close];
+        let span = pos..pos + close + 2;
+
+        let kind = if let Some(id) = inner.strip_prefix("@&") {
+            is_id(id).then_some(NodeKind::RoleMention(id))?
+        } else if let Some(id) = inner.strip_prefix("@!").or(inner.strip_prefix('@')) {
+            is_id(id).then_some(NodeKind::UserMention(id))?
+        } else if let Some(id) = inner.strip_prefix('#') {
+            is_id(id).then_some(NodeKind::ChannelMention(id))?
+        } else if let Some(command) = inner.strip_prefix('/') {
+            let (name, id) = command.rsplit_once(':')?;
+            (!name.trim().is_empty() && is_id(id)).then_some(NodeKind::SlashCommand { name, id })?
+        } else if let Some(timestamp) = inner.strip_prefix("t:") {
+            let (unix, style) = match timestamp.split_once(':') {
+                Some((unix, flag)) => {
+                    let mut chars = flag.chars();
+                    let style = TimestampStyle::from_flag(chars.next()?)?;
+                    if chars.next().is_some() {
+                        return None;
+                    }
+                    (unix, Some(style))
+                }
+                None => (timestamp, None),
+            };
+            let unix = unix.parse::<i64>().ok()?;
+            NodeKind::Timestamp {
+                timestamp: Timestamp::from_unix_secs(unix),
+                style,
+            }
+        } else if is_url(inner) && !inner.contains(char::is_whitespace) {
+            NodeKind::Link(inner)
+        } else {
+            let (animated, emoji) = match inner.strip_prefix("a:") {
+                Some(emoji) => (true, emoji),
+                None => (false, inner.strip_prefix(':')?),
+            };
+            let (name, id) = emoji.split_once(':')?;
+            let valid_name =
+                !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_');
+            (valid_name && is_id(id)).then_some(NodeKind::Emoji { name, id, animated })?
+        };
+
+        Some(Node { kind, span })
+    }
+
+    fn link(&self, pos: usize, end: usize) -> Option<Node<'a>> {
+        let rest = &self.src[pos..end];
+        let mut url = &rest[..rest
+            .find(|c: char| c.is_whitespace() || c == '<')
+            .unwrap_or(rest.len())];
+
+        // trailing punctuation and unbalanced parentheses are not part of the link
+        loop {
+            let trimmed =
+                url.trim_end_matches(['.', ',', ':', ';', '"', '\'', '!', '?', '*', '_', '~', '|']);
+            let trimmed = match trimmed.strip_suffix(')') {
+                Some(inner) if trimmed.matches('(').count() < trimmed.matches(')').count() => inner,
+                _ => trimmed,
+            };
+            if trimmed.len() == url.len() {
+                break;
+            }
+            url = trimmed;
+        }
+
+        is_url(url).then(|| Node {
+            kind: NodeKind::Link(url),
+            span: pos..pos + url.len(),
+        })
+    }
+
+    fn masked_link(&self, pos: usize, end: usize) -> Option<Node<'a>> {
+        let text_end = self.find_closing(pos + 1, end, "]")?;
+        let rest = self.src[text_end + 1..end].strip_prefix('(')?;
+        let url_end = rest.find([')', ' ', '\n'])?;
+        if rest.as_bytes()[url_end] != b')' {
+            return None;
+        }
+        let url = &rest[..url_end];
+        let url = url
+            .strip_prefix('<')
+            .and_then(|url| url.strip_suffix('>'))
+            .unwrap_or(url);
+        if !is_url(url) {
+            return None;
+        }
+
+        Some(Node {
+            kind: NodeKind::MaskedLink {
+                url,
+                children: self.parse(pos + 1, text_end, false),
+            },
+            span: pos..text_end + 2 + url_end + 1,
+        })
+    }
+
+    fn styled(&self, pos: usize, end: usize) -> Option<Node<'a>> {
+        let rest = &self.src[pos..end];
+        let (delimiter, style) = [
+            ("**", Style::Bold),
+            ("__", Style::Underline),
+            ("~~", Style::Strikethrough),
+            ("||", Style::Spoiler),
+            ("*", Style::Italic),
+            ("_", Style::Italic),
+        ]
+        .into_iter()
+        .find(|(delimiter, _)| rest.starts_with(delimiter))?;
+
+        let content_start = pos + delimiter.len();
+        if delimiter.len() == 1 && self.src[content_start..end].starts_with(char::is_whitespace) {
+            return None;
+        }
+        let close = self.find_closing(content_start, end, delimiter)?;
+
+        Some(Node {
+            kind: NodeKind::Styled {
+                style,
+                children: self.parse(content_start, close, false),
+            },
+            span: pos..close + delimiter.len(),
+        })
+    }
+
+    /// Find the closing delimiter of a non-empty span starting at `start`.
+    fn find_closing(&self, start: usize, end: usize, delimiter: &str) -> Option<usize> {
+        let bytes = self.src.as_bytes();
+        let marker = bytes[start.saturating_sub(1)];
+        let repeated = delimiter.len() == 1 && delimiter != "]";
+        let mut pos = start + self.char_len(start).min(end - start);
+
+        while pos < end {
+            let rest = &self.src[pos..end];
+            if rest.starts_with('\\') {
+                pos += 1 + self.char_len(pos + 1).min(end - pos - 1);
+                continue;
+            }
+            if rest.starts_with(delimiter) {
+                let after = bytes.get(pos + delimiter.len()).copied();
+                if repeated {
+                    // a doubled single character delimiter is part of the content
+                    if after == Some(marker) {
+                        pos += 2;
+                        continue;
+                    }
+                    let before_whitespace = bytes[pos - 1].is_ascii_whitespace();
+                    let inside_word =
+                        marker == b'_' && after.is_some_and(|b| b.is_ascii_alphanumeric());
+                    if !before_whitespace && !inside_word {
+                        return Some(pos);
+                    }
+                } else if delimiter == "]" || after != Some(delimiter.as_bytes()[0]) {
+                    return Some(pos);
+                }
+            }
+            pos += self.char_len(pos);
+        }
+
+        None
+    }
+}
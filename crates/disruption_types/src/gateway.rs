@@ -1,68 +1,49 @@
-/// TODO: Allow ORing of intents
-/// <https://discord.com/developers/docs/topics/gateway#gateway-intents>
-pub enum Intents {
-    #[allow(non_camel_case_types, dead_code)]
-    GUILDS = 1 << 0,
-
-    #[allow(non_camel_case_types)]
-    GUILD_MEMBERS = 1 << 1,
-
-    #[allow(non_camel_case_types, dead_code)]
-    GUILD_BANS = 1 << 2,
-
-    #[allow(non_camel_case_types, dead_code)]
-    GUILD_EMOJIS_AND_STICKERS = 1 << 3,
-
-    #[allow(non_camel_case_types, dead_code)]
-    GUILD_INTEGRATIONS = 1 << 4,
-
-    #[allow(non_camel_case_types, dead_code)]
-    GUILD_WEBHOOKS = 1 << 5,
-
-    #[allow(non_camel_case_types, dead_code)]
-    GUILD_INVITES = 1 << 6,
-
-    #[allow(non_camel_case_types, dead_code)]
-    GUILD_VOICE_STATES = 1 << 7,
-
-    #[allow(non_camel_case_types, dead_code)]
-    GUILD_PRESENCES = 1 << 8,
-
-    #[allow(non_camel_case_types)]
-    GUILD_MESSAGES = 1 << 9,
-
-    #[allow(non_camel_case_types)]
-    GUILD_MESSAGE_REACTIONS = 1 << 10,
-
-    #[allow(non_camel_case_types, dead_code)]
-    GUILD_MESSAGE_TYPING = 1 << 11,
-
-    #[allow(non_camel_case_types)]
-    DIRECT_MESSAGES = 1 << 12,
-
-    #[allow(non_camel_case_types, dead_code)]
-    DIRECT_MESSAGE_REACTIONS = 1 << 13,
-
-    #[allow(non_camel_case_types, dead_code)]
-    DIRECT_MESSAGE_TYPING = 1 << 14,
-
-    #[allow(non_camel_case_types)]
-    MESSAGE_CONTENT = 1 << 15,
-
-    #[allow(non_camel_case_types, dead_code)]
-    GUILD_SCHEDULED_EVENTS = 1 << 16,
-
-    #[allow(non_camel_case_types, dead_code)]
-    AUTO_MODERATION_CONFIGURATION = 1 << 20,
-
-    #[allow(non_camel_case_types, dead_code)]
-    AUTO_MODERATION_EXECUTION = 1 << 21,
-
-    #[allow(non_camel_case_types, dead_code)]
-    GUILD_MESSAGE_POLLS = 1 << 24,
-
-    #[allow(non_camel_case_types, dead_code)]
-    DIRECT_MESSAGE_POLLS = 1 << 25,
+flags! {
+    /// <https://discord.com/developers/docs/topics/gateway#gateway-intents>
+    pub struct Intents: u64 {
+        /// guild, role, channel, thread and stage instance events
+        const GUILDS = 1 << 0;
+        /// member add, update and remove events (privileged)
+        const GUILD_MEMBERS = 1 << 1;
+        /// ban add and remove and audit log entry events
+        const GUILD_BANS = 1 << 2;
+        /// emoji, sticker and soundboard sound updates
+        const GUILD_EMOJIS_AND_STICKERS = 1 << 3;
+        /// integration events
+        const GUILD_INTEGRATIONS = 1 << 4;
+        /// webhook updates
+        const GUILD_WEBHOOKS = 1 << 5;
+        /// invite create and delete events
+        const GUILD_INVITES = 1 << 6;
+        /// voice state updates and voice channel effects
+        const GUILD_VOICE_STATES = 1 << 7;
+        /// presence updates (privileged)
+        const GUILD_PRESENCES = 1 << 8;
+        /// message events in guilds
+        const GUILD_MESSAGES = 1 << 9;
+        /// reaction events in guilds
+        const GUILD_MESSAGE_REACTIONS = 1 << 10;
+        /// typing events in guilds
+        const GUILD_MESSAGE_TYPING = 1 << 11;
+        /// message events in direct messages
+        const DIRECT_MESSAGES = 1 << 12;
+        /// reaction events in direct messages
+        const DIRECT_MESSAGE_REACTIONS = 1 << 13;
+        /// typing events in direct messages
+        const DIRECT_MESSAGE_TYPING = 1 << 14;
+        /// content, embeds, attachments and components of messages (privileged)
+        const MESSAGE_CONTENT = 1 << 15;
+        /// scheduled event events
+        const GUILD_SCHEDULED_EVENTS = 1 << 16;
+        /// auto moderation rule events
+        const AUTO_MODERATION_CONFIGURATION = 1 << 20;
+        /// auto moderation action executions
+        const AUTO_MODERATION_EXECUTION = 1 << 21;
+        /// poll vote events in guilds
+        const GUILD_MESSAGE_POLLS = 1 << 24;
+        /// poll vote events in direct messages
+        const DIRECT_MESSAGE_POLLS = 1 << 25;
+    }
 }
 
 /// <https://discord.com/developers/docs/topics/gateway#commands-and-events-gateway-events>
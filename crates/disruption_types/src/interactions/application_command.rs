@@ -1,8 +1,8 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use super::InteractionContextType;
+use crate::locale::Localizations;
 
 /// <https://discord.com/developers/docs/interactions/application-commands#application-command-object>
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -21,12 +21,12 @@ pub struct ApplicationCommandApiType {
     pub name: String,
     /// Localization dictionary for name field
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name_localizations: Option<Value>,
+    pub name_localizations: Option<Localizations>,
     /// Description for CHAT_INPUT commands, 1-100 characters. Empty string for USER and MESSAGE commands
     pub description: String,
     /// Localization dictionary for description field
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub description_localizations: Option<Value>,
+    pub description_localizations: Option<Localizations>,
     /// Parameters for the command, max of 25
     #[serde(skip_serializing_if = "Option::is_none")]
     pub options: Option<Vec<ApplicationCommandOptionApiType>>,
@@ -52,21 +52,25 @@ pub struct ApplicationCommandApiType {
     /// Determines whether the interaction is handled by the app's interactions handler or by Discord
     #[serde(skip_serializing_if = "Option::is_none")]
     pub handler: Option<u8>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-types>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-#[allow(non_camel_case_types)]
-pub enum ApplicationCommandType {
-    /// Slash commands; a text-based command that shows up when a user types /
-    CHAT_INPUT = 1,
-    /// A UI-based command that shows up when you right click or tap on a user
-    USER = 2,
-    /// A UI-based command that shows up when you right click or tap on a message
-    MESSAGE = 3,
-    /// A UI-based command that represents the primary way to invoke an app's Activity
-    PRIMARY_ENTRY_POINT = 4,
+wire_enum! {
+    /// <https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-types>
+    #[allow(non_camel_case_types)]
+    pub enum ApplicationCommandType: u8 {
+        /// Slash commands; a text-based command that shows up when a user types /
+        CHAT_INPUT = 1,
+        /// A UI-based command that shows up when you right click or tap on a user
+        USER = 2,
+        /// A UI-based command that shows up when you right click or tap on a message
+        MESSAGE = 3,
+        /// A UI-based command that represents the primary way to invoke an app's Activity
+        PRIMARY_ENTRY_POINT = 4,
+    }
 }
 
 /// <https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-structure>
@@ -79,12 +83,12 @@ pub struct ApplicationCommandOptionApiType {
     pub name: String,
     /// Localization dictionary for the name field
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name_localizations: Option<Value>,
+    pub name_localizations: Option<Localizations>,
     /// 1-100 character description
     pub description: String,
     /// Localization dictionary for the description field
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub description_localizations: Option<Value>,
+    pub description_localizations: Option<Localizations>,
     /// If the parameter is required or optional--default false
     #[serde(skip_serializing_if = "Option::is_none")]
     pub required: Option<bool>,
@@ -112,24 +116,28 @@ pub struct ApplicationCommandOptionApiType {
     /// If autocomplete interactions are enabled for this STRING, INTEGER, or NUMBER type option
     #[serde(skip_serializing_if = "Option::is_none")]
     pub autocomplete: Option<bool>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-type>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-#[allow(non_camel_case_types)]
-pub enum ApplicationCommandOptionType {
-    SUB_COMMAND = 1,
-    SUB_COMMAND_GROUP = 2,
-    STRING = 3,
-    INTEGER = 4,
-    BOOLEAN = 5,
-    USER = 6,
-    CHANNEL = 7,
-    ROLE = 8,
-    MENTIONABLE = 9,
-    NUMBER = 10,
-    ATTACHMENT = 11,
+wire_enum! {
+    /// <https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-type>
+    #[allow(non_camel_case_types)]
+    pub enum ApplicationCommandOptionType: u8 {
+        SUB_COMMAND = 1,
+        SUB_COMMAND_GROUP = 2,
+        STRING = 3,
+        INTEGER = 4,
+        BOOLEAN = 5,
+        USER = 6,
+        CHANNEL = 7,
+        ROLE = 8,
+        MENTIONABLE = 9,
+        NUMBER = 10,
+        ATTACHMENT = 11,
+    }
 }
 
 /// <https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-choice-structure>
@@ -139,9 +147,13 @@ pub struct ApplicationCommandOptionChoiceApiType {
     pub name: String,
     /// Localization dictionary for the name field
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub name_localizations: Option<Value>,
+    pub name_localizations: Option<Localizations>,
     /// Value for the choice, up to 100 characters if string
     pub value: Value, // Can be string, integer, or number
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/interactions/application-commands#application-command-permissions-object>
@@ -155,6 +167,10 @@ pub struct ApplicationCommandPermissionsApiType {
     pub guild_id: String,
     /// Permissions for the command in the guild, max of 100
     pub permissions: Vec<ApplicationCommandPermissionApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/interactions/application-commands#application-command-permissions-object-application-command-permission-structure>
@@ -167,13 +183,17 @@ pub struct ApplicationCommandPermissionApiType {
     pub type_: ApplicationCommandPermissionType,
     /// true to allow, false, to disallow
     pub permission: bool,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/interactions/application-commands#application-command-permissions-object-application-command-permission-type>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ApplicationCommandPermissionType {
-    ROLE = 1,
-    USER = 2,
-    CHANNEL = 3,
+wire_enum! {
+    /// <https://discord.com/developers/docs/interactions/application-commands#application-command-permissions-object-application-command-permission-type>
+    pub enum ApplicationCommandPermissionType: u8 {
+        ROLE = 1,
+        USER = 2,
+        CHANNEL = 3,
+    }
 }
@@ -1,13 +1,21 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
-use crate::channel::MessageApiType;
+use crate::channel::{ChannelApiType, MessageApiType};
 use crate::entities::{GuildMemberApiType, UserApiType};
+use crate::locale::Locale;
 use crate::resources::EntitlementApiType;
 
+use super::interaction_callback::callback_requires_data;
+use super::{
+    ApplicationCommandOptionChoiceApiType, InteractionCallbackAutocompleteApiType,
+    InteractionCallbackDataApiType, InteractionCallbackDataMismatch,
+    InteractionCallbackMessageApiType, InteractionCallbackModalApiType, InteractionDataApiType,
+};
+
 /// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object>
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(try_from = "RawInteractionApiType")]
 pub struct InteractionApiType {
     /// ID of the interaction
     pub id: String,
@@ -18,16 +26,16 @@ pub struct InteractionApiType {
     pub type_: InteractionType,
     /// Interaction data payload
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub data: Option<Value>, // TODO: Create proper InteractionDataApiType union type
+    pub data: Option<InteractionDataApiType>,
     /// Guild that the interaction was sent from
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub guild: Option<Value>, // Partial guild
+    pub guild: Option<InteractionGuildApiType>,
     /// Guild that the interaction was sent from
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<String>,
     /// Channel that the interaction was sent from
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub channel: Option<Value>, // Partial channel
+    pub channel: Option<ChannelApiType>, // Partial channel
     /// Channel that the interaction was sent from
     #[serde(skip_serializing_if = "Option::is_none")]
     pub channel_id: Option<String>,
@@ -48,10 +56,10 @@ pub struct InteractionApiType {
     pub app_permissions: String,
     /// Selected language of the invoking user
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub locale: Option<String>,
+    pub locale: Option<Locale>,
     /// Guild's preferred locale, if invoked in a guild
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub guild_locale: Option<String>,
+    pub guild_locale: Option<Locale>,
     /// For monetized apps, any entitlements for the invoking user
     pub entitlements: Vec<EntitlementApiType>,
     /// Mapping of installation contexts that the interaction was authorized for
@@ -59,63 +67,268 @@ pub struct InteractionApiType {
     /// Context where the interaction was triggered from
     #[serde(skip_serializing_if = "Option::is_none")]
     pub context: Option<InteractionContextType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-type>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-#[allow(non_camel_case_types)]
-pub enum InteractionType {
-    PING = 1,
-    APPLICATION_COMMAND = 2,
-    MESSAGE_COMPONENT = 3,
-    APPLICATION_COMMAND_AUTOCOMPLETE = 4,
-    MODAL_SUBMIT = 5,
+/// Wire representation of an interaction, before its data is parsed according to its type.
+#[derive(Deserialize)]
+struct RawInteractionApiType {
+    id: String,
+    application_id: String,
+    #[serde(rename = "type")]
+    type_: InteractionType,
+    data: Option<Value>,
+    guild: Option<InteractionGuildApiType>,
+    guild_id: Option<String>,
+    channel: Option<ChannelApiType>,
+    channel_id: Option<String>,
+    member: Option<GuildMemberApiType>,
+    user: Option<UserApiType>,
+    token: String,
+    version: u8,
+    message: Option<MessageApiType>,
+    app_permissions: String,
+    locale: Option<Locale>,
+    guild_locale: Option<Locale>,
+    entitlements: Vec<EntitlementApiType>,
+    authorizing_integration_owners: Value,
+    context: Option<InteractionContextType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-context-types>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-#[allow(non_camel_case_types)]
-pub enum InteractionContextType {
-    /// Interaction can be used within servers
-    GUILD = 0,
-    /// Interaction can be used within DMs with the app's bot user
-    BOT_DM = 1,
-    /// Interaction can be used within Group DMs and DMs other than the app's bot user
-    PRIVATE_CHANNEL = 2,
+impl TryFrom<RawInteractionApiType> for InteractionApiType {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawInteractionApiType) -> Result<Self, Self::Error> {
+        let data = raw
+            .data
+            .map(|data| InteractionDataApiType::from_value(raw.type_, data))
+            .transpose()?;
+
+        Ok(Self {
+            id: raw.id,
+            application_id: raw.application_id,
+            type_: raw.type_,
+            data,
+            guild: raw.guild,
+            guild_id: raw.guild_id,
+            channel: raw.channel,
+            channel_id: raw.channel_id,
+            member: raw.member,
+            user: raw.user,
+            token: raw.token,
+            version: raw.version,
+            message: raw.message,
+            app_permissions: raw.app_permissions,
+            locale: raw.locale,
+            guild_locale: raw.guild_locale,
+            entitlements: raw.entitlements,
+            authorizing_integration_owners: raw.authorizing_integration_owners,
+            context: raw.context,
+            #[cfg(feature = "extra-fields")]
+            extra: raw.extra,
+        })
+    }
+}
+
+/// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-structure>
+/// Partial guild that is sent with interactions
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InteractionGuildApiType {
+    /// guild id
+    pub id: String,
+    /// the preferred locale of the guild
+    pub locale: Locale,
+    /// enabled guild features
+    pub features: Vec<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+wire_enum! {
+    /// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-type>
+    #[allow(non_camel_case_types)]
+    pub enum InteractionType: u8 {
+        PING = 1,
+        APPLICATION_COMMAND = 2,
+        MESSAGE_COMPONENT = 3,
+        APPLICATION_COMMAND_AUTOCOMPLETE = 4,
+        MODAL_SUBMIT = 5,
+    }
+}
+
+wire_enum! {
+    /// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-context-types>
+    #[allow(non_camel_case_types)]
+    pub enum InteractionContextType: u8 {
+        /// Interaction can be used within servers
+        GUILD = 0,
+        /// Interaction can be used within DMs with the app's bot user
+        BOT_DM = 1,
+        /// Interaction can be used within Group DMs and DMs other than the app's bot user
+        PRIVATE_CHANNEL = 2,
+    }
 }
 
 /// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object>
+/// The data of a response is always checked against its type, so mismatching responses cannot be built
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(try_from = "RawInteractionResponseApiType")]
 pub struct InteractionResponseApiType {
     /// the type of response
     #[serde(rename = "type")]
-    pub type_: InteractionCallbackType,
+    type_: InteractionCallbackType,
     /// an optional response message
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub data: Option<Value>, // TODO: Create InteractionCallbackDataApiType union type
+    data: Option<InteractionCallbackDataApiType>,
+}
+
+impl InteractionResponseApiType {
+    /// Create a response, checking that the data fits the callback type.
+    pub fn new(
+        type_: InteractionCallbackType,
+        data: Option<InteractionCallbackDataApiType>,
+    ) -> Result<Self, InteractionCallbackDataMismatch> {
+        let valid = match &data {
+            Some(data) => data.is_valid_for(type_),
+            None => !callback_requires_data(type_),
+        };
+
+        if !valid {
+            return Err(InteractionCallbackDataMismatch { type_ });
+        }
+
+        Ok(Self { type_, data })
+    }
+
+    /// ACK a ping.
+    pub fn pong() -> Self {
+        Self {
+            type_: InteractionCallbackType::PONG,
+            data: None,
+        }
+    }
+
+    /// Respond to an interaction with a message.
+    pub fn message(message: InteractionCallbackMessageApiType) -> Self {
+        Self {
+            type_: InteractionCallbackType::CHANNEL_MESSAGE_WITH_SOURCE,
+            data: Some(InteractionCallbackDataApiType::Message(message)),
+        }
+    }
+
+    /// ACK an interaction and edit the response later, the user sees a loading state.
+    pub fn deferred_message(ephemeral: bool) -> Self {
+        let data = ephemeral.then(|| {
+            InteractionCallbackDataApiType::Message(
+                InteractionCallbackMessageApiType::default().ephemeral(),
+            )
+        });
+
+        Self {
+            type_: InteractionCallbackType::DEFERRED_CHANNEL_MESSAGE_WITH_SOURCE,
+            data,
+        }
+    }
+
+    /// For components, ACK an interaction and edit the original message later.
+    pub fn deferred_update_message() -> Self {
+        Self {
+            type_: InteractionCallbackType::DEFERRED_UPDATE_MESSAGE,
+            data: None,
+        }
+    }
+
+    /// For components, edit the message the component was attached to.
+    pub fn update_message(message: InteractionCallbackMessageApiType) -> Self {
+        Self {
+            type_: InteractionCallbackType::UPDATE_MESSAGE,
+            data: Some(InteractionCallbackDataApiType::Message(message)),
+        }
+    }
+
+    /// Respond to an autocomplete interaction with suggested choices.
+    pub fn autocomplete(choices: Vec<ApplicationCommandOptionChoiceApiType>) -> Self {
+        Self {
+            type_: InteractionCallbackType::APPLICATION_COMMAND_AUTOCOMPLETE_RESULT,
+            data: Some(InteractionCallbackDataApiType::Autocomplete(
+                InteractionCallbackAutocompleteApiType {
+                    choices,
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
+                },
+            )),
+        }
+    }
+
+    /// Respond to an interaction with a popup modal.
+    pub fn modal(modal: InteractionCallbackModalApiType) -> Self {
+        Self {
+            type_: InteractionCallbackType::MODAL,
+            data: Some(InteractionCallbackDataApiType::Modal(modal)),
+        }
+    }
+
+    /// The type of this response.
+    pub fn type_(&self) -> InteractionCallbackType {
+        self.type_
+    }
+
+    /// The data of this response.
+    pub fn data(&self) -> Option<&InteractionCallbackDataApiType> {
+        self.data.as_ref()
+    }
+}
+
+/// Wire representation of an interaction response, before its data is checked against its type.
+#[derive(Deserialize)]
+struct RawInteractionResponseApiType {
+    #[serde(rename = "type")]
+    type_: InteractionCallbackType,
+    data: Option<Value>,
+}
+
+impl TryFrom<RawInteractionResponseApiType> for InteractionResponseApiType {
+    type Error = String;
+
+    fn try_from(raw: RawInteractionResponseApiType) -> Result<Self, Self::Error> {
+        let data = raw
+            .data
+            .map(|data| InteractionCallbackDataApiType::from_value(raw.type_, data))
+            .transpose()
+            .map_err(|e| e.to_string())?;
+
+        Self::new(raw.type_, data).map_err(|e| e.to_string())
+    }
 }
 
-/// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-interaction-callback-type>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-#[allow(non_camel_case_types)]
-pub enum InteractionCallbackType {
-    /// ACK a Ping
-    PONG = 1,
-    /// respond to an interaction with a message
-    CHANNEL_MESSAGE_WITH_SOURCE = 4,
-    /// ACK an interaction and edit a response later, the user sees a loading state
-    DEFERRED_CHANNEL_MESSAGE_WITH_SOURCE = 5,
-    /// for components, ACK an interaction and edit the original message later; the user does not see a loading state
-    DEFERRED_UPDATE_MESSAGE = 6,
-    /// for components, edit the message the component was attached to
-    UPDATE_MESSAGE = 7,
-    /// respond to an autocomplete interaction with suggested choices
-    APPLICATION_COMMAND_AUTOCOMPLETE_RESULT = 8,
-    /// respond to an interaction with a popup modal
-    MODAL = 9,
-    /// respond to an interaction with an upgrade button, only available for apps with monetization enabled
-    PREMIUM_REQUIRED = 10,
+wire_enum! {
+    /// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-interaction-callback-type>
+    #[allow(non_camel_case_types)]
+    pub enum InteractionCallbackType: u8 {
+        /// ACK a Ping
+        PONG = 1,
+        /// respond to an interaction with a message
+        CHANNEL_MESSAGE_WITH_SOURCE = 4,
+        /// ACK an interaction and edit a response later, the user sees a loading state
+        DEFERRED_CHANNEL_MESSAGE_WITH_SOURCE = 5,
+        /// for components, ACK an interaction and edit the original message later; the user does not see a loading state
+        DEFERRED_UPDATE_MESSAGE = 6,
+        /// for components, edit the message the component was attached to
+        UPDATE_MESSAGE = 7,
+        /// respond to an autocomplete interaction with suggested choices
+        APPLICATION_COMMAND_AUTOCOMPLETE_RESULT = 8,
+        /// respond to an interaction with a popup modal
+        MODAL = 9,
+        /// respond to an interaction with an upgrade button, only available for apps with monetization enabled
+        PREMIUM_REQUIRED = 10,
+    }
 }
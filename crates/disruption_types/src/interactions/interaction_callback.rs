@@ -0,0 +1,188 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::channel::{
+    AllowedMentionsApiType, ComponentApiType, EmbedApiType, MessageFlags, PartialAttachmentApiType,
+};
+use crate::resources::PollCreateRequestApiType;
+
+use super::{ApplicationCommandOptionChoiceApiType, InteractionCallbackType};
+
+/// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-interaction-callback-data-structure>
+/// Data of an interaction response, which variant is allowed depends on the InteractionCallbackType
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
+pub enum InteractionCallbackDataApiType {
+    /// Data for (deferred) CHANNEL_MESSAGE_WITH_SOURCE and UPDATE_MESSAGE responses
+    Message(InteractionCallbackMessageApiType),
+    /// Data for APPLICATION_COMMAND_AUTOCOMPLETE_RESULT responses
+    Autocomplete(InteractionCallbackAutocompleteApiType),
+    /// Data for MODAL responses
+    Modal(InteractionCallbackModalApiType),
+    /// Untyped data of a callback type that is not known to this version of the library
+    Unknown(Value),
+}
+
+impl InteractionCallbackDataApiType {
+    /// Parse a raw data payload according to the type of the response it belongs to.
+    pub fn from_value(type_: InteractionCallbackType, data: Value) -> serde_json::Result<Self> {
+        use InteractionCallbackType::*;
+
+        match type_ {
+            CHANNEL_MESSAGE_WITH_SOURCE | DEFERRED_CHANNEL_MESSAGE_WITH_SOURCE | UPDATE_MESSAGE => {
+                serde_json::from_value(data).map(Self::Message)
+            }
+            APPLICATION_COMMAND_AUTOCOMPLETE_RESULT => {
+                serde_json::from_value(data).map(Self::Autocomplete)
+            }
+            MODAL => serde_json::from_value(data).map(Self::Modal),
+            Unknown(_) => Ok(Self::Unknown(data)),
+            PONG | DEFERRED_UPDATE_MESSAGE | PREMIUM_REQUIRED => {
+                Err(serde::de::Error::custom(InteractionCallbackDataMismatch {
+                    type_,
+                }))
+            }
+        }
+    }
+
+    /// Check whether this data may be sent with a response of the given type.
+    pub fn is_valid_for(&self, type_: InteractionCallbackType) -> bool {
+        use InteractionCallbackType::*;
+
+        matches!(
+            (type_, self),
+            (
+                CHANNEL_MESSAGE_WITH_SOURCE | DEFERRED_CHANNEL_MESSAGE_WITH_SOURCE | UPDATE_MESSAGE,
+                Self::Message(_)
+            ) | (
+                APPLICATION_COMMAND_AUTOCOMPLETE_RESULT,
+                Self::Autocomplete(_)
+            ) | (MODAL, Self::Modal(_))
+                | (Unknown(_), Self::Unknown(_))
+        )
+    }
+}
+
+/// Check whether a response of the given type requires data to be present.
+pub(crate) fn callback_requires_data(type_: InteractionCallbackType) -> bool {
+    use InteractionCallbackType::*;
+
+    matches!(
+        type_,
+        CHANNEL_MESSAGE_WITH_SOURCE
+            | UPDATE_MESSAGE
+            | APPLICATION_COMMAND_AUTOCOMPLETE_RESULT
+            | MODAL
+    )
+}
+
+/// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-messages>
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct InteractionCallbackMessageApiType {
+    /// is the response TTS
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tts: Option<bool>,
+    /// message content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// supports up to 10 embeds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<EmbedApiType>>,
+    /// allowed mentions object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentionsApiType>,
+    /// message flags combined as a bitfield (only SUPPRESS_EMBEDS, EPHEMERAL, SUPPRESS_NOTIFICATIONS and IS_COMPONENTS_V2 can be set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<MessageFlags>,
+    /// message components
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<ComponentApiType>>,
+    /// attachment objects with filename and description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<PartialAttachmentApiType>>,
+    /// a poll!
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll: Option<PollCreateRequestApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl InteractionCallbackMessageApiType {
+    /// Create a message response with the given content.
+    pub fn new(content: impl ToString) -> Self {
+        InteractionCallbackMessageApiType {
+            content: Some(content.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Only show this message to the user who invoked the interaction.
+    pub fn ephemeral(mut self) -> Self {
+        self.flags = Some(self.flags.unwrap_or_default() | MessageFlags::EPHEMERAL);
+        self
+    }
+
+    /// Whether this message is only visible to the user who invoked the interaction.
+    pub fn is_ephemeral(&self) -> bool {
+        self.flags
+            .is_some_and(|flags| flags.contains(MessageFlags::EPHEMERAL))
+    }
+}
+
+/// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-autocomplete>
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InteractionCallbackAutocompleteApiType {
+    /// autocomplete choices (max of 25 choices)
+    pub choices: Vec<ApplicationCommandOptionChoiceApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-modal>
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InteractionCallbackModalApiType {
+    /// a developer-defined identifier for the modal, max 100 characters
+    pub custom_id: String,
+    /// the title of the popup modal, max 45 characters
+    pub title: String,
+    /// between 1 and 5 (inclusive) components that make up the modal
+    pub components: Vec<ComponentApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Error for an interaction response whose data does not fit its callback type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InteractionCallbackDataMismatch {
+    /// the callback type the data was given for
+    pub type_: InteractionCallbackType,
+}
+
+impl Display for InteractionCallbackDataMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if callback_requires_data(self.type_) {
+            write!(
+                f,
+                "interaction callback type {:?} requires matching data",
+                self.type_
+            )
+        } else {
+            write!(
+                f,
+                "interaction callback type {:?} does not accept this data",
+                self.type_
+            )
+        }
+    }
+}
+
+impl std::error::Error for InteractionCallbackDataMismatch {}
@@ -0,0 +1,344 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::channel::MessageComponentApiType;
+
+use super::{
+    ApplicationCommandOptionType, ApplicationCommandType, InteractionType, ResolvedDataApiType,
+};
+
+/// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-data>
+/// Interaction data payload, the variant is determined by the type of the interaction
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum InteractionDataApiType {
+    /// Data for APPLICATION_COMMAND and APPLICATION_COMMAND_AUTOCOMPLETE interactions
+    ApplicationCommand(ApplicationCommandDataApiType),
+    /// Data for MESSAGE_COMPONENT interactions
+    MessageComponent(MessageComponentDataApiType),
+    /// Data for MODAL_SUBMIT interactions
+    ModalSubmit(ModalSubmitDataApiType),
+    /// Untyped data of an interaction type that is not known to this version of the library
+    Unknown(Value),
+}
+
+impl InteractionDataApiType {
+    /// Parse a raw data payload according to the type of the interaction it was sent with.
+    pub fn from_value(type_: InteractionType, data: Value) -> serde_json::Result<Self> {
+        match type_ {
+            InteractionType::APPLICATION_COMMAND
+            | InteractionType::APPLICATION_COMMAND_AUTOCOMPLETE => {
+                serde_json::from_value(data).map(Self::ApplicationCommand)
+            }
+            InteractionType::MESSAGE_COMPONENT => {
+                serde_json::from_value(data).map(Self::MessageComponent)
+            }
+            InteractionType::MODAL_SUBMIT => serde_json::from_value(data).map(Self::ModalSubmit),
+            InteractionType::PING => Err(serde::de::Error::custom(
+                "PING interactions do not carry a data payload",
+            )),
+            InteractionType::Unknown(_) => Ok(Self::Unknown(data)),
+        }
+    }
+
+    /// The data of an application command or autocomplete interaction.
+    pub fn as_application_command(&self) -> Option<&ApplicationCommandDataApiType> {
+        match self {
+            Self::ApplicationCommand(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// The data of a message component interaction.
+    pub fn as_message_component(&self) -> Option<&MessageComponentDataApiType> {
+        match self {
+            Self::MessageComponent(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// The data of a modal submit interaction.
+    pub fn as_modal_submit(&self) -> Option<&ModalSubmitDataApiType> {
+        match self {
+            Self::ModalSubmit(data) => Some(data),
+            _ => None,
+        }
+    }
+}
+
+/// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-application-command-data-structure>
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ApplicationCommandDataApiType {
+    /// the ID of the invoked command
+    pub id: String,
+    /// the name of the invoked command
+    pub name: String,
+    /// the type of the invoked command
+    #[serde(rename = "type")]
+    pub type_: ApplicationCommandType,
+    /// converted users + roles + channels + attachments
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved: Option<ResolvedDataApiType>,
+    /// the params + values from the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<ApplicationCommandInteractionDataOptionApiType>>,
+    /// the id of the guild the command is registered to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<String>,
+    /// id of the user or message targeted by a user or message command
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ApplicationCommandDataApiType {
+    /// Find a top-level option by its name.
+    pub fn option(&self, name: &str) -> Option<&ApplicationCommandInteractionDataOptionApiType> {
+        self.options
+            .as_ref()?
+            .iter()
+            .find(|option| option.name == name)
+    }
+}
+
+/// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-application-command-interaction-data-option-structure>
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(try_from = "RawApplicationCommandInteractionDataOption")]
+pub struct ApplicationCommandInteractionDataOptionApiType {
+    /// Name of the parameter
+    pub name: String,
+    /// Value of application command option type
+    #[serde(rename = "type")]
+    pub type_: ApplicationCommandOptionType,
+    /// Value of the option resulting from user input
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<ApplicationCommandOptionValue>,
+    /// Present if this option is a group or subcommand
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<ApplicationCommandInteractionDataOptionApiType>>,
+    /// true if this option is the currently focused option for autocomplete
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focused: Option<bool>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ApplicationCommandInteractionDataOptionApiType {
+    /// Find a nested option (of a subcommand or subcommand group) by its name.
+    pub fn option(&self, name: &str) -> Option<&ApplicationCommandInteractionDataOptionApiType> {
+        self.options
+            .as_ref()?
+            .iter()
+            .find(|option| option.name == name)
+    }
+}
+
+/// Wire representation of an option, before its value is checked against its type.
+#[derive(Deserialize)]
+struct RawApplicationCommandInteractionDataOption {
+    name: String,
+    #[serde(rename = "type")]
+    type_: ApplicationCommandOptionType,
+    value: Option<Value>,
+    options: Option<Vec<ApplicationCommandInteractionDataOptionApiType>>,
+    focused: Option<bool>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl TryFrom<RawApplicationCommandInteractionDataOption>
+    for ApplicationCommandInteractionDataOptionApiType
+{
+    type Error = String;
+
+    fn try_from(raw: RawApplicationCommandInteractionDataOption) -> Result<Self, Self::Error> {
+        let focused = raw.focused.unwrap_or(false);
+        let value = raw
+            .value
+            .map(|value| ApplicationCommandOptionValue::parse(raw.type_, value, focused))
+            .transpose()?;
+
+        Ok(Self {
+            name: raw.name,
+            type_: raw.type_,
+            value,
+            options: raw.options,
+            focused: raw.focused,
+            #[cfg(feature = "extra-fields")]
+            extra: raw.extra,
+        })
+    }
+}
+
+/// Typed value of an application command interaction option
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum ApplicationCommandOptionValue {
+    String(String),
+    Integer(i64),
+    Number(f64),
+    Boolean(bool),
+    /// ID of the user, look it up in the resolved data
+    User(String),
+    /// ID of the channel, look it up in the resolved data
+    Channel(String),
+    /// ID of the role, look it up in the resolved data
+    Role(String),
+    /// ID of the user or role, look it up in the resolved data
+    Mentionable(String),
+    /// ID of the attachment, look it up in the resolved data
+    Attachment(String),
+    /// Partial (and possibly invalid) user input of the focused option in an autocomplete interaction
+    Focused(String),
+}
+
+impl ApplicationCommandOptionValue {
+    fn parse(
+        type_: ApplicationCommandOptionType,
+        value: Value,
+        focused: bool,
+    ) -> Result<Self, String> {
+        use ApplicationCommandOptionType::*;
+
+        let parsed = match (type_, value) {
+            (_, Value::String(input)) if focused => Some(Self::Focused(input)),
+            (STRING, Value::String(value)) => Some(Self::String(value)),
+            (INTEGER, Value::Number(value)) => value.as_i64().map(Self::Integer),
+            (NUMBER, Value::Number(value)) => value.as_f64().map(Self::Number),
+            (BOOLEAN, Value::Bool(value)) => Some(Self::Boolean(value)),
+            (USER, Value::String(id)) => Some(Self::User(id)),
+            (CHANNEL, Value::String(id)) => Some(Self::Channel(id)),
+            (ROLE, Value::String(id)) => Some(Self::Role(id)),
+            (MENTIONABLE, Value::String(id)) => Some(Self::Mentionable(id)),
+            (ATTACHMENT, Value::String(id)) => Some(Self::Attachment(id)),
+            (type_, value) => {
+                return Err(format!(
+                    "invalid value {value} for option of type {type_:?}"
+                ))
+            }
+        };
+
+        parsed.ok_or_else(|| format!("value out of range for option of type {type_:?}"))
+    }
+
+    /// The value as a string slice, for string options, snowflakes and focused input.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value)
+            | Self::User(value)
+            | Self::Channel(value)
+            | Self::Role(value)
+            | Self::Mentionable(value)
+            | Self::Attachment(value)
+            | Self::Focused(value) => Some(value.as_str()),
+            _ => None,
+        }
+    }
+
+    /// The value of an INTEGER option.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The value of a NUMBER (or INTEGER) option.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Number(value) => Some(*value),
+            Self::Integer(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+
+    /// The value of a BOOLEAN option.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Boolean(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+/// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-message-component-data-structure>
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MessageComponentDataApiType {
+    /// the custom_id of the component
+    pub custom_id: String,
+    /// the type of the component
+    pub component_type: MessageComponentApiType,
+    /// values the user selected in a select menu component
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<String>>,
+    /// resolved entities from selected options
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved: Option<ResolvedDataApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-modal-submit-data-structure>
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ModalSubmitDataApiType {
+    /// the custom_id of the modal
+    pub custom_id: String,
+    /// the values submitted by the user
+    pub components: Vec<ModalSubmitComponentApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ModalSubmitDataApiType {
+    /// Find the submitted value of a text input by its custom_id.
+    pub fn value(&self, custom_id: &str) -> Option<&str> {
+        fn find<'a>(
+            components: &'a [ModalSubmitComponentApiType],
+            custom_id: &str,
+        ) -> Option<&'a str> {
+            components.iter().find_map(|component| {
+                if component.custom_id.as_deref() == Some(custom_id) {
+                    return component.value.as_deref();
+                }
+                find(component.components.as_deref()?, custom_id)
+            })
+        }
+
+        find(&self.components, custom_id)
+    }
+}
+
+/// A component as it is sent back in a modal submit interaction
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ModalSubmitComponentApiType {
+    /// the type of the component
+    #[serde(rename = "type")]
+    pub type_: MessageComponentApiType,
+    /// the identifier of the component
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    /// the custom_id of the component
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_id: Option<String>,
+    /// the value entered by the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// child components of an action row
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<ModalSubmitComponentApiType>>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
@@ -1,7 +1,11 @@
 mod application_command;
 mod interaction;
+mod interaction_callback;
+mod interaction_data;
 mod resolved;
 
 pub use self::application_command::*;
 pub use self::interaction::*;
+pub use self::interaction_callback::*;
+pub use self::interaction_data::*;
 pub use self::resolved::*;
@@ -26,4 +26,8 @@ pub struct ResolvedDataApiType {
     /// IDs and attachment objects
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attachments: Option<HashMap<String, AttachmentApiType>>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
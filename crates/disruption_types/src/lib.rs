@@ -1,8 +1,18 @@
 // TODO: Move these to own crate
+#[macro_use]
+mod macros;
+
+pub mod cdn;
 pub mod channel;
+pub mod color;
 pub mod entities;
+pub mod formatting;
 pub mod gateway;
 pub mod interactions;
+pub mod locale;
+pub mod nullable;
 pub mod opcodes;
 pub mod payloads;
 pub mod resources;
+pub mod timestamp;
+pub mod validation;
@@ -0,0 +1,121 @@
+//! Locales supported by the Discord client.
+
+use std::collections::HashMap;
+
+string_enum! {
+    /// <https://discord.com/developers/docs/reference#locales>
+    pub enum Locale {
+        /// Bahasa Indonesia
+        Indonesian = "id",
+        /// Dansk
+        Danish = "da",
+        /// Deutsch
+        German = "de",
+        /// English, UK
+        EnglishUk = "en-GB",
+        /// English, US
+        EnglishUs = "en-US",
+        /// Español
+        Spanish = "es-ES",
+        /// Español, LATAM
+        SpanishLatam = "es-419",
+        /// Français
+        French = "fr",
+        /// Hrvatski
+        Croatian = "hr",
+        /// Italiano
+        Italian = "it",
+        /// Lietuviškai
+        Lithuanian = "lt",
+        /// Magyar
+        Hungarian = "hu",
+        /// Nederlands
+        Dutch = "nl",
+        /// Norsk
+        Norwegian = "no",
+        /// Polski
+        Polish = "pl",
+        /// Português do Brasil
+        PortugueseBrazilian = "pt-BR",
+        /// Română
+        Romanian = "ro",
+        /// Suomi
+        Finnish = "fi",
+        /// Svenska
+        Swedish = "sv-SE",
+        /// Tiếng Việt
+        Vietnamese = "vi",
+        /// Türkçe
+        Turkish = "tr",
+        /// Čeština
+        Czech = "cs",
+        /// Ελληνικά
+        Greek = "el",
+        /// български
+        Bulgarian = "bg",
+        /// Pусский
+        Russian = "ru",
+        /// Українська
+        Ukrainian = "uk",
+        /// हिन्दी
+        Hindi = "hi",
+        /// ไทย
+        Thai = "th",
+        /// 中文
+        ChineseChina = "zh-CN",
+        /// 日本語
+        Japanese = "ja",
+        /// 繁體中文
+        ChineseTaiwan = "zh-TW",
+        /// 한국어
+        Korean = "ko",
+    }
+}
+
+impl Locale {
+    /// All locales supported by Discord.
+    pub const ALL: &'static [Locale] = &[
+        Locale::Indonesian,
+        Locale::Danish,
+        Locale::German,
+        Locale::EnglishUk,
+        Locale::EnglishUs,
+        Locale::Spanish,
+        Locale::SpanishLatam,
+        Locale::French,
+        Locale::Croatian,
+        Locale::Italian,
+        Locale::Lithuanian,
+        Locale::Hungarian,
+        Locale::Dutch,
+        Locale::Norwegian,
+        Locale::Polish,
+        Locale::PortugueseBrazilian,
+        Locale::Romanian,
+        Locale::Finnish,
+        Locale::Swedish,
+        Locale::Vietnamese,
+        Locale::Turkish,
+        Locale::Czech,
+        Locale::Greek,
+        Locale::Bulgarian,
+        Locale::Russian,
+        Locale::Ukrainian,
+        Locale::Hindi,
+        Locale::Thai,
+        Locale::ChineseChina,
+        Locale::Japanese,
+        Locale::ChineseTaiwan,
+        Locale::Korean,
+    ];
+
+    /// Whether the locale is supported by Discord.
+    pub fn is_known(&self) -> bool {
+        !matches!(self, Locale::Unknown(_))
+    }
+}
+
+/// Translations of a name or description, keyed by locale
+///
+/// <https://discord.com/developers/docs/interactions/application-commands#localization>
+pub type Localizations = HashMap<Locale, String>;
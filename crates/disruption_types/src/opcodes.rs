@@ -1,29 +1,239 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
-
-#[derive(Serialize_repr, Deserialize_repr, PartialEq, Debug, Default, Clone)]
-#[repr(u8)]
-pub enum GatewayOpcode {
-    /// An event was dispatched.
-    #[default]
-    Dispatch = 0,
-    /// Fired periodically by the client to keep the connection alive.
-    Heartbeat = 1,
-    /// Starts a new session during the initial handshake.
-    Identify = 2,
-    /// Update the clients presence.
-    PresenceUpdate = 3,
-    /// Used to join/leave or move between voice channels.
-    VoiceStateUpdate = 4,
-    /// Resume a previous session that was disconnected.
-    Resume = 6,
-    /// You should attempt to reconnect and resume immediately.
-    Reconnect = 7,
-    /// Request information about offline guild members in a large guild.
-    RequestGuildMembers = 8,
-    /// The session has been invalidated. You should reconnect and identify/resume accordingly.
-    InvalidSession = 9,
-    /// Sent immediatly after connecting. Contains the `heartbeat_interval` to use.
-    Hello = 10,
-    /// Sent in response to receiving a heartbot to acknowledge that it has been received.
-    HeartbeatACK = 11,
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+wire_enum! {
+    #[derive(Default)]
+    pub enum GatewayOpcode: u8 {
+        /// An event was dispatched.
+        #[default]
+        Dispatch = 0,
+        /// Fired periodically by the client to keep the connection alive.
+        Heartbeat = 1,
+        /// Starts a new session during the initial handshake.
+        Identify = 2,
+        /// Update the clients presence.
+        PresenceUpdate = 3,
+        /// Used to join/leave or move between voice channels.
+        VoiceStateUpdate = 4,
+        /// Resume a previous session that was disconnected.
+        Resume = 6,
+        /// You should attempt to reconnect and resume immediately.
+        Reconnect = 7,
+        /// Request information about offline guild members in a large guild.
+        RequestGuildMembers = 8,
+        /// The session has been invalidated. You should reconnect and identify/resume accordingly.
+        InvalidSession = 9,
+        /// Sent immediatly after connecting. Contains the `heartbeat_interval` to use.
+        Hello = 10,
+        /// Sent in response to receiving a heartbot to acknowledge that it has been received.
+        HeartbeatACK = 11,
+    }
+}
+
+wire_enum! {
+    /// <https://discord.com/developers/docs/topics/opcodes-and-status-codes#json-json-error-codes>
+    pub enum JsonErrorCode: u32 {
+        /// General error (such as a malformed request body, amongst other things)
+        General = 0,
+        /// Unknown account
+        UnknownAccount = 10001,
+        /// Unknown application
+        UnknownApplication = 10002,
+        /// Unknown channel
+        UnknownChannel = 10003,
+        /// Unknown guild
+        UnknownGuild = 10004,
+        /// Unknown integration
+        UnknownIntegration = 10005,
+        /// Unknown invite
+        UnknownInvite = 10006,
+        /// Unknown member
+        UnknownMember = 10007,
+        /// Unknown message
+        UnknownMessage = 10008,
+        /// Unknown permission overwrite
+        UnknownPermissionOverwrite = 10009,
+        /// Unknown role
+        UnknownRole = 10011,
+        /// Unknown token
+        UnknownToken = 10012,
+        /// Unknown user
+        UnknownUser = 10013,
+        /// Unknown emoji
+        UnknownEmoji = 10014,
+        /// Unknown webhook
+        UnknownWebhook = 10015,
+        /// Unknown ban
+        UnknownBan = 10026,
+        /// Unknown sticker
+        UnknownSticker = 10060,
+        /// Unknown interaction
+        UnknownInteraction = 10062,
+        /// Unknown application command
+        UnknownApplicationCommand = 10063,
+        /// Bots cannot use this endpoint
+        BotsCannotUseEndpoint = 20001,
+        /// Only bots can use this endpoint
+        OnlyBotsCanUseEndpoint = 20002,
+        /// This message cannot be edited due to announcement rate limits
+        AnnouncementRateLimited = 20022,
+        /// The channel you are writing has hit the write rate limit
+        ChannelWriteRateLimited = 20028,
+        /// Maximum number of guilds reached (100)
+        MaxGuilds = 30001,
+        /// Maximum number of pins reached for the channel (50)
+        MaxPins = 30003,
+        /// Maximum number of guild roles reached (250)
+        MaxRoles = 30005,
+        /// Maximum number of reactions reached (20)
+        MaxReactions = 30010,
+        /// Unauthorized. Provide a valid token and try again
+        Unauthorized = 40001,
+        /// Request entity too large. Try sending something smaller in size
+        RequestEntityTooLarge = 40005,
+        /// This feature has been temporarily disabled server-side
+        FeatureDisabled = 40006,
+        /// The user is banned from this guild
+        UserBanned = 40007,
+        /// This message has already been crossposted
+        AlreadyCrossposted = 40033,
+        /// An application command with that name already exists
+        CommandNameExists = 40041,
+        /// Interaction has already been acknowledged
+        InteractionAlreadyAcknowledged = 40060,
+        /// Missing access
+        MissingAccess = 50001,
+        /// Invalid account type
+        InvalidAccountType = 50002,
+        /// Cannot execute action on a DM channel
+        CannotExecuteOnDm = 50003,
+        /// Cannot edit a message authored by another user
+        CannotEditOthersMessage = 50005,
+        /// Cannot send an empty message
+        EmptyMessage = 50006,
+        /// Cannot send messages to this user
+        CannotMessageUser = 50007,
+        /// Cannot send messages in a non-text channel
+        CannotSendInNonTextChannel = 50008,
+        /// You lack permissions to perform that action
+        MissingPermissions = 50013,
+        /// Invalid authentication token provided
+        InvalidToken = 50014,
+        /// Note was too long
+        NoteTooLong = 50015,
+        /// Provided too few or too many messages to delete
+        InvalidBulkDeleteCount = 50016,
+        /// A message can only be pinned to the channel it was sent in
+        InvalidPinChannel = 50019,
+        /// Invalid OAuth2 access token provided
+        InvalidOAuth2AccessToken = 50025,
+        /// A message provided was too old to bulk delete
+        MessageTooOldToBulkDelete = 50034,
+        /// Invalid form body (returned for both application/json and multipart/form-data bodies), or invalid Content-Type provided
+        InvalidFormBody = 50035,
+        /// Invalid API version provided
+        InvalidApiVersion = 50041,
+        /// Cannot delete a channel required for Community guilds
+        CannotDeleteCommunityChannel = 50074,
+        /// Invalid sticker sent
+        InvalidSticker = 50081,
+        /// Reaction was blocked
+        ReactionBlocked = 90001,
+        /// API resource is currently overloaded. Try again a little later
+        ResourceOverloaded = 130000,
+        /// Failed to ban users, e.g. because none of them could be banned
+        FailedToBanUsers = 500000,
+    }
+}
+
+impl JsonErrorCode {
+    /// Whether the error is about a resource that does not exist.
+    pub fn is_unknown_resource(&self) -> bool {
+        (10001..20000).contains(&u32::from(*self))
+    }
+}
+
+/// <https://discord.com/developers/docs/reference#error-messages>
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct JsonErrorApiType {
+    /// the JSON error code
+    pub code: JsonErrorCode,
+    /// a human readable description of the error
+    pub message: String,
+    /// errors of the individual fields of the request body, nested like the body itself
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Value>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Error of a single field of a request body
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonFieldError {
+    /// path of the field, e.g. `embeds[0].fields[3].name`, empty for the body itself
+    pub path: String,
+    /// error code of the field, e.g. `BASE_TYPE_MAX_LENGTH`
+    pub code: String,
+    /// a human readable description of the error
+    pub message: String,
+}
+
+impl JsonErrorApiType {
+    /// Flatten the nested `errors` into a list of field errors.
+    pub fn field_errors(&self) -> Vec<JsonFieldError> {
+        let mut errors = Vec::new();
+        if let Some(tree) = &self.errors {
+            collect_field_errors(tree, String::new(), &mut errors);
+        }
+        errors
+    }
+}
+
+fn collect_field_errors(tree: &Value, path: String, errors: &mut Vec<JsonFieldError>) {
+    let Value::Object(object) = tree else {
+        return;
+    };
+
+    for (key, value) in object {
+        if key == "_errors" {
+            for error in value.as_array().into_iter().flatten() {
+                let text = |name| error[name].as_str().unwrap_or_default().to_owned();
+                errors.push(JsonFieldError {
+                    path: path.clone(),
+                    code: text("code"),
+                    message: text("message"),
+                });
+            }
+        } else if key.bytes().all(|b| b.is_ascii_digit()) {
+            collect_field_errors(value, format!("{path}[{key}]"), errors);
+        } else if path.is_empty() {
+            collect_field_errors(value, key.clone(), errors);
+        } else {
+            collect_field_errors(value, format!("{path}.{key}"), errors);
+        }
+    }
+}
+
+impl Display for JsonFieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            return write!(f, "{}", self.message);
+        }
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl Display for JsonErrorApiType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (code {})", self.message, u32::from(self.code))?;
+        for (index, error) in self.field_errors().iter().enumerate() {
+            f.write_str(if index == 0 { ": " } else { ", " })?;
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
 }
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use super::PresenceUpdateStructure;
+use crate::gateway::Intents;
 
 /// <https://discord.com/developers/docs/topics/gateway#identify>
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
@@ -22,7 +23,11 @@ pub struct IdentifyPayloadData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub presence: Option<PresenceUpdateStructure>,
     /// the gateway intents you wish to receive
-    pub intents: u64,
+    pub intents: Intents,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/topics/gateway#identify-identify-connection-properties>
@@ -34,4 +39,21 @@ pub struct IdentifyConnectionProperties {
     pub browser: String,
     /// your library name
     pub device: String,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl IdentifyConnectionProperties {
+    /// Create the connection properties sent when identifying.
+    pub fn new(os: impl ToString, browser: impl ToString, device: impl ToString) -> Self {
+        IdentifyConnectionProperties {
+            os: os.to_string(),
+            browser: browser.to_string(),
+            device: device.to_string(),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
+    }
 }
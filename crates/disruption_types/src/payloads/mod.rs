@@ -25,4 +25,8 @@ pub struct Payload {
     pub s: Option<u64>,
     /// the event name for this payload
     pub t: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
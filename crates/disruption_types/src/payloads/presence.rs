@@ -1,5 +1,4 @@
 use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
 /// <https://discord.com/developers/docs/topics/gateway#update-presence>
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -13,6 +12,10 @@ pub struct PresenceUpdateStructure {
     pub status: String,
     /// whether or not the client is afk
     pub afk: bool,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/events/gateway-events#activity-object>
@@ -67,28 +70,32 @@ pub struct Activity {
     pub instance: Option<bool>,
     /// activity flags ORd together, describes what the payload includes
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub flags: Option<u64>,
+    pub flags: Option<ActivityFlags>,
     /// the custom buttons shown in the Rich Presence (max 2)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub buttons: Option<Vec<ActivityButton>>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-types>
-#[derive(Serialize_repr, Deserialize_repr, PartialEq, Debug, Clone, Copy)]
-#[repr(u8)]
-pub enum ActivityType {
-    /// Playing {name}
-    Game = 0,
-    /// Streaming {details}
-    Streaming = 1,
-    /// Listening to {name}
-    Listening = 2,
-    /// Watching {name}
-    Watching = 3,
-    /// {emoji} {state}
-    Custom = 4,
-    /// Competing in {name}
-    Competing = 5,
+wire_enum! {
+    /// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-types>
+    pub enum ActivityType: u8 {
+        /// Playing {name}
+        Game = 0,
+        /// Streaming {details}
+        Streaming = 1,
+        /// Listening to {name}
+        Listening = 2,
+        /// Watching {name}
+        Watching = 3,
+        /// {emoji} {state}
+        Custom = 4,
+        /// Competing in {name}
+        Competing = 5,
+    }
 }
 
 /// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-timestamps>
@@ -100,6 +107,10 @@ pub struct ActivityTimestamps {
     /// unix time (in milliseconds) of when the activity ends
     #[serde(skip_serializing_if = "Option::is_none")]
     pub end: Option<u64>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-emoji>
@@ -113,6 +124,10 @@ pub struct ActivityEmoji {
     /// whether this emoji is animated
     #[serde(skip_serializing_if = "Option::is_none")]
     pub animated: Option<bool>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-party>
@@ -124,6 +139,10 @@ pub struct ActivityParty {
     /// used to show the party's current and maximum size [current_size, max_size]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub size: Option<[u64; 2]>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-assets>
@@ -147,6 +166,10 @@ pub struct ActivityAssets {
     /// URL that is opened when clicking on the small image
     #[serde(skip_serializing_if = "Option::is_none")]
     pub small_url: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-secrets>
@@ -161,22 +184,25 @@ pub struct ActivitySecrets {
     /// the secret for a specific instanced match
     #[serde(rename = "match", skip_serializing_if = "Option::is_none")]
     pub match_: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-flags>
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u64)]
-#[allow(dead_code)]
-pub enum ActivityFlags {
-    Instance = 1 << 0,
-    Join = 1 << 1,
-    Spectate = 1 << 2,
-    JoinRequest = 1 << 3,
-    Sync = 1 << 4,
-    Play = 1 << 5,
-    PartyPrivacyFriends = 1 << 6,
-    PartyPrivacyVoiceChannel = 1 << 7,
-    Embedded = 1 << 8,
+flags! {
+    /// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-flags>
+    pub struct ActivityFlags: u64 {
+        const INSTANCE = 1 << 0;
+        const JOIN = 1 << 1;
+        const SPECTATE = 1 << 2;
+        const JOIN_REQUEST = 1 << 3;
+        const SYNC = 1 << 4;
+        const PLAY = 1 << 5;
+        const PARTY_PRIVACY_FRIENDS = 1 << 6;
+        const PARTY_PRIVACY_VOICE_CHANNEL = 1 << 7;
+        const EMBEDDED = 1 << 8;
+    }
 }
 
 /// <https://discord.com/developers/docs/events/gateway-events#activity-object-activity-buttons>
@@ -186,4 +212,8 @@ pub struct ActivityButton {
     pub label: String,
     /// the url opened when clicking the button (1-512 characters)
     pub url: String,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
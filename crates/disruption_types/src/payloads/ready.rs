@@ -12,4 +12,8 @@ pub struct ReadyPayloadData {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shard: Option<Vec<u64>>,
     pub application: ApplicationApiType,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
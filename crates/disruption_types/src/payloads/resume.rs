@@ -9,4 +9,21 @@ pub struct ResumePayloadData {
     pub session_id: String,
     /// last sequence number received
     pub seq: u64,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ResumePayloadData {
+    /// Create the payload to resume the given session.
+    pub fn new(token: impl ToString, session_id: impl ToString, seq: u64) -> Self {
+        ResumePayloadData {
+            token: token.to_string(),
+            session_id: session_id.to_string(),
+            seq,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
+    }
 }
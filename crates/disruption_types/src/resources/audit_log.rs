@@ -1,11 +1,12 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use crate::channel::ChannelApiType;
 use crate::entities::{IntegrationApiType, UserApiType};
 use crate::interactions::ApplicationCommandApiType;
-use crate::resources::{AutoModerationRuleApiType, GuildScheduledEventApiType, WebhookApiType};
+use crate::resources::{
+    AuditLogChange, AutoModerationRuleApiType, GuildScheduledEventApiType, WebhookApiType,
+};
 
 /// <https://discord.com/developers/docs/resources/audit-log#audit-log-object>
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -26,17 +27,22 @@ pub struct AuditLogApiType {
     pub users: Vec<UserApiType>,
     /// List of webhooks referenced in the audit log
     pub webhooks: Vec<WebhookApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object>
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(remote = "Self")]
 pub struct AuditLogEntryApiType {
     /// ID of the affected entity (webhook, user, role, etc.)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_id: Option<String>,
     /// Changes made to the target_id
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub changes: Option<Vec<AuditLogChangeApiType>>,
+    pub changes: Option<Vec<AuditLogChange>>,
     /// User or app that made the changes
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_id: Option<String>,
@@ -50,73 +56,95 @@ pub struct AuditLogEntryApiType {
     /// Reason for the change (1-512 characters)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-audit-log-events>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u16)]
-#[allow(non_camel_case_types)]
-pub enum AuditLogEvent {
-    GUILD_UPDATE = 1,
-    CHANNEL_CREATE = 10,
-    CHANNEL_UPDATE = 11,
-    CHANNEL_DELETE = 12,
-    CHANNEL_OVERWRITE_CREATE = 13,
-    CHANNEL_OVERWRITE_UPDATE = 14,
-    CHANNEL_OVERWRITE_DELETE = 15,
-    MEMBER_KICK = 20,
-    MEMBER_PRUNE = 21,
-    MEMBER_BAN_ADD = 22,
-    MEMBER_BAN_REMOVE = 23,
-    MEMBER_UPDATE = 24,
-    MEMBER_ROLE_UPDATE = 25,
-    MEMBER_MOVE = 26,
-    MEMBER_DISCONNECT = 27,
-    BOT_ADD = 28,
-    ROLE_CREATE = 30,
-    ROLE_UPDATE = 31,
-    ROLE_DELETE = 32,
-    INVITE_CREATE = 40,
-    INVITE_UPDATE = 41,
-    INVITE_DELETE = 42,
-    WEBHOOK_CREATE = 50,
-    WEBHOOK_UPDATE = 51,
-    WEBHOOK_DELETE = 52,
-    EMOJI_CREATE = 60,
-    EMOJI_UPDATE = 61,
-    EMOJI_DELETE = 62,
-    MESSAGE_DELETE = 72,
-    MESSAGE_BULK_DELETE = 73,
-    MESSAGE_PIN = 74,
-    MESSAGE_UNPIN = 75,
-    INTEGRATION_CREATE = 80,
-    INTEGRATION_UPDATE = 81,
-    INTEGRATION_DELETE = 82,
-    STAGE_INSTANCE_CREATE = 83,
-    STAGE_INSTANCE_UPDATE = 84,
-    STAGE_INSTANCE_DELETE = 85,
-    STICKER_CREATE = 90,
-    STICKER_UPDATE = 91,
-    STICKER_DELETE = 92,
-    GUILD_SCHEDULED_EVENT_CREATE = 100,
-    GUILD_SCHEDULED_EVENT_UPDATE = 101,
-    GUILD_SCHEDULED_EVENT_DELETE = 102,
-    THREAD_CREATE = 110,
-    THREAD_UPDATE = 111,
-    THREAD_DELETE = 112,
-    APPLICATION_COMMAND_PERMISSION_UPDATE = 121,
-    AUTO_MODERATION_RULE_CREATE = 140,
-    AUTO_MODERATION_RULE_UPDATE = 141,
-    AUTO_MODERATION_RULE_DELETE = 142,
-    AUTO_MODERATION_BLOCK_MESSAGE = 143,
-    AUTO_MODERATION_FLAG_TO_CHANNEL = 144,
-    AUTO_MODERATION_USER_COMMUNICATION_DISABLED = 145,
-    CREATOR_MONETIZATION_REQUEST_CREATED = 150,
-    CREATOR_MONETIZATION_TERMS_ACCEPTED = 151,
+impl Serialize for AuditLogEntryApiType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Self::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for AuditLogEntryApiType {
+    /// Changes whose value depends on the changed entity are resolved by the action type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut entry = Self::deserialize(deserializer)?;
+        for change in entry.changes.iter_mut().flatten() {
+            change.resolve(entry.action_type);
+        }
+        Ok(entry)
+    }
+}
+
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-audit-log-events>
+    #[allow(non_camel_case_types)]
+    pub enum AuditLogEvent: u16 {
+        GUILD_UPDATE = 1,
+        CHANNEL_CREATE = 10,
+        CHANNEL_UPDATE = 11,
+        CHANNEL_DELETE = 12,
+        CHANNEL_OVERWRITE_CREATE = 13,
+        CHANNEL_OVERWRITE_UPDATE = 14,
+        CHANNEL_OVERWRITE_DELETE = 15,
+        MEMBER_KICK = 20,
+        MEMBER_PRUNE = 21,
+        MEMBER_BAN_ADD = 22,
+        MEMBER_BAN_REMOVE = 23,
+        MEMBER_UPDATE = 24,
+        MEMBER_ROLE_UPDATE = 25,
+        MEMBER_MOVE = 26,
+        MEMBER_DISCONNECT = 27,
+        BOT_ADD = 28,
+        ROLE_CREATE = 30,
+        ROLE_UPDATE = 31,
+        ROLE_DELETE = 32,
+        INVITE_CREATE = 40,
+        INVITE_UPDATE = 41,
+        INVITE_DELETE = 42,
+        WEBHOOK_CREATE = 50,
+        WEBHOOK_UPDATE = 51,
+        WEBHOOK_DELETE = 52,
+        EMOJI_CREATE = 60,
+        EMOJI_UPDATE = 61,
+        EMOJI_DELETE = 62,
+        MESSAGE_DELETE = 72,
+        MESSAGE_BULK_DELETE = 73,
+        MESSAGE_PIN = 74,
+        MESSAGE_UNPIN = 75,
+        INTEGRATION_CREATE = 80,
+        INTEGRATION_UPDATE = 81,
+        INTEGRATION_DELETE = 82,
+        STAGE_INSTANCE_CREATE = 83,
+        STAGE_INSTANCE_UPDATE = 84,
+        STAGE_INSTANCE_DELETE = 85,
+        STICKER_CREATE = 90,
+        STICKER_UPDATE = 91,
+        STICKER_DELETE = 92,
+        GUILD_SCHEDULED_EVENT_CREATE = 100,
+        GUILD_SCHEDULED_EVENT_UPDATE = 101,
+        GUILD_SCHEDULED_EVENT_DELETE = 102,
+        THREAD_CREATE = 110,
+        THREAD_UPDATE = 111,
+        THREAD_DELETE = 112,
+        APPLICATION_COMMAND_PERMISSION_UPDATE = 121,
+        AUTO_MODERATION_RULE_CREATE = 140,
+        AUTO_MODERATION_RULE_UPDATE = 141,
+        AUTO_MODERATION_RULE_DELETE = 142,
+        AUTO_MODERATION_BLOCK_MESSAGE = 143,
+        AUTO_MODERATION_FLAG_TO_CHANNEL = 144,
+        AUTO_MODERATION_USER_COMMUNICATION_DISABLED = 145,
+        CREATOR_MONETIZATION_REQUEST_CREATED = 150,
+        CREATOR_MONETIZATION_TERMS_ACCEPTED = 151,
+    }
 }
 
 /// <https://discord.com/developers/docs/resources/audit-log#audit-log-change-object>
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Untyped wire representation of a change, see [`AuditLogChange`] for the typed version
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct AuditLogChangeApiType {
     /// New value of the key
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -126,6 +154,10 @@ pub struct AuditLogChangeApiType {
     pub old_value: Option<Value>,
     /// Name of the changed entity, with a few exceptions
     pub key: String,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-optional-audit-entry-info>
@@ -167,4 +199,8 @@ pub struct OptionalAuditEntryInfoApiType {
     /// The type of integration which performed the action
     #[serde(skip_serializing_if = "Option::is_none")]
     pub integration_type: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
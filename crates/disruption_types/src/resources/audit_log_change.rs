@@ -0,0 +1,396 @@
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::channel::{
+    ChannelFlags, DefaultReactionApiType, ForumLayoutType, ForumTagApiType, OverwritesApiType,
+    SortOrderType, VideoQualityModeApiType,
+};
+use crate::entities::{
+    DefaultMessageNotificationLevel, ExplicitContentFilterLevel, GuildMemberFlags, GuildNSFWLevel,
+    MFALevel, RoleFlags, VerificationLevel,
+};
+use crate::locale::Locale;
+use crate::resources::{
+    AuditLogEvent, AutoModerationActionApiType, AutoModerationEventType,
+    AutoModerationTriggerMetadataApiType, AutoModerationTriggerType, GuildScheduledEventEntityType,
+    GuildScheduledEventPrivacyLevel, GuildScheduledEventStatus, StageInstancePrivacyLevel,
+    StickerFormatType,
+};
+
+use crate::timestamp::Timestamp;
+
+use super::AuditLogChangeApiType;
+
+/// Old and new value of a single audit log change
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AuditLogValueChange<T> {
+    /// Old value of the key
+    pub old_value: Option<T>,
+    /// New value of the key
+    pub new_value: Option<T>,
+}
+
+impl<T> AuditLogValueChange<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    fn parse(raw: &AuditLogChangeApiType) -> Option<Self> {
+        let parse = |value: &Option<serde_json::Value>| {
+            value
+                .clone()
+                .filter(|value| !value.is_null())
+                .map(serde_json::from_value)
+                .transpose()
+                .ok()
+        };
+
+        Some(Self {
+            old_value: parse(&raw.old_value)?,
+            new_value: parse(&raw.new_value)?,
+        })
+    }
+
+    fn map(&mut self, f: impl Fn(T) -> T) {
+        self.old_value = self.old_value.take().map(&f);
+        self.new_value = self.new_value.take().map(&f);
+    }
+
+    fn into_raw(self, key: &str) -> AuditLogChangeApiType {
+        AuditLogChangeApiType {
+            old_value: self.old_value.and_then(|v| serde_json::to_value(v).ok()),
+            new_value: self.new_value.and_then(|v| serde_json::to_value(v).ok()),
+            key: key.to_owned(),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
+    }
+}
+
+/// <https://discord.com/developers/docs/resources/audit-log#audit-log-change-object-audit-log-change-exceptions>
+/// Partial role that is added to or removed from a member
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AuditLogPartialRoleApiType {
+    /// role id
+    pub id: String,
+    /// role name
+    pub name: String,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Value of a `type` change, which is an integer or a string depending on the changed entity
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum AuditLogChangeTypeValue {
+    Integer(u64),
+    String(String),
+}
+
+/// Value of a `privacy_level` change, which depends on the changed entity
+///
+/// Changes of an [`AuditLogEntryApiType`](super::AuditLogEntryApiType) are resolved by its
+/// action type, changes parsed on their own are kept as integer.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum AuditLogChangePrivacyLevelValue {
+    Integer(u8),
+    StageInstance(StageInstancePrivacyLevel),
+    ScheduledEvent(GuildScheduledEventPrivacyLevel),
+}
+
+/// Value of a `flags` change, which depends on the changed entity
+///
+/// Changes of an [`AuditLogEntryApiType`](super::AuditLogEntryApiType) are resolved by its
+/// action type, changes parsed on their own are kept as integer.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum AuditLogChangeFlagsValue {
+    Integer(u64),
+    Channel(ChannelFlags),
+    Role(RoleFlags),
+    Member(GuildMemberFlags),
+}
+
+impl AuditLogChange {
+    /// Interpret values whose type depends on the changed entity by the action of the entry.
+    pub(crate) fn resolve(&mut self, action: AuditLogEvent) {
+        use AuditLogEvent::*;
+
+        match self {
+            Self::PrivacyLevel(values) => values.map(|value| match (value, action) {
+                (
+                    AuditLogChangePrivacyLevelValue::Integer(level),
+                    STAGE_INSTANCE_CREATE | STAGE_INSTANCE_UPDATE | STAGE_INSTANCE_DELETE,
+                ) => AuditLogChangePrivacyLevelValue::StageInstance(level.into()),
+                (
+                    AuditLogChangePrivacyLevelValue::Integer(level),
+                    GUILD_SCHEDULED_EVENT_CREATE
+                    | GUILD_SCHEDULED_EVENT_UPDATE
+                    | GUILD_SCHEDULED_EVENT_DELETE,
+                ) => AuditLogChangePrivacyLevelValue::ScheduledEvent(level.into()),
+                (value, _) => value,
+            }),
+            Self::Flags(values) => values.map(|value| {
+                let AuditLogChangeFlagsValue::Integer(bits) = value else {
+                    return value;
+                };
+                match (action, u32::try_from(bits)) {
+                    (
+                        CHANNEL_CREATE | CHANNEL_UPDATE | CHANNEL_DELETE | THREAD_CREATE
+                        | THREAD_UPDATE | THREAD_DELETE,
+                        _,
+                    ) => AuditLogChangeFlagsValue::Channel(ChannelFlags::from_bits_retain(bits)),
+                    (ROLE_CREATE | ROLE_UPDATE | ROLE_DELETE, Ok(bits)) => {
+                        AuditLogChangeFlagsValue::Role(RoleFlags::from_bits_retain(bits))
+                    }
+                    (MEMBER_UPDATE, Ok(bits)) => {
+                        AuditLogChangeFlagsValue::Member(GuildMemberFlags::from_bits_retain(bits))
+                    }
+                    _ => value,
+                }
+            }),
+            _ => {}
+        }
+    }
+}
+
+macro_rules! audit_log_changes {
+    ($($(#[$doc:meta])* $variant:ident($type:ty) = $key:literal,)*) => {
+        /// <https://discord.com/developers/docs/resources/audit-log#audit-log-change-object>
+        /// A typed audit log change, keys that are not known (or fail to parse) are kept as raw change
+        #[derive(Serialize, Deserialize, Debug, Clone)]
+        #[serde(from = "AuditLogChangeApiType", into = "AuditLogChangeApiType")]
+        pub enum AuditLogChange {
+            $($(#[$doc])* $variant(AuditLogValueChange<$type>),)*
+            /// A change of a key that is not known, with its untyped values
+            Unknown(AuditLogChangeApiType),
+        }
+
+        impl AuditLogChange {
+            /// Name of the changed key.
+            pub fn key(&self) -> &str {
+                match self {
+                    $(Self::$variant(_) => $key,)*
+                    Self::Unknown(raw) => raw.key.as_str(),
+                }
+            }
+        }
+
+        impl From<AuditLogChangeApiType> for AuditLogChange {
+            fn from(raw: AuditLogChangeApiType) -> Self {
+                // typed changes can not hold extra fields, so keep those changes raw
+                #[cfg(feature = "extra-fields")]
+                if !raw.extra.is_empty() {
+                    return Self::Unknown(raw);
+                }
+
+                let parsed = match raw.key.as_str() {
+                    $($key => AuditLogValueChange::parse(&raw).map(Self::$variant),)*
+                    _ => None,
+                };
+                parsed.unwrap_or(Self::Unknown(raw))
+            }
+        }
+
+        impl From<AuditLogChange> for AuditLogChangeApiType {
+            fn from(change: AuditLogChange) -> Self {
+                match change {
+                    $(AuditLogChange::$variant(values) => values.into_raw($key),)*
+                    AuditLogChange::Unknown(raw) => raw,
+                }
+            }
+        }
+    };
+}
+
+audit_log_changes! {
+    /// role(s) added to a member
+    RoleAdd(Vec<AuditLogPartialRoleApiType>) = "$add",
+    /// role(s) removed from a member
+    RoleRemove(Vec<AuditLogPartialRoleApiType>) = "$remove",
+    /// actions of an auto moderation rule
+    Actions(Vec<AutoModerationActionApiType>) = "actions",
+    /// id of the afk channel of a guild
+    AfkChannelId(String) = "afk_channel_id",
+    /// afk timeout of a guild in seconds
+    AfkTimeout(u32) = "afk_timeout",
+    /// allowed permissions of a permission overwrite
+    Allow(String) = "allow",
+    /// id of the application of a webhook or integration
+    ApplicationId(String) = "application_id",
+    /// tags applied to a thread in a forum channel
+    AppliedTags(Vec<String>) = "applied_tags",
+    /// whether a thread is archived
+    Archived(bool) = "archived",
+    /// asset of a sticker, always empty
+    Asset(String) = "asset",
+    /// archive duration of a thread in minutes
+    AutoArchiveDuration(u64) = "auto_archive_duration",
+    /// whether a sticker is available
+    Available(bool) = "available",
+    /// tags that can be applied to threads in a forum channel
+    AvailableTags(Vec<ForumTagApiType>) = "available_tags",
+    /// avatar hash of a member or webhook
+    AvatarHash(String) = "avatar_hash",
+    /// banner hash of a guild
+    BannerHash(String) = "banner_hash",
+    /// bitrate of a voice channel
+    Bitrate(u64) = "bitrate",
+    /// channel of an invite, webhook or scheduled event
+    ChannelId(String) = "channel_id",
+    /// invite code
+    Code(String) = "code",
+    /// color of a role
+    Color(crate::color::Color) = "color",
+    /// timestamp until which a member is timed out
+    CommunicationDisabledUntil(Timestamp) = "communication_disabled_until",
+    /// whether a member is server deafened
+    Deaf(bool) = "deaf",
+    /// default archive duration of threads in a channel in minutes
+    DefaultAutoArchiveDuration(u64) = "default_auto_archive_duration",
+    /// default layout of a forum channel
+    DefaultForumLayout(ForumLayoutType) = "default_forum_layout",
+    /// default message notification level of a guild
+    DefaultMessageNotifications(DefaultMessageNotificationLevel) = "default_message_notifications",
+    /// default reaction emoji of a forum channel
+    DefaultReactionEmoji(DefaultReactionApiType) = "default_reaction_emoji",
+    /// default sort order of a forum channel
+    DefaultSortOrder(SortOrderType) = "default_sort_order",
+    /// default slowmode of new threads in a channel
+    DefaultThreadRateLimitPerUser(u64) = "default_thread_rate_limit_per_user",
+    /// denied permissions of a permission overwrite
+    Deny(String) = "deny",
+    /// description of a guild, sticker or scheduled event
+    Description(String) = "description",
+    /// discovery splash hash of a guild
+    DiscoverySplashHash(String) = "discovery_splash_hash",
+    /// whether emoticons are synced for an integration
+    EnableEmoticons(bool) = "enable_emoticons",
+    /// whether an auto moderation rule is enabled
+    Enabled(bool) = "enabled",
+    /// entity type of a scheduled event
+    EntityType(GuildScheduledEventEntityType) = "entity_type",
+    /// event type of an auto moderation rule
+    EventType(AutoModerationEventType) = "event_type",
+    /// channels exempt from an auto moderation rule
+    ExemptChannels(Vec<String>) = "exempt_channels",
+    /// roles exempt from an auto moderation rule
+    ExemptRoles(Vec<String>) = "exempt_roles",
+    /// behavior of expiring integration subscribers
+    ExpireBehavior(u8) = "expire_behavior",
+    /// grace period of expiring integration subscribers in days
+    ExpireGracePeriod(u32) = "expire_grace_period",
+    /// explicit content filter level of a guild
+    ExplicitContentFilter(ExplicitContentFilterLevel) = "explicit_content_filter",
+    /// features of a guild
+    Features(Vec<String>) = "features",
+    /// flags of a channel, thread, role or member
+    Flags(AuditLogChangeFlagsValue) = "flags",
+    /// format type of a sticker
+    FormatType(StickerFormatType) = "format_type",
+    /// guild of a sticker
+    GuildId(String) = "guild_id",
+    /// whether a role is displayed separately
+    Hoist(bool) = "hoist",
+    /// icon hash of a guild or role
+    IconHash(String) = "icon_hash",
+    /// id of the changed entity
+    Id(String) = "id",
+    /// cover image hash of a scheduled event
+    ImageHash(String) = "image_hash",
+    /// whether non-moderators can add other non-moderators to a private thread
+    Invitable(bool) = "invitable",
+    /// creator of an invite
+    InviterId(String) = "inviter_id",
+    /// location of a scheduled event
+    Location(String) = "location",
+    /// whether a thread is locked
+    Locked(bool) = "locked",
+    /// lifetime of an invite in seconds
+    MaxAge(u32) = "max_age",
+    /// maximum number of uses of an invite
+    MaxUses(u32) = "max_uses",
+    /// whether a role is mentionable
+    Mentionable(bool) = "mentionable",
+    /// required MFA level of a guild
+    MfaLevel(MFALevel) = "mfa_level",
+    /// whether a member is server muted
+    Mute(bool) = "mute",
+    /// name of the changed entity
+    Name(String) = "name",
+    /// nickname of a member
+    Nick(String) = "nick",
+    /// whether a channel is age-restricted
+    Nsfw(bool) = "nsfw",
+    /// NSFW level of a guild
+    NsfwLevel(GuildNSFWLevel) = "nsfw_level",
+    /// owner of a guild
+    OwnerId(String) = "owner_id",
+    /// parent category of a channel
+    ParentId(String) = "parent_id",
+    /// permission overwrites of a channel
+    PermissionOverwrites(Vec<OverwritesApiType>) = "permission_overwrites",
+    /// permissions of a role
+    Permissions(String) = "permissions",
+    /// position of a channel or role
+    Position(u64) = "position",
+    /// preferred locale of a guild
+    PreferredLocale(Locale) = "preferred_locale",
+    /// whether the boost progress bar of a guild is enabled
+    PremiumProgressBarEnabled(bool) = "premium_progress_bar_enabled",
+    /// privacy level of a stage instance or scheduled event
+    PrivacyLevel(AuditLogChangePrivacyLevelValue) = "privacy_level",
+    /// public updates channel of a guild
+    PublicUpdatesChannelId(String) = "public_updates_channel_id",
+    /// slowmode of a channel in seconds
+    RateLimitPerUser(u64) = "rate_limit_per_user",
+    /// voice region of a guild (deprecated)
+    Region(String) = "region",
+    /// voice region of a voice channel
+    RtcRegion(String) = "rtc_region",
+    /// rules channel of a guild
+    RulesChannelId(String) = "rules_channel_id",
+    /// safety alerts channel of a guild
+    SafetyAlertsChannelId(String) = "safety_alerts_channel_id",
+    /// scheduled end time of a scheduled event
+    ScheduledEndTime(Timestamp) = "scheduled_end_time",
+    /// scheduled start time of a scheduled event
+    ScheduledStartTime(Timestamp) = "scheduled_start_time",
+    /// splash hash of a guild
+    SplashHash(String) = "splash_hash",
+    /// status of a scheduled event
+    Status(GuildScheduledEventStatus) = "status",
+    /// system channel flags of a guild
+    SystemChannelFlags(crate::entities::SystemChannelFlags) = "system_channel_flags",
+    /// system channel of a guild
+    SystemChannelId(String) = "system_channel_id",
+    /// related emoji of a sticker
+    Tags(String) = "tags",
+    /// whether an invite grants temporary membership
+    Temporary(bool) = "temporary",
+    /// topic of a channel or stage instance
+    Topic(String) = "topic",
+    /// trigger metadata of an auto moderation rule
+    TriggerMetadata(AutoModerationTriggerMetadataApiType) = "trigger_metadata",
+    /// trigger type of an auto moderation rule
+    TriggerType(AutoModerationTriggerType) = "trigger_type",
+    /// type of the changed entity, integer or string depending on the entity
+    Type(AuditLogChangeTypeValue) = "type",
+    /// unicode emoji of a role
+    UnicodeEmoji(String) = "unicode_emoji",
+    /// user limit of a voice channel
+    UserLimit(u64) = "user_limit",
+    /// number of times an invite has been used
+    Uses(u32) = "uses",
+    /// vanity invite code of a guild
+    VanityUrlCode(String) = "vanity_url_code",
+    /// verification level of a guild
+    VerificationLevel(VerificationLevel) = "verification_level",
+    /// camera video quality mode of a voice channel
+    VideoQualityMode(VideoQualityModeApiType) = "video_quality_mode",
+    /// channel the widget of a guild invites to
+    WidgetChannelId(String) = "widget_channel_id",
+    /// whether the widget of a guild is enabled
+    WidgetEnabled(bool) = "widget_enabled",
+}
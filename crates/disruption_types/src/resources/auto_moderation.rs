@@ -1,6 +1,4 @@
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
 /// <https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object>
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -18,7 +16,7 @@ pub struct AutoModerationRuleApiType {
     /// the rule trigger type
     pub trigger_type: AutoModerationTriggerType,
     /// the rule trigger metadata
-    pub trigger_metadata: Value, // TODO: Create TriggerMetadataApiType union
+    pub trigger_metadata: AutoModerationTriggerMetadataApiType,
     /// the actions which will execute when the rule is triggered
     pub actions: Vec<AutoModerationActionApiType>,
     /// whether the rule is enabled
@@ -27,29 +25,79 @@ pub struct AutoModerationRuleApiType {
     pub exempt_roles: Vec<String>,
     /// the channel ids that should not be affected by the rule (Maximum of 50)
     pub exempt_channels: Vec<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-event-types>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-#[allow(non_camel_case_types)]
-pub enum AutoModerationEventType {
-    /// when a member sends or edits a message in the guild
-    MESSAGE_SEND = 1,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-event-types>
+    #[allow(non_camel_case_types)]
+    pub enum AutoModerationEventType: u8 {
+        /// when a member sends or edits a message in the guild
+        MESSAGE_SEND = 1,
+        /// when a member edits their profile
+        MEMBER_UPDATE = 2,
+    }
 }
 
-/// <https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-trigger-types>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum AutoModerationTriggerType {
-    /// check if content contains words from a user defined list of keywords
-    KEYWORD = 1,
-    /// check if content represents generic spam
-    SPAM = 3,
-    /// check if content contains words from internal pre-defined wordsets
-    KEYWORD_PRESET = 4,
-    /// check if content contains more unique mentions than allowed
-    MENTION_SPAM = 5,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-trigger-types>
+    #[allow(non_camel_case_types)]
+    pub enum AutoModerationTriggerType: u8 {
+        /// check if content contains words from a user defined list of keywords
+        KEYWORD = 1,
+        /// check if content represents generic spam
+        SPAM = 3,
+        /// check if content contains words from internal pre-defined wordsets
+        KEYWORD_PRESET = 4,
+        /// check if content contains more unique mentions than allowed
+        MENTION_SPAM = 5,
+        /// check if member profile contains words from a user defined list of keywords
+        MEMBER_PROFILE = 6,
+    }
+}
+
+/// <https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-trigger-metadata>
+/// Which fields may be set depends on the trigger type
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct AutoModerationTriggerMetadataApiType {
+    /// substrings which will be searched for in content (Maximum of 1000), KEYWORD and MEMBER_PROFILE
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keyword_filter: Option<Vec<String>>,
+    /// regular expression patterns which will be matched against content (Maximum of 10), KEYWORD and MEMBER_PROFILE
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regex_patterns: Option<Vec<String>>,
+    /// the internally pre-defined wordsets which will be searched for in content, KEYWORD_PRESET
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presets: Option<Vec<AutoModerationKeywordPresetType>>,
+    /// substrings which should not trigger the rule (Maximum of 100 or 1000), KEYWORD, KEYWORD_PRESET and MEMBER_PROFILE
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_list: Option<Vec<String>>,
+    /// total number of unique role and user mentions allowed per message (Maximum of 50), MENTION_SPAM
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mention_total_limit: Option<u8>,
+    /// whether to automatically detect mention raids, MENTION_SPAM
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mention_raid_protection_enabled: Option<bool>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-keyword-preset-types>
+    #[allow(non_camel_case_types)]
+    pub enum AutoModerationKeywordPresetType: u8 {
+        /// words that may be considered forms of swearing or cursing
+        PROFANITY = 1,
+        /// words that refer to sexually explicit behavior or activity
+        SEXUAL_CONTENT = 2,
+        /// personal insults or words that may be considered hate speech
+        SLURS = 3,
+    }
 }
 
 /// <https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-action-object>
@@ -60,18 +108,102 @@ pub struct AutoModerationActionApiType {
     pub type_: AutoModerationActionType,
     /// additional metadata needed during execution for this specific action type
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<Value>, // TODO: Create ActionMetadataApiType union
+    pub metadata: Option<AutoModerationActionMetadataApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// <https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-action-object-action-metadata>
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct AutoModerationActionMetadataApiType {
+    /// channel to which user content should be logged, SEND_ALERT_MESSAGE
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<String>,
+    /// timeout duration in seconds (Maximum of 2419200 seconds, 4 weeks), TIMEOUT
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_seconds: Option<u32>,
+    /// additional explanation that will be shown to members whenever their message is blocked (Maximum of 150 characters), BLOCK_MESSAGE
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_message: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-action-object-action-types>
+    #[allow(non_camel_case_types)]
+    pub enum AutoModerationActionType: u8 {
+        /// blocks a member's message and prevents it from being posted
+        BLOCK_MESSAGE = 1,
+        /// logs user content to a specified channel
+        SEND_ALERT_MESSAGE = 2,
+        /// timeout user for a specified duration
+        TIMEOUT = 3,
+        /// prevents a member from using text, voice, or other interactions
+        BLOCK_MEMBER_INTERACTION = 4,
+    }
+}
+
+/// <https://discord.com/developers/docs/resources/auto-moderation#create-auto-moderation-rule-json-params>
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreateAutoModerationRuleApiType {
+    /// the rule name
+    pub name: String,
+    /// the event type
+    pub event_type: AutoModerationEventType,
+    /// the trigger type
+    pub trigger_type: AutoModerationTriggerType,
+    /// the trigger metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_metadata: Option<AutoModerationTriggerMetadataApiType>,
+    /// the actions which will execute when the rule is triggered
+    pub actions: Vec<AutoModerationActionApiType>,
+    /// whether the rule is enabled (False by default)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// the role ids that should not be affected by the rule (Maximum of 20)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exempt_roles: Option<Vec<String>>,
+    /// the channel ids that should not be affected by the rule (Maximum of 50)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exempt_channels: Option<Vec<String>>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-action-object-action-types>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-#[allow(non_camel_case_types)]
-pub enum AutoModerationActionType {
-    /// blocks a member's message and prevents it from being posted
-    BLOCK_MESSAGE = 1,
-    /// logs user content to a specified channel
-    SEND_ALERT_MESSAGE = 2,
-    /// timeout user for a specified duration
-    TIMEOUT = 3,
+/// <https://discord.com/developers/docs/resources/auto-moderation#modify-auto-moderation-rule-json-params>
+/// The trigger type of a rule cannot be changed, all fields are optional
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ModifyAutoModerationRuleApiType {
+    /// the rule name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// the event type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_type: Option<AutoModerationEventType>,
+    /// the trigger metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_metadata: Option<AutoModerationTriggerMetadataApiType>,
+    /// the actions which will execute when the rule is triggered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Vec<AutoModerationActionApiType>>,
+    /// whether the rule is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// the role ids that should not be affected by the rule (Maximum of 20)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exempt_roles: Option<Vec<String>>,
+    /// the channel ids that should not be affected by the rule (Maximum of 50)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exempt_channels: Option<Vec<String>>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -1,5 +1,5 @@
+use crate::timestamp::Timestamp;
 use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
 /// <https://discord.com/developers/docs/resources/entitlement#entitlement-object>
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -20,37 +20,41 @@ pub struct EntitlementApiType {
     pub deleted: bool,
     /// Start date at which the entitlement is valid
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub starts_at: Option<String>,
+    pub starts_at: Option<Timestamp>,
     /// Date at which the entitlement is no longer valid
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ends_at: Option<String>,
+    pub ends_at: Option<Timestamp>,
     /// ID of the guild that is granted access to the entitlement's sku
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_id: Option<String>,
     /// For consumable items, whether or not the entitlement has been consumed
     #[serde(skip_serializing_if = "Option::is_none")]
     pub consumed: Option<bool>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/resources/entitlement#entitlement-object-entitlement-types>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-#[allow(non_camel_case_types)]
-pub enum EntitlementType {
-    /// Entitlement was purchased by user
-    PURCHASE = 1,
-    /// Entitlement for Discord Nitro subscription
-    PREMIUM_SUBSCRIPTION = 2,
-    /// Entitlement was gifted by developer
-    DEVELOPER_GIFT = 3,
-    /// Entitlement was purchased by a dev in application test mode
-    TEST_MODE_PURCHASE = 4,
-    /// Entitlement was granted when the SKU was free
-    FREE_PURCHASE = 5,
-    /// Entitlement was gifted by another user
-    USER_GIFT = 6,
-    /// Entitlement was claimed by user for free as a Nitro Subscriber
-    PREMIUM_PURCHASE = 7,
-    /// Entitlement was purchased as an app subscription
-    APPLICATION_SUBSCRIPTION = 8,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/entitlement#entitlement-object-entitlement-types>
+    #[allow(non_camel_case_types)]
+    pub enum EntitlementType: u8 {
+        /// Entitlement was purchased by user
+        PURCHASE = 1,
+        /// Entitlement for Discord Nitro subscription
+        PREMIUM_SUBSCRIPTION = 2,
+        /// Entitlement was gifted by developer
+        DEVELOPER_GIFT = 3,
+        /// Entitlement was purchased by a dev in application test mode
+        TEST_MODE_PURCHASE = 4,
+        /// Entitlement was granted when the SKU was free
+        FREE_PURCHASE = 5,
+        /// Entitlement was gifted by another user
+        USER_GIFT = 6,
+        /// Entitlement was claimed by user for free as a Nitro Subscriber
+        PREMIUM_PURCHASE = 7,
+        /// Entitlement was purchased as an app subscription
+        APPLICATION_SUBSCRIPTION = 8,
+    }
 }
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use crate::entities::{GuildMemberApiType, UserApiType};
+use crate::timestamp::Timestamp;
 
 /// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object>
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -22,10 +22,10 @@ pub struct GuildScheduledEventApiType {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// the time the scheduled event will start
-    pub scheduled_start_time: String,
+    pub scheduled_start_time: Timestamp,
     /// the time the scheduled event will end, required if entity_type is EXTERNAL
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scheduled_end_time: Option<String>,
+    pub scheduled_end_time: Option<Timestamp>,
     /// the privacy level of the scheduled event
     pub privacy_level: GuildScheduledEventPrivacyLevel,
     /// the status of the scheduled event
@@ -50,35 +50,39 @@ pub struct GuildScheduledEventApiType {
     /// the definition for how often this event should recur
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recurrence_rule: Option<GuildScheduledEventRecurrenceRuleApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-privacy-level>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-#[allow(non_camel_case_types)]
-pub enum GuildScheduledEventPrivacyLevel {
-    /// the scheduled event is only accessible to guild members
-    GUILD_ONLY = 2,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-privacy-level>
+    #[allow(non_camel_case_types)]
+    pub enum GuildScheduledEventPrivacyLevel: u8 {
+        /// the scheduled event is only accessible to guild members
+        GUILD_ONLY = 2,
+    }
 }
 
-/// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-status>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum GuildScheduledEventStatus {
-    SCHEDULED = 1,
-    ACTIVE = 2,
-    COMPLETED = 3,
-    CANCELED = 4,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-status>
+    pub enum GuildScheduledEventStatus: u8 {
+        SCHEDULED = 1,
+        ACTIVE = 2,
+        COMPLETED = 3,
+        CANCELED = 4,
+    }
 }
 
-/// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-entity-types>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-#[allow(non_camel_case_types)]
-pub enum GuildScheduledEventEntityType {
-    STAGE_INSTANCE = 1,
-    VOICE = 2,
-    EXTERNAL = 3,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-entity-types>
+    #[allow(non_camel_case_types)]
+    pub enum GuildScheduledEventEntityType: u8 {
+        STAGE_INSTANCE = 1,
+        VOICE = 2,
+        EXTERNAL = 3,
+    }
 }
 
 /// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-entity-metadata>
@@ -87,6 +91,10 @@ pub struct GuildScheduledEventEntityMetadataApiType {
     /// location of the event (1-100 characters)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub location: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-user-object>
@@ -99,16 +107,20 @@ pub struct GuildScheduledEventUserApiType {
     /// guild member data for this user for the guild which this event belongs to, if any
     #[serde(skip_serializing_if = "Option::is_none")]
     pub member: Option<GuildMemberApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object>
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GuildScheduledEventRecurrenceRuleApiType {
     /// Starting time of the recurrence interval
-    pub start: String, // ISO8601 timestamp
+    pub start: Timestamp,
     /// Ending time of the recurrence interval (cannot be set externally currently)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub end: Option<String>, // ISO8601 timestamp
+    pub end: Option<Timestamp>,
     /// How often the event occurs
     pub frequency: GuildScheduledEventRecurrenceRuleFrequency,
     /// The spacing between the events, defined by frequency
@@ -131,29 +143,33 @@ pub struct GuildScheduledEventRecurrenceRuleApiType {
     /// The total amount of times that the event is allowed to recur before stopping (cannot be set externally currently)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub count: Option<u32>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object-guild-scheduled-event-recurrence-rule-frequency>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum GuildScheduledEventRecurrenceRuleFrequency {
-    Yearly = 0,
-    Monthly = 1,
-    Weekly = 2,
-    Daily = 3,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object-guild-scheduled-event-recurrence-rule-frequency>
+    pub enum GuildScheduledEventRecurrenceRuleFrequency: u8 {
+        Yearly = 0,
+        Monthly = 1,
+        Weekly = 2,
+        Daily = 3,
+    }
 }
 
-/// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object-guild-scheduled-event-recurrence-rule-weekday>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum GuildScheduledEventRecurrenceRuleWeekday {
-    Monday = 0,
-    Tuesday = 1,
-    Wednesday = 2,
-    Thursday = 3,
-    Friday = 4,
-    Saturday = 5,
-    Sunday = 6,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object-guild-scheduled-event-recurrence-rule-weekday>
+    pub enum GuildScheduledEventRecurrenceRuleWeekday: u8 {
+        Monday = 0,
+        Tuesday = 1,
+        Wednesday = 2,
+        Thursday = 3,
+        Friday = 4,
+        Saturday = 5,
+        Sunday = 6,
+    }
 }
 
 /// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object-guild-scheduled-event-recurrence-rule-nweekday-structure>
@@ -163,22 +179,26 @@ pub struct GuildScheduledEventRecurrenceRuleNWeekdayApiType {
     pub n: u8,
     /// The day within the week to reoccur on
     pub day: GuildScheduledEventRecurrenceRuleWeekday,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object-guild-scheduled-event-recurrence-rule-month>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum GuildScheduledEventRecurrenceRuleMonth {
-    January = 1,
-    February = 2,
-    March = 3,
-    April = 4,
-    May = 5,
-    June = 6,
-    July = 7,
-    August = 8,
-    September = 9,
-    October = 10,
-    November = 11,
-    December = 12,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-recurrence-rule-object-guild-scheduled-event-recurrence-rule-month>
+    pub enum GuildScheduledEventRecurrenceRuleMonth: u8 {
+        January = 1,
+        February = 2,
+        March = 3,
+        April = 4,
+        May = 5,
+        June = 6,
+        July = 7,
+        August = 8,
+        September = 9,
+        October = 10,
+        November = 11,
+        December = 12,
+    }
 }
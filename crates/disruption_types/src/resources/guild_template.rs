@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::entities::UserApiType;
+use crate::timestamp::Timestamp;
 
 /// <https://discord.com/developers/docs/resources/guild-template#guild-template-object>
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -20,9 +21,9 @@ pub struct GuildTemplateApiType {
     /// the user who created the template
     pub creator: UserApiType,
     /// when this template was created
-    pub created_at: String,
+    pub created_at: Timestamp,
     /// when this template was last synced to the source guild
-    pub updated_at: String,
+    pub updated_at: Timestamp,
     /// the ID of the guild this template is based on
     pub source_guild_id: String,
     /// the guild snapshot this template contains
@@ -30,4 +31,8 @@ pub struct GuildTemplateApiType {
     /// whether the template has unsynced changes
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_dirty: Option<bool>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -1,9 +1,9 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use crate::entities::{GuildMemberApiType, UserApiType};
 use crate::resources::GuildScheduledEventApiType;
+use crate::timestamp::Timestamp;
 
 /// <https://discord.com/developers/docs/resources/invite#invite-object>
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -39,37 +39,44 @@ pub struct InviteApiType {
     pub approximate_member_count: Option<u32>,
     /// the expiration date of this invite
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expires_at: Option<String>,
+    pub expires_at: Option<Timestamp>,
     /// guild scheduled event data
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_scheduled_event: Option<GuildScheduledEventApiType>,
     /// guild invite flags for guild invites
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub flags: Option<u32>,
+    pub flags: Option<GuildInviteFlags>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/resources/invite#invite-object-invite-types>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum InviteType {
-    GUILD = 0,
-    GROUP_DM = 1,
-    FRIEND = 2,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/invite#invite-object-invite-types>
+    #[allow(non_camel_case_types)]
+    pub enum InviteType: u8 {
+        GUILD = 0,
+        GROUP_DM = 1,
+        FRIEND = 2,
+    }
 }
 
-/// <https://discord.com/developers/docs/resources/invite#invite-object-invite-target-types>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum InviteTargetType {
-    STREAM = 1,
-    EMBEDDED_APPLICATION = 2,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/invite#invite-object-invite-target-types>
+    #[allow(non_camel_case_types)]
+    pub enum InviteTargetType: u8 {
+        STREAM = 1,
+        EMBEDDED_APPLICATION = 2,
+    }
 }
 
-/// <https://discord.com/developers/docs/resources/invite#invite-object-guild-invite-flags>
-#[allow(non_camel_case_types)]
-pub enum GuildInviteFlags {
-    /// this invite is a guest invite for a voice channel
-    IS_GUEST_INVITE = 1 << 0,
+flags! {
+    /// <https://discord.com/developers/docs/resources/invite#invite-object-guild-invite-flags>
+    pub struct GuildInviteFlags: u32 {
+        /// this invite is a guest invite for a voice channel
+        const IS_GUEST_INVITE = 1 << 0;
+    }
 }
 
 /// <https://discord.com/developers/docs/resources/invite#invite-metadata-object>
@@ -84,7 +91,11 @@ pub struct InviteMetadataApiType {
     /// whether this invite only grants temporary membership
     pub temporary: bool,
     /// when this invite was created
-    pub created_at: String,
+    pub created_at: Timestamp,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/invite#invite-stage-instance-object>
@@ -98,4 +109,8 @@ pub struct InviteStageInstanceApiType {
     pub speaker_count: u32,
     /// the topic of the Stage instance (1-120 characters)
     pub topic: String,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -1,4 +1,5 @@
 mod audit_log;
+mod audit_log_change;
 mod auto_moderation;
 mod entitlement;
 mod guild_scheduled_event;
@@ -14,6 +15,7 @@ mod voice;
 mod webhook;
 
 pub use self::audit_log::*;
+pub use self::audit_log_change::*;
 pub use self::auto_moderation::*;
 pub use self::entitlement::*;
 pub use self::guild_scheduled_event::*;
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use crate::entities::EmojiApiType;
+use crate::timestamp::Timestamp;
 
 /// <https://discord.com/developers/docs/resources/poll#poll-object>
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -12,7 +12,7 @@ pub struct PollApiType {
     pub answers: Vec<PollAnswerApiType>,
     /// The time when the poll ends.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiry: Option<String>,
+    pub expiry: Option<Timestamp>,
     /// Whether a user can select multiple answers
     pub allow_multiselect: bool,
     /// The layout type of the poll
@@ -20,6 +20,43 @@ pub struct PollApiType {
     /// The results of the poll
     #[serde(skip_serializing_if = "Option::is_none")]
     pub results: Option<PollResultsApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// <https://discord.com/developers/docs/resources/poll#poll-create-request-object>
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PollCreateRequestApiType {
+    /// The question of the poll. Only text is supported.
+    pub question: PollMediaApiType,
+    /// Each of the answers available in the poll, up to 10
+    pub answers: Vec<PollAnswerCreateApiType>,
+    /// Number of hours the poll should be open for, up to 32 days. Defaults to 24
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u32>,
+    /// Whether a user can select multiple answers. Defaults to false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_multiselect: Option<bool>,
+    /// The layout type of the poll. Defaults to... DEFAULT!
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub layout_type: Option<PollLayoutType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// An answer of a poll that is about to be created
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PollAnswerCreateApiType {
+    /// The data of the answer
+    pub poll_media: PollMediaApiType,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/poll#poll-media-object>
@@ -31,6 +68,10 @@ pub struct PollMediaApiType {
     /// The emoji of the field
     #[serde(skip_serializing_if = "Option::is_none")]
     pub emoji: Option<EmojiApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/poll#poll-answer-object>
@@ -40,6 +81,10 @@ pub struct PollAnswerApiType {
     pub answer_id: u32,
     /// The data of the answer
     pub poll_media: PollMediaApiType,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/poll#poll-results-object>
@@ -49,6 +94,10 @@ pub struct PollResultsApiType {
     pub is_finalized: bool,
     /// The counts for each answer
     pub answer_counts: Vec<PollAnswerCountApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/poll#poll-answer-count-object>
@@ -60,12 +109,16 @@ pub struct PollAnswerCountApiType {
     pub count: u32,
     /// Whether the current user voted for this answer
     pub me_voted: bool,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/resources/poll#layout-type>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum PollLayoutType {
-    /// The default layout type
-    Default = 1,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/poll#layout-type>
+    pub enum PollLayoutType: u8 {
+        /// The default layout type
+        Default = 1,
+    }
 }
@@ -1,5 +1,4 @@
 use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
 /// <https://discord.com/developers/docs/resources/sku#sku-object>
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -16,30 +15,36 @@ pub struct SKUApiType {
     /// System-generated URL slug based on the SKU's name
     pub slug: String,
     /// SKU flags combined as a bitfield
-    pub flags: u32,
+    pub flags: SKUFlags,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/resources/sku#sku-object-sku-types>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SKUType {
-    /// Durable one-time purchase
-    DURABLE = 2,
-    /// Consumable one-time purchase
-    CONSUMABLE = 3,
-    /// Represents a recurring subscription
-    SUBSCRIPTION = 5,
-    /// System-generated group for each SUBSCRIPTION SKU created
-    SUBSCRIPTION_GROUP = 6,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/sku#sku-object-sku-types>
+    #[allow(non_camel_case_types)]
+    pub enum SKUType: u8 {
+        /// Durable one-time purchase
+        DURABLE = 2,
+        /// Consumable one-time purchase
+        CONSUMABLE = 3,
+        /// Represents a recurring subscription
+        SUBSCRIPTION = 5,
+        /// System-generated group for each SUBSCRIPTION SKU created
+        SUBSCRIPTION_GROUP = 6,
+    }
 }
 
-/// <https://discord.com/developers/docs/resources/sku#sku-object-sku-flags>
-#[allow(non_camel_case_types)]
-pub enum SKUFlags {
-    /// SKU is available for purchase
-    AVAILABLE = 1 << 2,
-    /// Recurring SKU that can be purchased by a user and applied to a single server
-    GUILD_SUBSCRIPTION = 1 << 7,
-    /// Recurring SKU purchased by a user for themselves
-    USER_SUBSCRIPTION = 1 << 8,
+flags! {
+    /// <https://discord.com/developers/docs/resources/sku#sku-object-sku-flags>
+    pub struct SKUFlags: u32 {
+        /// SKU is available for purchase
+        const AVAILABLE = 1 << 2;
+        /// Recurring SKU that can be purchased by a user and applied to a single server
+        const GUILD_SUBSCRIPTION = 1 << 7;
+        /// Recurring SKU purchased by a user for themselves
+        const USER_SUBSCRIPTION = 1 << 8;
+    }
 }
@@ -25,4 +25,8 @@ pub struct SoundboardSoundApiType {
     /// the user who created this sound
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<UserApiType>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -1,5 +1,4 @@
 use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
 /// <https://discord.com/developers/docs/resources/stage-instance#stage-instance-object>
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -19,15 +18,19 @@ pub struct StageInstanceApiType {
     /// The id of the scheduled event for this Stage instance
     #[serde(skip_serializing_if = "Option::is_none")]
     pub guild_scheduled_event_id: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/resources/stage-instance#stage-instance-object-privacy-level>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-#[allow(non_camel_case_types)]
-pub enum StageInstancePrivacyLevel {
-    /// The Stage instance is visible publicly (deprecated)
-    PUBLIC = 1,
-    /// The Stage instance is visible to only guild members
-    GUILD_ONLY = 2,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/stage-instance#stage-instance-object-privacy-level>
+    #[allow(non_camel_case_types)]
+    pub enum StageInstancePrivacyLevel: u8 {
+        /// The Stage instance is visible publicly (deprecated)
+        PUBLIC = 1,
+        /// The Stage instance is visible to only guild members
+        GUILD_ONLY = 2,
+    }
 }
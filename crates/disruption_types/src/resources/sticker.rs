@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
+use crate::cdn::{CdnError, CdnUrl};
 use crate::entities::UserApiType;
 
 /// <https://discord.com/developers/docs/resources/sticker#sticker-object>
@@ -35,6 +35,17 @@ pub struct StickerApiType {
     /// the standard sticker's sort order within its pack
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sort_value: Option<u32>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl StickerApiType {
+    /// URL of the sticker, in the format it was uploaded in.
+    pub fn url(&self) -> Result<CdnUrl, CdnError> {
+        CdnUrl::sticker(&self.id, self.format_type)
+    }
 }
 
 /// <https://discord.com/developers/docs/resources/sticker#sticker-item-object>
@@ -46,26 +57,37 @@ pub struct StickerItemApiType {
     pub name: String,
     /// type of sticker format
     pub format_type: StickerFormatType,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl StickerItemApiType {
+    /// URL of the sticker, in the format it was uploaded in.
+    pub fn url(&self) -> Result<CdnUrl, CdnError> {
+        CdnUrl::sticker(&self.id, self.format_type)
+    }
 }
 
-/// <https://discord.com/developers/docs/resources/sticker#sticker-object-sticker-types>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum StickerType {
-    /// an official sticker in a pack
-    STANDARD = 1,
-    /// a sticker uploaded to a guild for the guild's members
-    GUILD = 2,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/sticker#sticker-object-sticker-types>
+    pub enum StickerType: u8 {
+        /// an official sticker in a pack
+        STANDARD = 1,
+        /// a sticker uploaded to a guild for the guild's members
+        GUILD = 2,
+    }
 }
 
-/// <https://discord.com/developers/docs/resources/sticker#sticker-object-sticker-format-types>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum StickerFormatType {
-    PNG = 1,
-    APNG = 2,
-    LOTTIE = 3,
-    GIF = 4,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/sticker#sticker-object-sticker-format-types>
+    pub enum StickerFormatType: u8 {
+        PNG = 1,
+        APNG = 2,
+        LOTTIE = 3,
+        GIF = 4,
+    }
 }
 
 /// <https://discord.com/developers/docs/resources/sticker#sticker-pack-object>
@@ -87,4 +109,8 @@ pub struct StickerPackApiType {
     /// id of the sticker pack's banner image
     #[serde(skip_serializing_if = "Option::is_none")]
     pub banner_asset_id: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
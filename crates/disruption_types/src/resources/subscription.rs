@@ -1,5 +1,5 @@
+use crate::timestamp::Timestamp;
 use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
 /// <https://discord.com/developers/docs/resources/subscription#subscription-object>
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -16,27 +16,31 @@ pub struct SubscriptionApiType {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub renewal_sku_ids: Option<Vec<String>>,
     /// Start of the current subscription period
-    pub current_period_start: String,
+    pub current_period_start: Timestamp,
     /// End of the current subscription period
-    pub current_period_end: String,
+    pub current_period_end: Timestamp,
     /// Current status of the subscription
     pub status: SubscriptionStatus,
     /// When the subscription was canceled
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub canceled_at: Option<String>,
+    pub canceled_at: Option<Timestamp>,
     /// ISO3166-1 alpha-2 country code of the payment source
     #[serde(skip_serializing_if = "Option::is_none")]
     pub country: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/resources/subscription#subscription-object-subscription-statuses>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum SubscriptionStatus {
-    /// Subscription is active and scheduled to renew
-    ACTIVE = 0,
-    /// Subscription is active but will not renew
-    ENDING = 1,
-    /// Subscription is inactive and not being charged
-    INACTIVE = 2,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/subscription#subscription-object-subscription-statuses>
+    pub enum SubscriptionStatus: u8 {
+        /// Subscription is active and scheduled to renew
+        ACTIVE = 0,
+        /// Subscription is active but will not renew
+        ENDING = 1,
+        /// Subscription is inactive and not being charged
+        INACTIVE = 2,
+    }
 }
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::entities::GuildMemberApiType;
+use crate::timestamp::Timestamp;
 
 /// <https://discord.com/developers/docs/resources/voice#voice-state-object>
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -35,7 +36,11 @@ pub struct VoiceStateApiType {
     pub suppress: bool,
     /// the time at which the user requested to speak
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub request_to_speak_timestamp: Option<String>,
+    pub request_to_speak_timestamp: Option<Timestamp>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// <https://discord.com/developers/docs/resources/voice#voice-region-object>
@@ -51,4 +56,8 @@ pub struct VoiceRegionApiType {
     pub deprecated: bool,
     /// whether this is a custom voice region (used for events/etc)
     pub custom: bool,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -1,6 +1,5 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use crate::entities::UserApiType;
 
@@ -44,16 +43,20 @@ pub struct WebhookApiType {
     /// the url used for executing the webhook (returned by the webhooks OAuth2 flow)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    /// fields that are not modeled by this type
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-/// <https://discord.com/developers/docs/resources/webhook#webhook-object-webhook-types>
-#[derive(Serialize_repr, Deserialize_repr, Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-pub enum WebhookType {
-    /// Incoming Webhooks can post messages to channels with a generated token
-    Incoming = 1,
-    /// Channel Follower Webhooks are internal webhooks used with Channel Following to post new messages into channels
-    ChannelFollower = 2,
-    /// Application webhooks are webhooks used with Interactions
-    Application = 3,
+wire_enum! {
+    /// <https://discord.com/developers/docs/resources/webhook#webhook-object-webhook-types>
+    pub enum WebhookType: u8 {
+        /// Incoming Webhooks can post messages to channels with a generated token
+        Incoming = 1,
+        /// Channel Follower Webhooks are internal webhooks used with Channel Following to post new messages into channels
+        ChannelFollower = 2,
+        /// Application webhooks are webhooks used with Interactions
+        Application = 3,
+    }
 }
@@ -0,0 +1,373 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+const MILLIS_PER_DAY: i64 = 86_400_000;
+/// Milliseconds since the unix epoch of the first second of 2015, the epoch of snowflake ids
+pub const DISCORD_EPOCH: i64 = 1_420_070_400_000;
+
+/// An ISO8601 timestamp as it is sent by Discord, with millisecond precision.
+///
+/// Timestamps are serialized as `YYYY-MM-DDTHH:MM:SS+00:00`, with fractional seconds if the parsed
+/// timestamp had them (e.g. `2020-01-01T00:00:00.000000+00:00`). Comparisons only consider the
+/// point in time.
+#[derive(Debug, Clone, Copy)]
+pub struct Timestamp {
+    millis: i64,
+    fractional: bool,
+}
+
+impl Timestamp {
+    /// Create a timestamp from milliseconds since the unix epoch.
+    pub fn from_unix_millis(millis: i64) -> Self {
+        Timestamp {
+            millis,
+            fractional: true,
+        }
+    }
+
+    /// Create a timestamp from seconds since the unix epoch.
+    pub fn from_unix_secs(secs: i64) -> Self {
+        Timestamp {
+            millis: secs.saturating_mul(1000),
+            fractional: false,
+        }
+    }
+
+    /// Time at which the object with the given snowflake id was created.
+    ///
+    /// <https://discord.com/developers/docs/reference#snowflakes>
+    pub fn from_snowflake(id: &str) -> Option<Self> {
+        let id: u64 = id.parse().ok()?;
+        Some(Self::from_unix_millis((id >> 22) as i64 + DISCORD_EPOCH))
+    }
+
+    /// The smallest snowflake id of an object created at this time, e.g. for paginating from a
+    /// point in time. `None` for timestamps before the Discord epoch.
+    pub fn to_snowflake(&self) -> Option<String> {
+        let millis = u64::try_from(self.millis.checked_sub(DISCORD_EPOCH)?).ok()?;
+        Some((millis << 22).to_string())
+    }
+
+    /// The current time.
+    pub fn now() -> Self {
+        Self::from(SystemTime::now())
+    }
+
+    /// Milliseconds since the unix epoch.
+    pub fn unix_millis(&self) -> i64 {
+        self.millis
+    }
+
+    /// Whole seconds since the unix epoch.
+    pub fn unix_secs(&self) -> i64 {
+        self.millis.div_euclid(1000)
+    }
+
+    /// Convert the timestamp into a [`SystemTime`].
+    pub fn to_system_time(&self) -> SystemTime {
+        let offset = Duration::from_millis(self.millis.unsigned_abs());
+        if self.millis >= 0 {
+            UNIX_EPOCH + offset
+        } else {
+            UNIX_EPOCH - offset
+        }
+    }
+
+    /// Time that passed between `earlier` and this timestamp, zero if `earlier` is later.
+    pub fn duration_since(&self, earlier: Timestamp) -> Duration {
+        Duration::from_millis(self.millis.saturating_sub(earlier.millis).max(0) as u64)
+    }
+
+    /// Time that passed since this timestamp, zero if it lies in the future.
+    pub fn elapsed(&self) -> Duration {
+        Self::now().duration_since(*self)
+    }
+
+    /// Add a duration, returning `None` on overflow.
+    pub fn checked_add(&self, duration: Duration) -> Option<Timestamp> {
+        let millis = i64::try_from(duration.as_millis()).ok()?;
+        Some(Timestamp {
+            millis: self.millis.checked_add(millis)?,
+            ..*self
+        })
+    }
+
+    /// Subtract a duration, returning `None` on overflow.
+    pub fn checked_sub(&self, duration: Duration) -> Option<Timestamp> {
+        let millis = i64::try_from(duration.as_millis()).ok()?;
+        Some(Timestamp {
+            millis: self.millis.checked_sub(millis)?,
+            ..*self
+        })
+    }
+
+    fn parse(input: &str) -> Option<Timestamp> {
+        let bytes = input.as_bytes();
+        let number = |start: usize, len: usize| -> Option<i64> {
+            let digits = bytes.get(start..start + len)?;
+            digits.iter().try_fold(0, |acc, digit| {
+                digit
+                    .is_ascii_digit()
+                    .then(|| acc * 10 + i64::from(digit - b'0'))
+            })
+        };
+        let separator = |index: usize, expected: &[u8]| {
+            bytes
+                .get(index)
+                .filter(|byte| expected.contains(byte))
+                .map(|_| ())
+        };
+
+        let year = number(0, 4)?;
+        separator(4, b"-")?;
+        let month = number(5, 2)?;
+        separator(7, b"-")?;
+        let day = number(8, 2)?;
+        separator(10, b"Tt ")?;
+        let hour = number(11, 2)?;
+        separator(13, b":")?;
+        let minute = number(14, 2)?;
+        separator(16, b":")?;
+        let second = number(17, 2)?;
+
+        let mut index = 19;
+        let mut millis = 0;
+        let fractional = bytes.get(index) == Some(&b'.');
+        if fractional {
+            index += 1;
+            let start = index;
+            while bytes.get(index).is_some_and(u8::is_ascii_digit) {
+                index += 1;
+            }
+            if index == start {
+                return None;
+            }
+            millis = bytes[start..index]
+                .iter()
+                .chain(std::iter::repeat(&b'0'))
+                .take(3)
+                .fold(0, |acc, digit| acc * 10 + i64::from(digit - b'0'));
+        }
+
+        let offset_minutes = match bytes.get(index)? {
+            b'Z' | b'z' if bytes.len() == index + 1 => 0,
+            sign @ (b'+' | b'-') if bytes.len() == index + 6 => {
+                let hours = number(index + 1, 2)?;
+                separator(index + 3, b":")?;
+                let minutes = number(index + 4, 2)?;
+                if hours > 23 || minutes > 59 {
+                    return None;
+                }
+                let offset = hours * 60 + minutes;
+                if *sign == b'-' {
+                    -offset
+                } else {
+                    offset
+                }
+            }
+            _ => return None,
+        };
+
+        if !(1..=12).contains(&month)
+            || day < 1
+            || day > days_in_month(year, month)
+            || hour > 23
+            || minute > 59
+            || second > 59
+        {
+            return None;
+        }
+
+        let seconds =
+            days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second
+                - offset_minutes * 60;
+
+        Some(Timestamp {
+            millis: seconds * 1000 + millis,
+            fractional,
+        })
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since the unix epoch of a date in the proleptic gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Date in the proleptic gregorian calendar of a number of days since the unix epoch.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400;
+
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (year, month, day) = civil_from_days(self.millis.div_euclid(MILLIS_PER_DAY));
+        let millis_of_day = self.millis.rem_euclid(MILLIS_PER_DAY);
+        let seconds_of_day = millis_of_day / 1000;
+
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            year,
+            month,
+            day,
+            seconds_of_day / 3600,
+            seconds_of_day / 60 % 60,
+            seconds_of_day % 60
+        )?;
+        if self.fractional || millis_of_day % 1000 != 0 {
+            write!(f, ".{:06}", millis_of_day % 1000 * 1000)?;
+        }
+        write!(f, "+00:00")
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = TimestampParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s).ok_or_else(|| TimestampParseError {
+            input: s.to_owned(),
+        })
+    }
+}
+
+impl Default for Timestamp {
+    /// The unix epoch.
+    fn default() -> Self {
+        Self::from_unix_millis(0)
+    }
+}
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.millis == other.millis
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.millis.cmp(&other.millis)
+    }
+}
+
+impl Hash for Timestamp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.millis.hash(state);
+    }
+}
+
+impl From<SystemTime> for Timestamp {
+    fn from(time: SystemTime) -> Self {
+        let millis = match time.duration_since(UNIX_EPOCH) {
+            Ok(since) => i64::try_from(since.as_millis()).unwrap_or(i64::MAX),
+            Err(before) => -i64::try_from(before.duration().as_millis()).unwrap_or(i64::MAX),
+        };
+        Self::from_unix_millis(millis)
+    }
+}
+
+impl From<Timestamp> for SystemTime {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.to_system_time()
+    }
+}
+
+impl Add<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    fn add(self, duration: Duration) -> Timestamp {
+        self.checked_add(duration)
+            .expect("overflow when adding duration to timestamp")
+    }
+}
+
+impl Sub<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    fn sub(self, duration: Duration) -> Timestamp {
+        self.checked_sub(duration)
+            .expect("overflow when subtracting duration from timestamp")
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let input = String::deserialize(deserializer)?;
+        input.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Error for a string that is not a valid ISO8601 timestamp
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimestampParseError {
+    /// the input that failed to parse
+    pub input: String,
+}
+
+impl Display for TimestampParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid ISO8601 timestamp {:?}", self.input)
+    }
+}
+
+impl std::error::Error for TimestampParseError {}
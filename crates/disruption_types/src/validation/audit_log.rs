@@ -0,0 +1,13 @@
+use super::{ValidationErrors, Validator};
+
+/// Maximum number of characters of an audit log reason
+pub const AUDIT_LOG_REASON_LENGTH: usize = 512;
+
+/// Check the reason that is sent with a request in the `X-Audit-Log-Reason` header.
+///
+/// <https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object>
+pub fn validate_audit_log_reason(reason: &str) -> Result<(), ValidationErrors> {
+    Validator::run(|validator| {
+        validator.length("reason", reason, 1, AUDIT_LOG_REASON_LENGTH);
+    })
+}
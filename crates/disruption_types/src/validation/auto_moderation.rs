@@ -0,0 +1,252 @@
+use crate::resources::{
+    AutoModerationActionApiType, AutoModerationActionType, AutoModerationTriggerMetadataApiType,
+    AutoModerationTriggerType, CreateAutoModerationRuleApiType, ModifyAutoModerationRuleApiType,
+};
+
+use super::{Validate, ValidationErrorKind, ValidationErrors, Validator};
+
+/// Maximum number of keywords of a rule
+pub const MAX_RULE_KEYWORDS: usize = 1000;
+/// Maximum number of characters of a keyword or allow list entry
+pub const RULE_KEYWORD_LENGTH: usize = 60;
+/// Maximum number of regex patterns of a rule
+pub const MAX_RULE_REGEX_PATTERNS: usize = 10;
+/// Maximum number of characters of a regex pattern
+pub const RULE_REGEX_PATTERN_LENGTH: usize = 260;
+/// Maximum number of allow list entries of a keyword or member profile rule
+pub const MAX_RULE_ALLOW_LIST: usize = 100;
+/// Maximum number of allow list entries of a keyword preset rule
+pub const MAX_RULE_PRESET_ALLOW_LIST: usize = 1000;
+/// Upper bound of the total number of mentions allowed per message
+pub const MAX_RULE_MENTION_TOTAL_LIMIT: u64 = 50;
+/// Maximum number of roles exempt from a rule
+pub const MAX_RULE_EXEMPT_ROLES: usize = 20;
+/// Maximum number of channels exempt from a rule
+pub const MAX_RULE_EXEMPT_CHANNELS: usize = 50;
+/// Maximum number of seconds of a timeout action (4 weeks)
+pub const MAX_ACTION_TIMEOUT_SECONDS: u64 = 2_419_200;
+/// Maximum number of characters of the custom message of a block message action
+pub const ACTION_CUSTOM_MESSAGE_LENGTH: usize = 150;
+
+const UNSUPPORTED_ACTION: ValidationErrorKind =
+    ValidationErrorKind::Invalid("action is not available for the trigger type");
+
+/// <https://discord.com/developers/docs/resources/auto-moderation#create-auto-moderation-rule-json-params>
+impl Validate for CreateAutoModerationRuleApiType {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Validator::run(|validator| {
+            check_rule(
+                validator,
+                Some(self.trigger_type),
+                self.trigger_metadata.as_ref(),
+                Some(&self.actions),
+                self.exempt_roles.as_deref(),
+                self.exempt_channels.as_deref(),
+            );
+        })
+    }
+}
+
+/// <https://discord.com/developers/docs/resources/auto-moderation#modify-auto-moderation-rule-json-params>
+///
+/// Only checks the limits that do not depend on the trigger type of the rule, see
+/// [`ModifyAutoModerationRuleApiType::validate_for`].
+impl Validate for ModifyAutoModerationRuleApiType {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Validator::run(|validator| check_modify_rule(validator, self, None))
+    }
+}
+
+impl ModifyAutoModerationRuleApiType {
+    /// Check the changes against the trigger type of the rule that is being modified.
+    pub fn validate_for(
+        &self,
+        trigger_type: AutoModerationTriggerType,
+    ) -> Result<(), ValidationErrors> {
+        Validator::run(|validator| check_modify_rule(validator, self, Some(trigger_type)))
+    }
+}
+
+fn check_modify_rule(
+    validator: &mut Validator,
+    rule: &ModifyAutoModerationRuleApiType,
+    trigger_type: Option<AutoModerationTriggerType>,
+) {
+    check_rule(
+        validator,
+        trigger_type,
+        rule.trigger_metadata.as_ref(),
+        rule.actions.as_deref(),
+        rule.exempt_roles.as_deref(),
+        rule.exempt_channels.as_deref(),
+    );
+}
+
+fn check_rule(
+    validator: &mut Validator,
+    trigger_type: Option<AutoModerationTriggerType>,
+    metadata: Option<&AutoModerationTriggerMetadataApiType>,
+    actions: Option<&[AutoModerationActionApiType]>,
+    exempt_roles: Option<&[String]>,
+    exempt_channels: Option<&[String]>,
+) {
+    if let Some(metadata) = metadata {
+        validator.field("trigger_metadata", |validator| {
+            check_trigger_metadata(validator, metadata, trigger_type)
+        });
+    }
+    validator.each(
+        "actions",
+        actions.unwrap_or_default(),
+        |validator, action| check_action(validator, action, trigger_type),
+    );
+    if let Some(roles) = exempt_roles {
+        validator.count("exempt_roles", roles.len(), 0, MAX_RULE_EXEMPT_ROLES);
+    }
+    if let Some(channels) = exempt_channels {
+        validator.count(
+            "exempt_channels",
+            channels.len(),
+            0,
+            MAX_RULE_EXEMPT_CHANNELS,
+        );
+    }
+}
+
+fn check_trigger_metadata(
+    validator: &mut Validator,
+    metadata: &AutoModerationTriggerMetadataApiType,
+    trigger_type: Option<AutoModerationTriggerType>,
+) {
+    use AutoModerationTriggerType::*;
+
+    if let Some(trigger_type) = trigger_type {
+        let fields: [(&str, bool, &[AutoModerationTriggerType]); 6] = [
+            (
+                "keyword_filter",
+                metadata.keyword_filter.is_some(),
+                &[KEYWORD, MEMBER_PROFILE],
+            ),
+            (
+                "regex_patterns",
+                metadata.regex_patterns.is_some(),
+                &[KEYWORD, MEMBER_PROFILE],
+            ),
+            ("presets", metadata.presets.is_some(), &[KEYWORD_PRESET]),
+            (
+                "allow_list",
+                metadata.allow_list.is_some(),
+                &[KEYWORD, KEYWORD_PRESET, MEMBER_PROFILE],
+            ),
+            (
+                "mention_total_limit",
+                metadata.mention_total_limit.is_some(),
+                &[MENTION_SPAM],
+            ),
+            (
+                "mention_raid_protection_enabled",
+                metadata.mention_raid_protection_enabled.is_some(),
+                &[MENTION_SPAM],
+            ),
+        ];
+        for (field, is_set, supported_by) in fields {
+            if is_set && !supported_by.contains(&trigger_type) {
+                validator.error(field, ValidationErrorKind::NotAllowed);
+            }
+        }
+    }
+
+    // without the trigger type only the larger allow list limit is known to apply
+    let max_allow_list = match trigger_type {
+        Some(KEYWORD_PRESET) | None => MAX_RULE_PRESET_ALLOW_LIST,
+        Some(_) => MAX_RULE_ALLOW_LIST,
+    };
+    check_strings(
+        validator,
+        "keyword_filter",
+        metadata.keyword_filter.as_deref(),
+        MAX_RULE_KEYWORDS,
+        RULE_KEYWORD_LENGTH,
+    );
+    check_strings(
+        validator,
+        "regex_patterns",
+        metadata.regex_patterns.as_deref(),
+        MAX_RULE_REGEX_PATTERNS,
+        RULE_REGEX_PATTERN_LENGTH,
+    );
+    check_strings(
+        validator,
+        "allow_list",
+        metadata.allow_list.as_deref(),
+        max_allow_list,
+        RULE_KEYWORD_LENGTH,
+    );
+    validator.range(
+        "mention_total_limit",
+        metadata.mention_total_limit.map(u64::from),
+        0,
+        MAX_RULE_MENTION_TOTAL_LIMIT,
+    );
+}
+
+fn check_strings(
+    validator: &mut Validator,
+    name: &str,
+    values: Option<&[String]>,
+    max_entries: usize,
+    max_length: usize,
+) {
+    let Some(values) = values else {
+        return;
+    };
+    validator.count(name, values.len(), 0, max_entries);
+    validator.each(name, values, |validator, value| {
+        validator.length("", value, 0, max_length)
+    });
+}
+
+fn check_action(
+    validator: &mut Validator,
+    action: &AutoModerationActionApiType,
+    trigger_type: Option<AutoModerationTriggerType>,
+) {
+    use AutoModerationActionType::*;
+    use AutoModerationTriggerType::*;
+
+    if let Some(trigger_type) = trigger_type {
+        let supported = match action.type_ {
+            BLOCK_MESSAGE => trigger_type != MEMBER_PROFILE,
+            SEND_ALERT_MESSAGE => true,
+            TIMEOUT => matches!(trigger_type, KEYWORD | MENTION_SPAM),
+            BLOCK_MEMBER_INTERACTION => trigger_type == MEMBER_PROFILE,
+            // availability of unknown actions can not be checked here
+            AutoModerationActionType::Unknown(_) => true,
+        };
+        if !supported {
+            validator.error("type", UNSUPPORTED_ACTION);
+        }
+    }
+
+    let metadata = action.metadata.clone().unwrap_or_default();
+    match action.type_ {
+        SEND_ALERT_MESSAGE if metadata.channel_id.is_none() => {
+            validator.error("metadata.channel_id", ValidationErrorKind::Missing)
+        }
+        TIMEOUT => match metadata.duration_seconds {
+            Some(duration) => validator.range(
+                "metadata.duration_seconds",
+                Some(u64::from(duration)),
+                0,
+                MAX_ACTION_TIMEOUT_SECONDS,
+            ),
+            None => validator.error("metadata.duration_seconds", ValidationErrorKind::Missing),
+        },
+        BLOCK_MESSAGE => validator.max_length(
+            "metadata.custom_message",
+            metadata.custom_message.as_deref(),
+            ACTION_CUSTOM_MESSAGE_LENGTH,
+        ),
+        _ => {}
+    }
+}
@@ -0,0 +1,41 @@
+use crate::channel::{ChannelTypeApiType, CreateChannelApiType};
+
+use super::{Validate, ValidationErrors, Validator};
+
+/// Maximum number of characters of the name of a channel
+pub const CHANNEL_NAME_LENGTH: usize = 100;
+/// Maximum number of characters of the topic of a channel
+pub const CHANNEL_TOPIC_LENGTH: usize = 1024;
+/// Maximum number of characters of the topic of a forum or media channel
+pub const FORUM_CHANNEL_TOPIC_LENGTH: usize = 4096;
+/// Maximum number of seconds of the slowmode of a channel
+pub const MAX_RATE_LIMIT_PER_USER: u64 = 21_600;
+
+/// <https://discord.com/developers/docs/resources/guild#create-guild-channel-json-params>
+impl Validate for CreateChannelApiType {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Validator::run(|validator| {
+            validator.length("name", &self.name, 1, CHANNEL_NAME_LENGTH);
+
+            let max_topic = match self.type_ {
+                Some(ChannelTypeApiType::GUILD_FORUM | ChannelTypeApiType::GUILD_MEDIA) => {
+                    FORUM_CHANNEL_TOPIC_LENGTH
+                }
+                _ => CHANNEL_TOPIC_LENGTH,
+            };
+            validator.max_length("topic", self.topic.as_deref(), max_topic);
+            validator.range(
+                "rate_limit_per_user",
+                self.rate_limit_per_user,
+                0,
+                MAX_RATE_LIMIT_PER_USER,
+            );
+            validator.range(
+                "default_thread_rate_limit_per_user",
+                self.default_thread_rate_limit_per_user,
+                0,
+                MAX_RATE_LIMIT_PER_USER,
+            );
+        })
+    }
+}
@@ -0,0 +1,202 @@
+use serde_json::Value;
+
+use crate::interactions::{
+    ApplicationCommandApiType, ApplicationCommandOptionApiType,
+    ApplicationCommandOptionChoiceApiType, ApplicationCommandOptionType, ApplicationCommandType,
+};
+use crate::locale::{Locale, Localizations};
+
+use super::{Validate, ValidationErrorKind, ValidationErrors, Validator};
+
+/// Maximum number of characters in the name of a command or option
+pub const COMMAND_NAME_LENGTH: usize = 32;
+/// Maximum number of characters in the description of a command or option
+pub const COMMAND_DESCRIPTION_LENGTH: usize = 100;
+/// Maximum number of options of a command or subcommand
+pub const MAX_COMMAND_OPTIONS: usize = 25;
+/// Maximum number of choices of an option
+pub const MAX_OPTION_CHOICES: usize = 25;
+/// Maximum number of characters in the name and string value of a choice
+pub const CHOICE_LENGTH: usize = 100;
+/// Upper bound of the min and max length of string options
+pub const STRING_OPTION_LENGTH: usize = 6000;
+
+const INVALID_NAME: ValidationErrorKind = ValidationErrorKind::Invalid(
+    "names must be lowercase and only contain letters, numbers, `-` and `_`",
+);
+
+/// <https://discord.com/developers/docs/interactions/application-commands#application-command-object>
+impl Validate for ApplicationCommandApiType {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Validator::run(|validator| {
+            let context_menu = matches!(
+                self.type_,
+                Some(ApplicationCommandType::USER | ApplicationCommandType::MESSAGE)
+            );
+
+            if context_menu {
+                // context menu commands may use any casing and spaces in their names
+                validator.length("name", &self.name, 1, COMMAND_NAME_LENGTH);
+                if !self.description.is_empty() {
+                    validator.error("description", ValidationErrorKind::NotAllowed);
+                }
+                if self.options.is_some() {
+                    validator.error("options", ValidationErrorKind::NotAllowed);
+                }
+            } else {
+                check_name(validator, "name", &self.name);
+                validator.length(
+                    "description",
+                    &self.description,
+                    1,
+                    COMMAND_DESCRIPTION_LENGTH,
+                );
+                check_localized_names(validator, "name_localizations", &self.name_localizations);
+                if let Some(options) = &self.options {
+                    check_options(validator, options);
+                }
+            }
+
+            check_localized_descriptions(
+                validator,
+                "description_localizations",
+                &self.description_localizations,
+            );
+        })
+    }
+}
+
+/// Whether a character is allowed in the name of a chat input command.
+///
+/// <https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-naming>
+fn is_name_character(c: char) -> bool {
+    let allowed = c == '-'
+        || c == '_'
+        || c.is_alphanumeric()
+        // Devanagari and Thai are allowed as a whole, including their combining marks
+        || ('\u{0900}'..='\u{097F}').contains(&c)
+        || ('\u{0E00}'..='\u{0E7F}').contains(&c);
+
+    allowed && !c.is_uppercase()
+}
+
+fn check_name(validator: &mut Validator, field: &str, name: &str) {
+    validator.length(field, name, 1, COMMAND_NAME_LENGTH);
+    if !name.chars().all(is_name_character) {
+        validator.error(field, INVALID_NAME);
+    }
+}
+
+fn check_localized_names(
+    validator: &mut Validator,
+    field: &str,
+    localizations: &Option<Localizations>,
+) {
+    if let Some(localizations) = localizations {
+        validator.field(field, |validator| {
+            for (locale, name) in sorted(localizations) {
+                check_name(validator, locale.as_str(), name);
+            }
+        });
+    }
+}
+
+fn check_localized_descriptions(
+    validator: &mut Validator,
+    field: &str,
+    localizations: &Option<Localizations>,
+) {
+    if let Some(localizations) = localizations {
+        validator.field(field, |validator| {
+            for (locale, description) in sorted(localizations) {
+                validator.length(locale.as_str(), description, 1, COMMAND_DESCRIPTION_LENGTH);
+            }
+        });
+    }
+}
+
+/// Localizations in a stable order, so errors are reported deterministically.
+fn sorted(localizations: &Localizations) -> Vec<(&Locale, &String)> {
+    let mut localizations: Vec<_> = localizations.iter().collect();
+    localizations.sort_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+    localizations
+}
+
+fn check_options(validator: &mut Validator, options: &[ApplicationCommandOptionApiType]) {
+    validator.count("options", options.len(), 0, MAX_COMMAND_OPTIONS);
+
+    let mut optional_seen = false;
+    validator.each("options", options, |validator, option| {
+        let required = option.required.unwrap_or(false);
+        if required && optional_seen {
+            validator.error(
+                "required",
+                ValidationErrorKind::Invalid(
+                    "required options must be listed before optional ones",
+                ),
+            );
+        }
+        optional_seen |= !required;
+
+        check_option(validator, option);
+    });
+}
+
+fn check_option(validator: &mut Validator, option: &ApplicationCommandOptionApiType) {
+    let max_length = STRING_OPTION_LENGTH as u64;
+
+    check_name(validator, "name", &option.name);
+    validator.length(
+        "description",
+        &option.description,
+        1,
+        COMMAND_DESCRIPTION_LENGTH,
+    );
+    check_localized_names(validator, "name_localizations", &option.name_localizations);
+    check_localized_descriptions(
+        validator,
+        "description_localizations",
+        &option.description_localizations,
+    );
+    validator.range(
+        "min_length",
+        option.min_length.map(u64::from),
+        0,
+        max_length,
+    );
+    validator.range(
+        "max_length",
+        option.max_length.map(u64::from),
+        1,
+        max_length,
+    );
+
+    if let Some(choices) = &option.choices {
+        check_choices(validator, choices);
+        if option.autocomplete == Some(true) {
+            validator.error("autocomplete", ValidationErrorKind::NotAllowed);
+        }
+    }
+
+    if let Some(options) = &option.options {
+        match option.type_ {
+            ApplicationCommandOptionType::SUB_COMMAND
+            | ApplicationCommandOptionType::SUB_COMMAND_GROUP => check_options(validator, options),
+            _ => validator.error("options", ValidationErrorKind::NotAllowed),
+        }
+    }
+}
+
+/// Check the choices of an option or of an autocomplete response.
+pub(crate) fn check_choices(
+    validator: &mut Validator,
+    choices: &[ApplicationCommandOptionChoiceApiType],
+) {
+    validator.count("choices", choices.len(), 0, MAX_OPTION_CHOICES);
+    validator.each("choices", choices, |validator, choice| {
+        validator.length("name", &choice.name, 1, CHOICE_LENGTH);
+        if let Value::String(value) = &choice.value {
+            validator.length("value", value, 1, CHOICE_LENGTH);
+        }
+    });
+}
@@ -0,0 +1,174 @@
+use crate::channel::{ComponentApiType, MessageComponentApiType};
+
+use super::{Validate, ValidationErrorKind, ValidationErrors, Validator};
+
+/// Maximum number of action rows of a message
+pub const MAX_ACTION_ROWS: usize = 5;
+/// Maximum number of components in an action row
+pub const MAX_ACTION_ROW_COMPONENTS: usize = 5;
+/// Maximum number of characters in a custom id
+pub const CUSTOM_ID_LENGTH: usize = 100;
+/// Maximum number of characters in the label of a button
+pub const BUTTON_LABEL_LENGTH: usize = 80;
+/// Maximum number of options of a select menu, also the limit for min and max values
+pub const MAX_SELECT_OPTIONS: usize = 25;
+/// Maximum number of characters in the placeholder of a select menu
+pub const SELECT_PLACEHOLDER_LENGTH: usize = 150;
+/// Maximum number of characters in the label, value and description of a select option
+pub const SELECT_OPTION_LENGTH: usize = 100;
+/// Maximum number of characters in the label of a text input
+pub const TEXT_INPUT_LABEL_LENGTH: usize = 45;
+/// Maximum number of characters in the placeholder of a text input
+pub const TEXT_INPUT_PLACEHOLDER_LENGTH: usize = 100;
+/// Maximum number of characters of the value of a text input
+pub const TEXT_INPUT_LENGTH: usize = 4000;
+
+/// Style of buttons that link to a URL
+const LINK_BUTTON_STYLE: u8 = 5;
+/// Style of buttons that let users purchase a SKU
+const PREMIUM_BUTTON_STYLE: u8 = 6;
+
+/// <https://discord.com/developers/docs/interactions/message-components>
+impl Validate for ComponentApiType {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Validator::run(|validator| check_component(validator, self))
+    }
+}
+
+fn is_select_menu(type_: MessageComponentApiType) -> bool {
+    matches!(
+        type_,
+        MessageComponentApiType::SelectMenu
+            | MessageComponentApiType::UserSelect
+            | MessageComponentApiType::RoleSelect
+            | MessageComponentApiType::MentionableSelect
+            | MessageComponentApiType::ChannelSelect
+    )
+}
+
+/// Check the top-level components of a message.
+pub(crate) fn check_message_components(validator: &mut Validator, components: &[ComponentApiType]) {
+    validator.count("components", components.len(), 0, MAX_ACTION_ROWS);
+    validator.each("components", components, |validator, component| {
+        match component.type_ {
+            MessageComponentApiType::ActionRow | MessageComponentApiType::Unknown(_) => {}
+            _ => validator.error(
+                "type",
+                ValidationErrorKind::Invalid("top-level components must be action rows"),
+            ),
+        }
+        check_component(validator, component);
+    });
+}
+
+pub(crate) fn check_component(validator: &mut Validator, component: &ComponentApiType) {
+    match component.type_ {
+        MessageComponentApiType::ActionRow => check_action_row(validator, component),
+        MessageComponentApiType::Button => check_button(validator, component),
+        MessageComponentApiType::TextInput => check_text_input(validator, component),
+        type_ if is_select_menu(type_) => check_select_menu(validator, component),
+        _ => {}
+    }
+}
+
+fn check_custom_id(validator: &mut Validator, component: &ComponentApiType) {
+    match &component.custom_id {
+        Some(custom_id) => validator.length("custom_id", custom_id, 1, CUSTOM_ID_LENGTH),
+        None => validator.error("custom_id", ValidationErrorKind::Missing),
+    }
+}
+
+fn check_action_row(validator: &mut Validator, row: &ComponentApiType) {
+    let Some(components) = &row.components else {
+        validator.error("components", ValidationErrorKind::Missing);
+        return;
+    };
+
+    validator.count("components", components.len(), 1, MAX_ACTION_ROW_COMPONENTS);
+    if components.len() > 1 && components.iter().any(|c| is_select_menu(c.type_)) {
+        validator.error(
+            "components",
+            ValidationErrorKind::Invalid("a select menu must be the only component of its row"),
+        );
+    }
+    validator.each("components", components, |validator, component| {
+        if component.type_ == MessageComponentApiType::ActionRow {
+            validator.error(
+                "type",
+                ValidationErrorKind::Invalid("action rows can not be nested"),
+            );
+        } else {
+            check_component(validator, component);
+        }
+    });
+}
+
+fn check_button(validator: &mut Validator, button: &ComponentApiType) {
+    validator.max_length("label", button.label.as_deref(), BUTTON_LABEL_LENGTH);
+
+    match button.style {
+        Some(LINK_BUTTON_STYLE) => {
+            if button.url.is_none() {
+                validator.error("url", ValidationErrorKind::Missing);
+            }
+            if button.custom_id.is_some() {
+                validator.error("custom_id", ValidationErrorKind::NotAllowed);
+            }
+        }
+        Some(PREMIUM_BUTTON_STYLE) => {
+            if button.sku_id.is_none() {
+                validator.error("sku_id", ValidationErrorKind::Missing);
+            }
+        }
+        _ => check_custom_id(validator, button),
+    }
+}
+
+fn check_select_menu(validator: &mut Validator, menu: &ComponentApiType) {
+    let max = MAX_SELECT_OPTIONS as u64;
+
+    check_custom_id(validator, menu);
+    validator.max_length(
+        "placeholder",
+        menu.placeholder.as_deref(),
+        SELECT_PLACEHOLDER_LENGTH,
+    );
+    validator.range("min_values", menu.min_values.map(u64::from), 0, max);
+    validator.range("max_values", menu.max_values.map(u64::from), 1, max);
+
+    if menu.type_ != MessageComponentApiType::SelectMenu {
+        return;
+    }
+    let Some(options) = &menu.options else {
+        validator.error("options", ValidationErrorKind::Missing);
+        return;
+    };
+    validator.count("options", options.len(), 1, MAX_SELECT_OPTIONS);
+    validator.each("options", options, |validator, option| {
+        validator.length("label", &option.label, 1, SELECT_OPTION_LENGTH);
+        validator.length("value", &option.value, 1, SELECT_OPTION_LENGTH);
+        validator.max_length(
+            "description",
+            option.description.as_deref(),
+            SELECT_OPTION_LENGTH,
+        );
+    });
+}
+
+fn check_text_input(validator: &mut Validator, input: &ComponentApiType) {
+    let max = TEXT_INPUT_LENGTH as u64;
+
+    check_custom_id(validator, input);
+    match &input.label {
+        Some(label) => validator.length("label", label, 1, TEXT_INPUT_LABEL_LENGTH),
+        None => validator.error("label", ValidationErrorKind::Missing),
+    }
+    validator.max_length(
+        "placeholder",
+        input.placeholder.as_deref(),
+        TEXT_INPUT_PLACEHOLDER_LENGTH,
+    );
+    validator.max_length("value", input.value.as_deref(), TEXT_INPUT_LENGTH);
+    validator.range("min_length", input.min_length.map(u64::from), 0, max);
+    validator.range("max_length", input.max_length.map(u64::from), 1, max);
+}
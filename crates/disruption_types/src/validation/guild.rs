@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use crate::entities::{
+    BulkGuildBanApiType, CreateGuildBanApiType, CreateRoleApiType, ModifyGuildMemberApiType,
+};
+use crate::timestamp::Timestamp;
+
+use super::{Validate, ValidationErrorKind, ValidationErrors, Validator};
+
+/// Maximum number of characters of the nickname of a member
+pub const NICK_LENGTH: usize = 32;
+/// Maximum number of days a member can be timed out for
+pub const MAX_TIMEOUT_DAYS: u64 = 28;
+/// Maximum number of seconds of messages that are deleted with a ban
+pub const MAX_DELETE_MESSAGE_SECONDS: u64 = 604_800;
+/// Maximum number of users of a bulk ban
+pub const MAX_BULK_BAN_USERS: usize = 200;
+/// Maximum number of characters of the name of a role
+pub const ROLE_NAME_LENGTH: usize = 100;
+
+/// <https://discord.com/developers/docs/resources/guild#modify-guild-member-json-params>
+impl Validate for ModifyGuildMemberApiType {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Validator::run(|validator| {
+            validator.max_length("nick", self.nick.value().map(String::as_str), NICK_LENGTH);
+
+            let max_timeout = Duration::from_secs(MAX_TIMEOUT_DAYS * 24 * 60 * 60);
+            if let Some(until) = self.communication_disabled_until.value() {
+                if *until > Timestamp::now() + max_timeout {
+                    validator.error(
+                        "communication_disabled_until",
+                        ValidationErrorKind::Invalid("timeouts can last at most 28 days"),
+                    );
+                }
+            }
+        })
+    }
+}
+
+/// <https://discord.com/developers/docs/resources/guild#create-guild-ban-json-params>
+impl Validate for CreateGuildBanApiType {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Validator::run(|validator| {
+            validator.range(
+                "delete_message_seconds",
+                self.delete_message_seconds.map(u64::from),
+                0,
+                MAX_DELETE_MESSAGE_SECONDS,
+            );
+        })
+    }
+}
+
+/// <https://discord.com/developers/docs/resources/guild#bulk-guild-ban-json-params>
+impl Validate for BulkGuildBanApiType {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Validator::run(|validator| {
+            validator.count("user_ids", self.user_ids.len(), 1, MAX_BULK_BAN_USERS);
+            validator.range(
+                "delete_message_seconds",
+                self.delete_message_seconds.map(u64::from),
+                0,
+                MAX_DELETE_MESSAGE_SECONDS,
+            );
+        })
+    }
+}
+
+/// <https://discord.com/developers/docs/resources/guild#create-guild-role-json-params>
+impl Validate for CreateRoleApiType {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Validator::run(|validator| {
+            validator.max_length("name", self.name.as_deref(), ROLE_NAME_LENGTH);
+        })
+    }
+}
@@ -0,0 +1,69 @@
+use crate::interactions::{
+    InteractionCallbackDataApiType, InteractionCallbackMessageApiType,
+    InteractionCallbackModalApiType, InteractionResponseApiType,
+};
+
+use super::{
+    command::check_choices,
+    component::{check_component, check_message_components, CUSTOM_ID_LENGTH},
+    message::{check_embeds, MESSAGE_CONTENT_LENGTH},
+    poll::check_poll_request,
+    Validate, ValidationErrors, Validator,
+};
+
+/// Maximum number of characters of the title of a modal
+pub const MODAL_TITLE_LENGTH: usize = 45;
+/// Maximum number of components of a modal
+pub const MAX_MODAL_COMPONENTS: usize = 5;
+
+/// <https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object>
+impl Validate for InteractionResponseApiType {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Validator::run(|validator| {
+            let Some(data) = self.data() else {
+                return;
+            };
+            validator.field("data", |validator| match data {
+                InteractionCallbackDataApiType::Message(message) => {
+                    check_callback_message(validator, message)
+                }
+                InteractionCallbackDataApiType::Autocomplete(autocomplete) => {
+                    check_choices(validator, &autocomplete.choices)
+                }
+                InteractionCallbackDataApiType::Modal(modal) => check_modal(validator, modal),
+                InteractionCallbackDataApiType::Unknown(_) => {}
+            });
+        })
+    }
+}
+
+fn check_callback_message(validator: &mut Validator, message: &InteractionCallbackMessageApiType) {
+    validator.max_length(
+        "content",
+        message.content.as_deref(),
+        MESSAGE_CONTENT_LENGTH,
+    );
+    if let Some(embeds) = &message.embeds {
+        check_embeds(validator, embeds);
+    }
+    if let Some(components) = &message.components {
+        check_message_components(validator, components);
+    }
+    if let Some(poll) = &message.poll {
+        validator.field("poll", |validator| check_poll_request(validator, poll));
+    }
+}
+
+fn check_modal(validator: &mut Validator, modal: &InteractionCallbackModalApiType) {
+    validator.length("custom_id", &modal.custom_id, 1, CUSTOM_ID_LENGTH);
+    validator.length("title", &modal.title, 1, MODAL_TITLE_LENGTH);
+    validator.count(
+        "components",
+        modal.components.len(),
+        1,
+        MAX_MODAL_COMPONENTS,
+    );
+    validator.each("components", &modal.components, |validator, component| {
+        check_component(validator, component)
+    });
+}
@@ -0,0 +1,194 @@
+use crate::channel::{
+    BulkDeleteMessagesApiType, CreateMessageApiType, EditMessageApiType, EmbedApiType,
+    MessageApiType,
+};
+
+use super::{
+    component::check_message_components,
+    poll::{check_poll, check_poll_request},
+    Validate, ValidationErrorKind, ValidationErrors, Validator,
+};
+
+/// Maximum number of characters in the content of a message
+pub const MESSAGE_CONTENT_LENGTH: usize = 2000;
+/// Maximum number of stickers of a message
+pub const MAX_STICKERS: usize = 3;
+/// Minimum number of messages of a bulk delete
+pub const MIN_BULK_DELETE_MESSAGES: usize = 2;
+/// Maximum number of messages of a bulk delete
+pub const MAX_BULK_DELETE_MESSAGES: usize = 100;
+/// Maximum age in days of messages that can be bulk deleted
+pub const BULK_DELETE_MAX_AGE_DAYS: u64 = 14;
+/// Maximum number of embeds of a message
+pub const MAX_EMBEDS: usize = 10;
+/// Maximum number of characters of all embeds of a message combined
+pub const EMBED_TOTAL_LENGTH: usize = 6000;
+/// Maximum number of characters in the title of an embed
+pub const EMBED_TITLE_LENGTH: usize = 256;
+/// Maximum number of characters in the description of an embed
+pub const EMBED_DESCRIPTION_LENGTH: usize = 4096;
+/// Maximum number of fields of an embed
+pub const MAX_EMBED_FIELDS: usize = 25;
+/// Maximum number of characters in the name of an embed field
+pub const EMBED_FIELD_NAME_LENGTH: usize = 256;
+/// Maximum number of characters in the value of an embed field
+pub const EMBED_FIELD_VALUE_LENGTH: usize = 1024;
+/// Maximum number of characters in the footer text of an embed
+pub const EMBED_FOOTER_TEXT_LENGTH: usize = 2048;
+/// Maximum number of characters in the author name of an embed
+pub const EMBED_AUTHOR_NAME_LENGTH: usize = 256;
+
+/// <https://discord.com/developers/docs/resources/message#create-message-jsonform-params>
+impl Validate for MessageApiType {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Validator::run(|validator| {
+            validator.length("content", &self.content, 0, MESSAGE_CONTENT_LENGTH);
+            check_embeds(validator, &self.embeds);
+            if let Some(components) = &self.components {
+                check_message_components(validator, components);
+            }
+            if let Some(poll) = &self.poll {
+                validator.field("poll", |validator| check_poll(validator, poll));
+            }
+        })
+    }
+}
+
+/// <https://discord.com/developers/docs/resources/message#create-message-jsonform-params>
+impl Validate for CreateMessageApiType {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Validator::run(|validator| {
+            validator.max_length("content", self.content.as_deref(), MESSAGE_CONTENT_LENGTH);
+            if let Some(embeds) = &self.embeds {
+                check_embeds(validator, embeds);
+            }
+            if let Some(components) = &self.components {
+                check_message_components(validator, components);
+            }
+            if let Some(sticker_ids) = &self.sticker_ids {
+                validator.count("sticker_ids", sticker_ids.len(), 0, MAX_STICKERS);
+            }
+            if let Some(poll) = &self.poll {
+                validator.field("poll", |validator| check_poll_request(validator, poll));
+            }
+
+            let empty = self.content.as_deref().unwrap_or_default().is_empty()
+                && self.embeds.as_ref().is_none_or(Vec::is_empty)
+                && self.components.as_ref().is_none_or(Vec::is_empty)
+                && self.sticker_ids.as_ref().is_none_or(Vec::is_empty)
+                && self.attachments.as_ref().is_none_or(Vec::is_empty)
+                && self.poll.is_none();
+            if empty {
+                validator.error(
+                    "",
+                    ValidationErrorKind::Invalid(
+                        "one of content, embeds, components, sticker_ids, attachments or poll is required",
+                    ),
+                );
+            }
+        })
+    }
+}
+
+/// <https://discord.com/developers/docs/resources/message#edit-message-jsonform-params>
+impl Validate for EditMessageApiType {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Validator::run(|validator| {
+            validator.max_length(
+                "content",
+                self.content.value().map(String::as_str),
+                MESSAGE_CONTENT_LENGTH,
+            );
+            if let Some(embeds) = self.embeds.value() {
+                check_embeds(validator, embeds);
+            }
+            if let Some(components) = self.components.value() {
+                check_message_components(validator, components);
+            }
+        })
+    }
+}
+
+/// <https://discord.com/developers/docs/resources/message#bulk-delete-messages>
+impl Validate for BulkDeleteMessagesApiType {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Validator::run(|validator| {
+            validator.count(
+                "messages",
+                self.messages.len(),
+                MIN_BULK_DELETE_MESSAGES,
+                MAX_BULK_DELETE_MESSAGES,
+            );
+        })
+    }
+}
+
+/// <https://discord.com/developers/docs/resources/message#embed-object-embed-limits>
+impl Validate for EmbedApiType {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Validator::run(|validator| check_embed(validator, self))
+    }
+}
+
+/// Check the embeds of a message, including their combined length.
+pub(crate) fn check_embeds(validator: &mut Validator, embeds: &[EmbedApiType]) {
+    validator.count("embeds", embeds.len(), 0, MAX_EMBEDS);
+    validator.each("embeds", embeds, check_embed);
+
+    let length = embeds.iter().map(embed_length).sum();
+    if embeds.len() > 1 && length > EMBED_TOTAL_LENGTH {
+        validator.error(
+            "embeds",
+            ValidationErrorKind::TooLong {
+                max: EMBED_TOTAL_LENGTH,
+                length,
+            },
+        );
+    }
+}
+
+pub(crate) fn check_embed(validator: &mut Validator, embed: &EmbedApiType) {
+    validator.max_length("title", embed.title.as_deref(), EMBED_TITLE_LENGTH);
+    validator.max_length(
+        "description",
+        embed.description.as_deref(),
+        EMBED_DESCRIPTION_LENGTH,
+    );
+    if let Some(footer) = &embed.footer {
+        validator.length("footer.text", &footer.text, 0, EMBED_FOOTER_TEXT_LENGTH);
+    }
+    if let Some(author) = &embed.author {
+        validator.length("author.name", &author.name, 0, EMBED_AUTHOR_NAME_LENGTH);
+    }
+    if let Some(fields) = &embed.fields {
+        validator.count("fields", fields.len(), 0, MAX_EMBED_FIELDS);
+        validator.each("fields", fields, |validator, field| {
+            validator.length("name", &field.name, 1, EMBED_FIELD_NAME_LENGTH);
+            validator.length("value", &field.value, 1, EMBED_FIELD_VALUE_LENGTH);
+        });
+    }
+
+    let length = embed_length(embed);
+    if length > EMBED_TOTAL_LENGTH {
+        validator.error(
+            "",
+            ValidationErrorKind::TooLong {
+                max: EMBED_TOTAL_LENGTH,
+                length,
+            },
+        );
+    }
+}
+
+/// Number of characters that count towards the total limit of embeds.
+fn embed_length(embed: &EmbedApiType) -> usize {
+    let count = |text: Option<&str>| text.map_or(0, |text| text.chars().count());
+
+    count(embed.title.as_deref())
+        + count(embed.description.as_deref())
+        + count(embed.footer.as_ref().map(|footer| footer.text.as_str()))
+        + count(embed.author.as_ref().map(|author| author.name.as_str()))
+        + embed.fields.iter().flatten().fold(0, |length, field| {
+            length + count(Some(&field.name)) + count(Some(&field.value))
+        })
+}
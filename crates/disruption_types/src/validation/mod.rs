@@ -0,0 +1,217 @@
+//! Client side validation of the limits Discord enforces on payloads.
+//!
+//! Discord rejects payloads that exceed its limits with a generic `400 Bad Request`. Validating them
+//! beforehand reports every violation together with the path of the offending field.
+
+mod audit_log;
+mod auto_moderation;
+mod channel;
+mod command;
+mod component;
+mod guild;
+mod interaction;
+mod message;
+mod poll;
+
+pub use self::audit_log::*;
+pub use self::auto_moderation::*;
+pub use self::channel::*;
+pub use self::command::*;
+pub use self::component::*;
+pub use self::guild::*;
+pub use self::interaction::*;
+pub use self::message::*;
+pub use self::poll::*;
+
+use std::fmt::Display;
+
+/// Types that can be checked against the limits of the Discord API
+pub trait Validate {
+    /// Check all limits, returning every violation that was found.
+    fn validate(&self) -> Result<(), ValidationErrors>;
+}
+
+/// The limit a field violates
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationErrorKind {
+    /// the text has more than `max` characters
+    TooLong { max: usize, length: usize },
+    /// the text has less than `min` characters
+    TooShort { min: usize, length: usize },
+    /// the list has more than `max` entries
+    TooMany { max: usize, count: usize },
+    /// the list has less than `min` entries
+    TooFew { min: usize, count: usize },
+    /// the number is outside of the allowed range
+    OutOfRange { min: u64, max: u64, value: u64 },
+    /// a field that is required in this context is missing
+    Missing,
+    /// a field that is not allowed in this context is set
+    NotAllowed,
+    /// the value does not have the expected form
+    Invalid(&'static str),
+}
+
+impl Display for ValidationErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationErrorKind::TooLong { max, length } => {
+                write!(f, "{length} characters exceed the limit of {max}")
+            }
+            ValidationErrorKind::TooShort { min, length } => {
+                write!(f, "{length} characters are below the minimum of {min}")
+            }
+            ValidationErrorKind::TooMany { max, count } => {
+                write!(f, "{count} entries exceed the limit of {max}")
+            }
+            ValidationErrorKind::TooFew { min, count } => {
+                write!(f, "{count} entries are below the minimum of {min}")
+            }
+            ValidationErrorKind::OutOfRange { min, max, value } => {
+                write!(f, "{value} is not between {min} and {max}")
+            }
+            ValidationErrorKind::Missing => write!(f, "required field is missing"),
+            ValidationErrorKind::NotAllowed => write!(f, "field is not allowed here"),
+            ValidationErrorKind::Invalid(reason) => write!(f, "{reason}"),
+        }
+    }
+}
+
+/// A single violated limit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// path of the offending field, e.g. `embeds[0].fields[3].name`
+    pub path: String,
+    /// the violated limit
+    pub kind: ValidationErrorKind,
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            // limits of the payload as a whole
+            return write!(f, "{}", self.kind);
+        }
+        write!(f, "{}: {}", self.path, self.kind)
+    }
+}
+
+/// All limits violated by a payload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationErrors(pub Vec<ValidationError>);
+
+impl ValidationErrors {
+    /// The violated limits.
+    pub fn errors(&self) -> &[ValidationError] {
+        &self.0
+    }
+
+    /// The violation of the field at the given path, if any.
+    pub fn get(&self, path: &str) -> Option<&ValidationErrorKind> {
+        self.0
+            .iter()
+            .find(|error| error.path == path)
+            .map(|error| &error.kind)
+    }
+}
+
+impl Display for ValidationErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, error) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+/// Collects violations while walking a payload
+#[derive(Default)]
+pub(crate) struct Validator {
+    path: Vec<String>,
+    errors: Vec<ValidationError>,
+}
+
+impl Validator {
+    /// Run the checks of a value and collect their result.
+    pub(crate) fn run(check: impl FnOnce(&mut Validator)) -> Result<(), ValidationErrors> {
+        let mut validator = Validator::default();
+        check(&mut validator);
+        match validator.errors.is_empty() {
+            true => Ok(()),
+            false => Err(ValidationErrors(validator.errors)),
+        }
+    }
+
+    /// Run checks for a nested field.
+    pub(crate) fn field(&mut self, name: &str, check: impl FnOnce(&mut Validator)) {
+        self.path.push(name.to_owned());
+        check(self);
+        self.path.pop();
+    }
+
+    /// Run checks for every entry of a list field.
+    pub(crate) fn each<T>(
+        &mut self,
+        name: &str,
+        items: &[T],
+        mut check: impl FnMut(&mut Validator, &T),
+    ) {
+        for (index, item) in items.iter().enumerate() {
+            self.field(&format!("{name}[{index}]"), |validator| {
+                check(validator, item)
+            });
+        }
+    }
+
+    /// Record a violation of the given field.
+    pub(crate) fn error(&mut self, name: &str, kind: ValidationErrorKind) {
+        let mut path = self.path.join(".");
+        if !name.is_empty() {
+            if !path.is_empty() {
+                path.push('.');
+            }
+            path.push_str(name);
+        }
+        self.errors.push(ValidationError { path, kind });
+    }
+
+    /// Check the number of characters of a text.
+    pub(crate) fn length(&mut self, name: &str, text: &str, min: usize, max: usize) {
+        let length = text.chars().count();
+        if length > max {
+            self.error(name, ValidationErrorKind::TooLong { max, length });
+        } else if length < min {
+            self.error(name, ValidationErrorKind::TooShort { min, length });
+        }
+    }
+
+    /// Check the number of characters of an optional text.
+    pub(crate) fn max_length(&mut self, name: &str, text: Option<&str>, max: usize) {
+        if let Some(text) = text {
+            self.length(name, text, 0, max);
+        }
+    }
+
+    /// Check the number of entries of a list.
+    pub(crate) fn count(&mut self, name: &str, count: usize, min: usize, max: usize) {
+        if count > max {
+            self.error(name, ValidationErrorKind::TooMany { max, count });
+        } else if count < min {
+            self.error(name, ValidationErrorKind::TooFew { min, count });
+        }
+    }
+
+    /// Check that an optional number is within a range.
+    pub(crate) fn range(&mut self, name: &str, value: Option<u64>, min: u64, max: u64) {
+        if let Some(value) = value {
+            if !(min..=max).contains(&value) {
+                self.error(name, ValidationErrorKind::OutOfRange { min, max, value });
+            }
+        }
+    }
+}
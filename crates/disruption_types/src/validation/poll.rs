@@ -0,0 +1,62 @@
+use crate::resources::{PollApiType, PollCreateRequestApiType, PollMediaApiType};
+
+use super::{Validate, ValidationErrorKind, ValidationErrors, Validator};
+
+/// Maximum number of characters in the question of a poll
+pub const POLL_QUESTION_LENGTH: usize = 300;
+/// Maximum number of characters in an answer of a poll
+pub const POLL_ANSWER_LENGTH: usize = 55;
+/// Maximum number of answers of a poll
+pub const MAX_POLL_ANSWERS: usize = 10;
+/// Maximum duration of a poll in hours (32 days)
+pub const MAX_POLL_DURATION_HOURS: u64 = 768;
+
+/// <https://discord.com/developers/docs/resources/poll#poll-object>
+impl Validate for PollApiType {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Validator::run(|validator| check_poll(validator, self))
+    }
+}
+
+/// <https://discord.com/developers/docs/resources/poll#poll-create-request-object>
+impl Validate for PollCreateRequestApiType {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        Validator::run(|validator| check_poll_request(validator, self))
+    }
+}
+
+pub(crate) fn check_poll(validator: &mut Validator, poll: &PollApiType) {
+    check_question(validator, &poll.question);
+    validator.count("answers", poll.answers.len(), 1, MAX_POLL_ANSWERS);
+    validator.each("answers", &poll.answers, |validator, answer| {
+        check_answer(validator, &answer.poll_media)
+    });
+}
+
+pub(crate) fn check_poll_request(validator: &mut Validator, poll: &PollCreateRequestApiType) {
+    check_question(validator, &poll.question);
+    validator.count("answers", poll.answers.len(), 1, MAX_POLL_ANSWERS);
+    validator.each("answers", &poll.answers, |validator, answer| {
+        check_answer(validator, &answer.poll_media)
+    });
+    validator.range(
+        "duration",
+        poll.duration.map(u64::from),
+        1,
+        MAX_POLL_DURATION_HOURS,
+    );
+}
+
+fn check_question(validator: &mut Validator, question: &PollMediaApiType) {
+    match &question.text {
+        Some(text) => validator.length("question.text", text, 1, POLL_QUESTION_LENGTH),
+        None => validator.error("question.text", ValidationErrorKind::Missing),
+    }
+}
+
+fn check_answer(validator: &mut Validator, media: &PollMediaApiType) {
+    validator.max_length("poll_media.text", media.text.as_deref(), POLL_ANSWER_LENGTH);
+    if media.text.is_none() && media.emoji.is_none() {
+        validator.error("poll_media", ValidationErrorKind::Missing);
+    }
+}
@@ -0,0 +1,187 @@
+/// Tests for Audit Log types serialization and deserialization
+mod common;
+
+use common::*;
+use disruption_types::channel::{ChannelFlags, ForumLayoutType};
+use disruption_types::entities::{ExplicitContentFilterLevel, RoleFlags, VerificationLevel};
+use disruption_types::resources::{
+    AuditLogApiType, AuditLogChange, AuditLogChangeFlagsValue, AuditLogChangePrivacyLevelValue,
+    AuditLogChangeTypeValue, AuditLogEvent, AuditLogPartialRoleApiType, AuditLogValueChange,
+    GuildScheduledEventPrivacyLevel, GuildScheduledEventStatus, StageInstancePrivacyLevel,
+};
+
+fn entry_changes(log: &AuditLogApiType, index: usize) -> &Vec<AuditLogChange> {
+    log.audit_log_entries[index].changes.as_ref().unwrap()
+}
+
+#[test]
+fn test_member_role_changes() {
+    let log: AuditLogApiType = load_fixture_as("audit_log", "member_entries.json");
+    assert_eq!(
+        log.audit_log_entries[0].action_type,
+        AuditLogEvent::MEMBER_ROLE_UPDATE
+    );
+
+    let changes = entry_changes(&log, 0);
+    let AuditLogChange::RoleAdd(added) = &changes[0] else {
+        panic!("expected $add change, got {:?}", changes[0]);
+    };
+    let roles: &Vec<AuditLogPartialRoleApiType> = added.new_value.as_ref().unwrap();
+    assert_eq!(roles.len(), 1);
+    assert_eq!(roles[0].id, "41771983423143936");
+    assert_eq!(roles[0].name, "Moderator");
+    assert!(added.old_value.is_none());
+    assert_eq!(changes[1].key(), "$remove");
+}
+
+#[test]
+fn test_member_update_changes() {
+    let log: AuditLogApiType = load_fixture_as("audit_log", "member_entries.json");
+    let changes = entry_changes(&log, 1);
+
+    assert!(matches!(
+        &changes[0],
+        AuditLogChange::Nick(AuditLogValueChange { old_value: Some(old), new_value: Some(new) })
+            if old == "Nelly" && new == "Nelly (mod)"
+    ));
+    assert!(matches!(
+        &changes[1],
+        AuditLogChange::CommunicationDisabledUntil(AuditLogValueChange {
+            old_value: None,
+            ..
+        })
+    ));
+    assert!(matches!(
+        &changes[2],
+        AuditLogChange::Mute(AuditLogValueChange {
+            old_value: Some(false),
+            new_value: Some(true)
+        })
+    ));
+}
+
+#[test]
+fn test_overwrite_changes_and_raw_fallback() {
+    let log: AuditLogApiType = load_fixture_as("audit_log", "member_entries.json");
+    let changes = entry_changes(&log, 2);
+
+    assert!(matches!(&changes[0], AuditLogChange::Allow(_)));
+    assert!(matches!(
+        &changes[1],
+        AuditLogChange::Type(AuditLogValueChange {
+            new_value: Some(AuditLogChangeTypeValue::Integer(0)),
+            ..
+        })
+    ));
+    let AuditLogChange::PermissionOverwrites(overwrites) = &changes[2] else {
+        panic!("expected permission_overwrites change");
+    };
+    assert_eq!(overwrites.new_value.as_ref().unwrap()[0].allow, "1024");
+
+    // unknown keys are kept as raw changes
+    let AuditLogChange::Unknown(raw) = &changes[3] else {
+        panic!("expected unknown change");
+    };
+    assert_eq!(raw.key, "some_future_key");
+    assert_eq!(raw.new_value, Some(serde_json::json!({ "a": 2 })));
+
+    // known keys with unexpected values fall back to raw as well
+    assert!(matches!(&changes[4], AuditLogChange::Unknown(raw) if raw.key == "position"));
+}
+
+#[test]
+fn test_audit_log_json_roundtrip() {
+    let json = load_fixture("audit_log", "member_entries.json");
+    assert_json_roundtrip::<AuditLogApiType>(&json);
+}
+
+#[test]
+fn test_target_dependent_values_are_resolved_by_action() {
+    let log: AuditLogApiType = load_fixture_as("audit_log", "target_dependent_entries.json");
+
+    let AuditLogChange::PrivacyLevel(stage) = &entry_changes(&log, 0)[0] else {
+        panic!("expected privacy_level change");
+    };
+    assert_eq!(
+        stage.new_value,
+        Some(AuditLogChangePrivacyLevelValue::StageInstance(
+            StageInstancePrivacyLevel::GUILD_ONLY
+        ))
+    );
+
+    let changes = entry_changes(&log, 1);
+    assert!(matches!(
+        &changes[0],
+        AuditLogChange::PrivacyLevel(AuditLogValueChange {
+            new_value: Some(AuditLogChangePrivacyLevelValue::ScheduledEvent(
+                GuildScheduledEventPrivacyLevel::GUILD_ONLY
+            )),
+            ..
+        })
+    ));
+    assert!(matches!(
+        &changes[1],
+        AuditLogChange::Status(AuditLogValueChange {
+            new_value: Some(GuildScheduledEventStatus::ACTIVE),
+            ..
+        })
+    ));
+
+    let AuditLogChange::Flags(role) = &entry_changes(&log, 2)[0] else {
+        panic!("expected flags change");
+    };
+    assert_eq!(
+        role.new_value,
+        Some(AuditLogChangeFlagsValue::Role(RoleFlags::IN_PROMPT))
+    );
+
+    let changes = entry_changes(&log, 3);
+    assert!(matches!(
+        &changes[0],
+        AuditLogChange::Flags(AuditLogValueChange {
+            new_value: Some(AuditLogChangeFlagsValue::Channel(flags)),
+            ..
+        }) if *flags == ChannelFlags::REQUIRE_TAG
+    ));
+    assert!(matches!(
+        &changes[1],
+        AuditLogChange::DefaultForumLayout(AuditLogValueChange {
+            new_value: Some(ForumLayoutType::GALLERY_VIEW),
+            ..
+        })
+    ));
+
+    let changes = entry_changes(&log, 4);
+    assert!(matches!(
+        &changes[0],
+        AuditLogChange::VerificationLevel(AuditLogValueChange {
+            new_value: Some(VerificationLevel::HIGH),
+            ..
+        })
+    ));
+    // unknown values are kept instead of falling back to a raw change
+    assert!(matches!(
+        &changes[2],
+        AuditLogChange::ExplicitContentFilter(AuditLogValueChange {
+            new_value: Some(ExplicitContentFilterLevel::Unknown(9)),
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_target_dependent_values_roundtrip() {
+    let json = load_fixture("audit_log", "target_dependent_entries.json");
+    assert_json_roundtrip::<AuditLogApiType>(&json);
+
+    // changes parsed without their entry keep the integer
+    let change: AuditLogChange =
+        serde_json::from_str(r#"{ "key": "privacy_level", "new_value": 2 }"#).unwrap();
+    assert!(matches!(
+        change,
+        AuditLogChange::PrivacyLevel(AuditLogValueChange {
+            new_value: Some(AuditLogChangePrivacyLevelValue::Integer(2)),
+            ..
+        })
+    ));
+}
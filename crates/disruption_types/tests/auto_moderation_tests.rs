@@ -0,0 +1,267 @@
+/// Tests for Auto Moderation types serialization, deserialization and validation
+mod common;
+
+use common::*;
+use disruption_types::resources::{
+    AutoModerationActionApiType, AutoModerationActionMetadataApiType, AutoModerationActionType,
+    AutoModerationEventType, AutoModerationKeywordPresetType, AutoModerationRuleApiType,
+    AutoModerationTriggerMetadataApiType, AutoModerationTriggerType,
+    CreateAutoModerationRuleApiType, ModifyAutoModerationRuleApiType,
+};
+use disruption_types::validation::{Validate, ValidationErrorKind};
+use serde_json::json;
+
+fn create_rule(
+    trigger_type: AutoModerationTriggerType,
+    trigger_metadata: AutoModerationTriggerMetadataApiType,
+    actions: Vec<AutoModerationActionApiType>,
+) -> CreateAutoModerationRuleApiType {
+    CreateAutoModerationRuleApiType {
+        name: "rule".to_string(),
+        event_type: AutoModerationEventType::MESSAGE_SEND,
+        trigger_type,
+        trigger_metadata: Some(trigger_metadata),
+        actions,
+        enabled: None,
+        exempt_roles: None,
+        exempt_channels: None,
+        #[cfg(feature = "extra-fields")]
+        extra: Default::default(),
+    }
+}
+
+fn timeout_action(duration_seconds: u32) -> AutoModerationActionApiType {
+    AutoModerationActionApiType {
+        type_: AutoModerationActionType::TIMEOUT,
+        metadata: Some(AutoModerationActionMetadataApiType {
+            duration_seconds: Some(duration_seconds),
+            ..Default::default()
+        }),
+        #[cfg(feature = "extra-fields")]
+        extra: Default::default(),
+    }
+}
+
+#[test]
+fn test_keyword_rule_deserialization() {
+    let rule: AutoModerationRuleApiType = load_fixture_as("auto_moderation", "keyword_rule.json");
+
+    assert_eq!(rule.trigger_type, AutoModerationTriggerType::KEYWORD);
+    assert_eq!(
+        rule.trigger_metadata.keyword_filter.as_ref().unwrap().len(),
+        4
+    );
+    assert_eq!(
+        rule.trigger_metadata.allow_list,
+        Some(vec!["catapult".to_string()])
+    );
+    assert!(rule.trigger_metadata.presets.is_none());
+
+    let timeout = rule.actions[2].metadata.as_ref().unwrap();
+    assert_eq!(timeout.duration_seconds, Some(60));
+    let alert = rule.actions[1].metadata.as_ref().unwrap();
+    assert_eq!(alert.channel_id, Some("123456789123456789".to_string()));
+
+    assert!(
+        create_rule(rule.trigger_type, rule.trigger_metadata, rule.actions)
+            .validate()
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_keyword_rule_json_roundtrip() {
+    let json = load_fixture("auto_moderation", "keyword_rule.json");
+    assert_json_roundtrip::<AutoModerationRuleApiType>(&json);
+}
+
+#[test]
+fn test_trigger_metadata_checked_against_trigger_type() {
+    let metadata = AutoModerationTriggerMetadataApiType {
+        presets: Some(vec![AutoModerationKeywordPresetType::SLURS]),
+        ..Default::default()
+    };
+
+    assert!(create_rule(
+        AutoModerationTriggerType::KEYWORD_PRESET,
+        metadata.clone(),
+        vec![]
+    )
+    .validate()
+    .is_ok());
+    let errors = create_rule(AutoModerationTriggerType::KEYWORD, metadata, vec![])
+        .validate()
+        .unwrap_err();
+    assert_eq!(
+        errors.get("trigger_metadata.presets"),
+        Some(&ValidationErrorKind::NotAllowed)
+    );
+
+    let mention_spam = AutoModerationTriggerMetadataApiType {
+        mention_total_limit: Some(51),
+        mention_raid_protection_enabled: Some(true),
+        ..Default::default()
+    };
+    let errors = create_rule(
+        AutoModerationTriggerType::MENTION_SPAM,
+        mention_spam,
+        vec![],
+    )
+    .validate()
+    .unwrap_err();
+    assert_eq!(
+        errors.get("trigger_metadata.mention_total_limit"),
+        Some(&ValidationErrorKind::OutOfRange {
+            min: 0,
+            max: 50,
+            value: 51
+        })
+    );
+}
+
+#[test]
+fn test_trigger_metadata_entries_are_reported_by_index() {
+    let mut keywords = vec!["cat".to_string(); 4];
+    keywords[3] = "a".repeat(61);
+    let metadata = AutoModerationTriggerMetadataApiType {
+        keyword_filter: Some(keywords),
+        allow_list: Some(vec!["dog".to_string(); 101]),
+        ..Default::default()
+    };
+
+    let errors = create_rule(AutoModerationTriggerType::KEYWORD, metadata, vec![])
+        .validate()
+        .unwrap_err();
+    assert_eq!(errors.errors().len(), 2);
+    assert_eq!(
+        errors.get("trigger_metadata.keyword_filter[3]"),
+        Some(&ValidationErrorKind::TooLong {
+            max: 60,
+            length: 61
+        })
+    );
+    assert_eq!(
+        errors.get("trigger_metadata.allow_list"),
+        Some(&ValidationErrorKind::TooMany {
+            max: 100,
+            count: 101
+        })
+    );
+}
+
+#[test]
+fn test_action_metadata_validation() {
+    let keyword = AutoModerationTriggerMetadataApiType::default();
+    let rule = |trigger_type, actions| create_rule(trigger_type, keyword.clone(), actions);
+
+    assert!(rule(
+        AutoModerationTriggerType::MENTION_SPAM,
+        vec![timeout_action(60)]
+    )
+    .validate()
+    .is_ok());
+    assert!(matches!(
+        rule(AutoModerationTriggerType::SPAM, vec![timeout_action(60)])
+            .validate()
+            .unwrap_err()
+            .get("actions[0].type"),
+        Some(ValidationErrorKind::Invalid(_))
+    ));
+    assert!(matches!(
+        rule(
+            AutoModerationTriggerType::KEYWORD,
+            vec![timeout_action(2419201)]
+        )
+        .validate()
+        .unwrap_err()
+        .get("actions[0].metadata.duration_seconds"),
+        Some(ValidationErrorKind::OutOfRange { .. })
+    ));
+
+    let alert = AutoModerationActionApiType {
+        type_: AutoModerationActionType::SEND_ALERT_MESSAGE,
+        metadata: None,
+        #[cfg(feature = "extra-fields")]
+        extra: Default::default(),
+    };
+    let errors = rule(
+        AutoModerationTriggerType::KEYWORD,
+        vec![timeout_action(60), alert],
+    )
+    .validate()
+    .unwrap_err();
+    assert_eq!(
+        errors.get("actions[1].metadata.channel_id"),
+        Some(&ValidationErrorKind::Missing)
+    );
+}
+
+#[test]
+fn test_create_rule_serialization_and_validation() {
+    let rule = CreateAutoModerationRuleApiType {
+        name: "No slurs".to_string(),
+        event_type: AutoModerationEventType::MESSAGE_SEND,
+        trigger_type: AutoModerationTriggerType::KEYWORD_PRESET,
+        trigger_metadata: Some(AutoModerationTriggerMetadataApiType {
+            presets: Some(vec![AutoModerationKeywordPresetType::SLURS]),
+            ..Default::default()
+        }),
+        actions: vec![AutoModerationActionApiType {
+            type_: AutoModerationActionType::BLOCK_MESSAGE,
+            metadata: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }],
+        enabled: Some(true),
+        exempt_roles: None,
+        exempt_channels: None,
+        #[cfg(feature = "extra-fields")]
+        extra: Default::default(),
+    };
+
+    assert!(rule.validate().is_ok());
+    assert_eq!(
+        serde_json::to_value(&rule).unwrap(),
+        json!({
+            "name": "No slurs",
+            "event_type": 1,
+            "trigger_type": 4,
+            "trigger_metadata": { "presets": [3] },
+            "actions": [{ "type": 1 }],
+            "enabled": true
+        })
+    );
+}
+
+#[test]
+fn test_modify_rule_validation() {
+    let modify = ModifyAutoModerationRuleApiType {
+        exempt_roles: Some(vec!["1".to_string(); 21]),
+        ..Default::default()
+    };
+    assert_eq!(
+        modify.validate().unwrap_err().get("exempt_roles"),
+        Some(&ValidationErrorKind::TooMany { max: 20, count: 21 })
+    );
+
+    let presets = ModifyAutoModerationRuleApiType {
+        trigger_metadata: Some(AutoModerationTriggerMetadataApiType {
+            presets: Some(vec![AutoModerationKeywordPresetType::PROFANITY]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    // the trigger type is not part of the changes
+    assert!(presets.validate().is_ok());
+    assert_eq!(
+        presets
+            .validate_for(AutoModerationTriggerType::KEYWORD)
+            .unwrap_err()
+            .get("trigger_metadata.presets"),
+        Some(&ValidationErrorKind::NotAllowed)
+    );
+    assert_eq!(
+        serde_json::to_value(ModifyAutoModerationRuleApiType::default()).unwrap(),
+        json!({})
+    );
+}
@@ -0,0 +1,103 @@
+/// Tests for building CDN URLs
+mod common;
+
+use common::*;
+use disruption_types::cdn::{CdnError, CdnUrl, ImageFormat};
+use disruption_types::entities::UserApiType;
+use disruption_types::resources::StickerFormatType;
+
+#[test]
+fn test_user_avatar_urls() {
+    let user: UserApiType = load_fixture_as("users", "basic_user.json");
+    let hash = user.avatar.clone().unwrap();
+    assert_eq!(
+        user.avatar_url().to_string(),
+        format!(
+            "https://cdn.discordapp.com/avatars/{}/{}.png",
+            user.id, hash
+        )
+    );
+
+    let animated = CdnUrl::user_avatar("80351110224678912", "a_1269e74af4df7417b13759eae50c83dc");
+    assert!(animated.is_animated());
+    assert_eq!(
+        animated.to_string(),
+        "https://cdn.discordapp.com/avatars/80351110224678912/a_1269e74af4df7417b13759eae50c83dc.gif"
+    );
+    assert_eq!(
+        animated.format(ImageFormat::WebP).unwrap().size(256).unwrap().to_string(),
+        "https://cdn.discordapp.com/avatars/80351110224678912/a_1269e74af4df7417b13759eae50c83dc.webp?animated=true&size=256"
+    );
+}
+
+#[test]
+fn test_default_avatars() {
+    // new username system: (user_id >> 22) % 6
+    assert_eq!(
+        CdnUrl::default_user_avatar("80351110224678912", "0").to_string(),
+        "https://cdn.discordapp.com/embed/avatars/5.png"
+    );
+    // legacy discriminators: discriminator % 5
+    assert_eq!(
+        CdnUrl::default_user_avatar("80351110224678912", "1337").to_string(),
+        "https://cdn.discordapp.com/embed/avatars/2.png"
+    );
+    assert_eq!(
+        CdnUrl::default_user_avatar("80351110224678912", "0").size(64),
+        Err(CdnError::SizeNotSupported)
+    );
+}
+
+#[test]
+fn test_format_and_size_choices() {
+    let icon = CdnUrl::guild_icon("197038439483310086", "1269e74af4df7417b13759eae50c83dc");
+    assert_eq!(
+        icon.clone().format(ImageFormat::Gif),
+        Err(CdnError::UnsupportedFormat(ImageFormat::Gif))
+    );
+    assert_eq!(icon.clone().size(100), Err(CdnError::InvalidSize(100)));
+    assert_eq!(icon.clone().size(8192), Err(CdnError::InvalidSize(8192)));
+    assert_eq!(
+        icon.format(ImageFormat::Jpeg)
+            .and_then(|url| url.size(4096))
+            .unwrap()
+            .to_string(),
+        "https://cdn.discordapp.com/icons/197038439483310086/1269e74af4df7417b13759eae50c83dc.jpg?size=4096"
+    );
+
+    assert_eq!(
+        CdnUrl::guild_member_avatar("1", "2", "a_abc").to_string(),
+        "https://cdn.discordapp.com/guilds/1/users/2/avatars/a_abc.gif"
+    );
+    assert_eq!(
+        CdnUrl::custom_emoji("41771983429993937", false).to_string(),
+        "https://cdn.discordapp.com/emojis/41771983429993937.png"
+    );
+}
+
+#[test]
+fn test_sticker_formats() {
+    let lottie = CdnUrl::sticker("749054660769218631", StickerFormatType::LOTTIE).unwrap();
+    assert_eq!(
+        lottie.to_string(),
+        "https://cdn.discordapp.com/stickers/749054660769218631.json"
+    );
+    assert_eq!(lottie.size(160), Err(CdnError::SizeNotSupported));
+
+    assert_eq!(
+        CdnUrl::sticker("1", StickerFormatType::APNG)
+            .unwrap()
+            .to_string(),
+        "https://cdn.discordapp.com/stickers/1.png"
+    );
+    assert_eq!(
+        CdnUrl::sticker("1", StickerFormatType::GIF)
+            .unwrap()
+            .to_string(),
+        "https://media.discordapp.net/stickers/1.gif"
+    );
+    assert_eq!(
+        CdnUrl::sticker("1", StickerFormatType::Unknown(9)),
+        Err(CdnError::UnknownStickerFormat(9))
+    );
+}
@@ -93,7 +93,22 @@ fn test_channel_types() {
     for (type_num, expected_type, type_name) in types_to_test {
         let json = format!(r#"{{"id": "123", "type": {}}}"#, type_num);
         let channel: ChannelApiType = serde_json::from_str(&json)
-            .expect(&format!("Failed to deserialize {} channel", type_name));
+            .unwrap_or_else(|_| panic!("Failed to deserialize {} channel", type_name));
         assert_eq!(channel.type_, expected_type);
     }
 }
+
+#[test]
+fn test_unknown_channel_type_roundtrip() {
+    let json = load_fixture("channels", "text_channel.json");
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    value["type"] = serde_json::json!(99);
+
+    let channel: ChannelApiType =
+        serde_json::from_value(value).expect("unknown channel types must not fail parsing");
+    assert_eq!(channel.type_, ChannelTypeApiType::Unknown(99));
+    assert_eq!(u8::from(channel.type_), 99);
+
+    let reserialized = serde_json::to_value(&channel).unwrap();
+    assert_eq!(reserialized["type"], 99);
+}
@@ -0,0 +1,77 @@
+/// Tests for colors of embeds and roles
+mod common;
+
+use common::*;
+use disruption_types::color::{Color, ColorParseError};
+use disruption_types::entities::{RoleApiType, RoleColorsApiType};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn test_color_from_hex() {
+    assert_eq!(Color::from_hex("#5865F2"), Ok(Color::BLURPLE));
+    assert_eq!(Color::from_hex("57f287"), Ok(Color::GREEN));
+    assert_eq!("#FFF".parse::<Color>(), Ok(Color::WHITE));
+    assert_eq!(Color::from_hex("#0f0"), Ok(Color::from_rgb(0, 255, 0)));
+
+    for input in ["", "#", "#12345", "#1234567", "+12345", "#GGGGGG", "#+1234"] {
+        assert_eq!(
+            Color::from_hex(input),
+            Err(ColorParseError {
+                input: input.to_string()
+            }),
+            "{input:?} should be invalid"
+        );
+    }
+}
+
+#[test]
+fn test_color_components() {
+    let color = Color::from_rgb(0x58, 0x65, 0xF2);
+
+    assert_eq!(color, Color::BLURPLE);
+    assert_eq!(color.value(), 0x5865F2);
+    assert_eq!(color.to_rgb(), (0x58, 0x65, 0xF2));
+    assert_eq!(Color::from((1, 2, 3)).to_string(), "#010203");
+    assert_eq!(Color::BLURPLE.to_string(), "#5865F2");
+    assert_eq!(Color::new(0x1_000000), Color::BLACK);
+}
+
+#[test]
+fn test_color_serialization() {
+    assert_eq!(serde_json::to_value(Color::RED).unwrap(), json!(0xED4245));
+    assert_eq!(
+        serde_json::from_value::<Color>(json!(3066993)).unwrap(),
+        Color::from_hex("#2ECC71").unwrap()
+    );
+}
+
+#[test]
+fn test_role_colors() {
+    let role: RoleApiType = load_fixture_as("roles", "gradient_role.json");
+    let colors = role.colors.as_ref().expect("fixture has colors");
+
+    assert_eq!(role.color, colors.primary_color);
+    assert!(colors.is_gradient());
+    assert_eq!(
+        colors.secondary_color,
+        Some(Color::from_hex("#FFBBEC").unwrap())
+    );
+    assert_eq!(colors.tertiary_color, Some(Color::new(16761760)));
+
+    let json = load_fixture("roles", "gradient_role.json");
+    assert_json_roundtrip::<RoleApiType>(&json);
+
+    let role: RoleApiType = load_fixture_as("roles", "basic_role.json");
+    assert!(role.colors.is_none());
+}
+
+#[test]
+fn test_build_role_colors() {
+    let colors = RoleColorsApiType::gradient(Color::BLURPLE, Color::FUCHSIA);
+    assert_eq!(
+        serde_json::to_value(&colors).unwrap(),
+        json!({ "primary_color": 0x5865F2, "secondary_color": 0xEB459E, "tertiary_color": null })
+    );
+    assert!(!RoleColorsApiType::solid(Color::GREEN).is_gradient());
+}
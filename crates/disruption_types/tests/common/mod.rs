@@ -1,4 +1,6 @@
-/// Common test utilities and fixture loading for disruption_types tests
+//! Common test utilities and fixture loading for disruption_types tests
+#![allow(dead_code)]
+
 use std::path::PathBuf;
 
 /// Load a JSON fixture file from the fixtures directory
@@ -0,0 +1,213 @@
+/// Tests for parsing message content into markdown and mention nodes
+use disruption_types::formatting::*;
+use disruption_types::timestamp::Timestamp;
+use pretty_assertions::assert_eq;
+
+fn kinds<'a>(parsed: &'a ParsedContent) -> Vec<&'a NodeKind<'a>> {
+    parsed.nodes().iter().map(|node| &node.kind).collect()
+}
+
+#[test]
+fn test_mentions_with_spans() {
+    let content =
+        "hey <@80351110224678912> and <@!1234>, see <#41771983423143937> <@&5678> @everyone";
+    let parsed = ParsedContent::parse(content);
+
+    assert_eq!(parsed.user_mentions(), vec!["80351110224678912", "1234"]);
+    assert_eq!(parsed.role_mentions(), vec!["5678"]);
+    assert_eq!(parsed.channel_mentions(), vec!["41771983423143937"]);
+    assert!(parsed.mentions_everyone());
+
+    for node in parsed.nodes() {
+        if let NodeKind::UserMention(id) = node.kind {
+            assert!(content[node.span.clone()].contains(id));
+            assert!(content[node.span.clone()].starts_with("<@"));
+        }
+    }
+    assert_eq!(parsed.nodes()[1].span, 4..24);
+}
+
+#[test]
+fn test_emojis_timestamps_and_commands() {
+    let parsed =
+        ParsedContent::parse("<:LUL:123><a:dance:456><t:1618953630:R><t:1618953630></tag get:789>");
+
+    assert_eq!(
+        kinds(&parsed),
+        vec![
+            &NodeKind::Emoji {
+                name: "LUL",
+                id: "123",
+                animated: false
+            },
+            &NodeKind::Emoji {
+                name: "dance",
+                id: "456",
+                animated: true
+            },
+            &NodeKind::Timestamp {
+                timestamp: Timestamp::from_unix_secs(1618953630),
+                style: Some(TimestampStyle::Relative)
+            },
+            &NodeKind::Timestamp {
+                timestamp: Timestamp::from_unix_secs(1618953630),
+                style: None
+            },
+            &NodeKind::SlashCommand {
+                name: "tag get",
+                id: "789"
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_markup_is_text() {
+    let content = "<@abc> <t:1:x> <:no_id:> a < b > c";
+    let parsed = ParsedContent::parse(content);
+
+    assert_eq!(kinds(&parsed), vec![&NodeKind::Text(content)]);
+}
+
+#[test]
+fn test_styles_nest() {
+    let parsed = ParsedContent::parse("**bold *italic* ||<@1>||**");
+    let node = &parsed.nodes()[0];
+
+    assert_eq!(node.span, 0..26);
+    let NodeKind::Styled { style, children } = &node.kind else {
+        panic!("expected styled node, got {:?}", node.kind);
+    };
+    assert_eq!(*style, Style::Bold);
+    assert_eq!(children.len(), 4);
+    assert!(matches!(
+        children[1].kind,
+        NodeKind::Styled {
+            style: Style::Italic,
+            ..
+        }
+    ));
+    assert!(matches!(
+        children[3].kind,
+        NodeKind::Styled {
+            style: Style::Spoiler,
+            ..
+        }
+    ));
+    assert_eq!(parsed.user_mentions(), vec!["1"]);
+}
+
+#[test]
+fn test_code_is_not_parsed() {
+    let parsed =
+        ParsedContent::parse("`<@1>` ``a`b`` ```rust\nlet x = **y**;\n``` ```\nplain\n```");
+
+    assert!(parsed.user_mentions().is_empty());
+    let code: Vec<_> = parsed
+        .iter()
+        .filter(|node| !matches!(node.kind, NodeKind::Text(_)))
+        .map(|node| node.kind.clone())
+        .collect();
+    assert_eq!(
+        code,
+        vec![
+            NodeKind::InlineCode("<@1>"),
+            NodeKind::InlineCode("a`b"),
+            NodeKind::CodeBlock {
+                language: Some("rust"),
+                code: "let x = **y**;"
+            },
+            NodeKind::CodeBlock {
+                language: None,
+                code: "plain"
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_links() {
+    let parsed = ParsedContent::parse(
+        "see https://example.com/a_(b). or <https://example.org> and [docs](https://discord.com/developers)",
+    );
+
+    assert_eq!(
+        parsed.links(),
+        vec![
+            "https://example.com/a_(b)",
+            "https://example.org",
+            "https://discord.com/developers"
+        ]
+    );
+}
+
+#[test]
+fn test_block_elements() {
+    let parsed = ParsedContent::parse("# Title\n> quoted\n-# small\ntext >>> not a quote");
+
+    assert!(matches!(
+        parsed.nodes()[0].kind,
+        NodeKind::Heading { level: 1, .. }
+    ));
+    assert!(matches!(parsed.nodes()[2].kind, NodeKind::Quote(_)));
+    assert!(matches!(parsed.nodes()[4].kind, NodeKind::Subtext(_)));
+    assert_eq!(
+        parsed.nodes()[5].kind,
+        NodeKind::Text("\ntext >>> not a quote")
+    );
+}
+
+#[test]
+fn test_escaped_markup() {
+    let parsed = ParsedContent::parse(r"\*\*not bold\*\* \<@1>");
+
+    assert!(parsed.user_mentions().is_empty());
+    assert_eq!(parsed.plain_text(), "**not bold** <@1>");
+}
+
+#[test]
+fn test_plain_text_defeats_filter_bypasses() {
+    assert_eq!(to_plain_text("||b||**a**__d__"), "bad");
+    assert_eq!(to_plain_text("b\u{200B}a\u{00AD}d\u{2060}word"), "badword");
+    assert_eq!(
+        to_plain_text("[free nitro](https://scam.example) from <@1>"),
+        "free nitro (https://scam.example) from @1"
+    );
+    assert_eq!(to_plain_text("> ~~*quoted*~~ <a:wave:2>"), "quoted :wave:");
+}
+
+#[test]
+fn test_unclosed_markup_is_text() {
+    let content = "**not closed ~~ || ` [text](no link)";
+    let parsed = ParsedContent::parse(content);
+
+    assert_eq!(parsed.plain_text(), content);
+    assert!(parsed
+        .nodes()
+        .iter()
+        .all(|node| matches!(node.kind, NodeKind::Text(_))));
+}
+
+#[test]
+fn test_multibyte_content() {
+    let content = "🔥 **héllo** ||wörld|| ünïcode";
+    let parsed = ParsedContent::parse(content);
+
+    assert_eq!(parsed.plain_text(), "🔥 héllo wörld ünïcode");
+    for node in parsed.iter() {
+        assert!(content.is_char_boundary(node.span.start));
+        assert!(content.is_char_boundary(node.span.end));
+    }
+}
+
+#[test]
+fn test_message_content() {
+    let message = disruption_types::channel::MessageApiType {
+        content: "hi <@42> ||*secret*||".to_string(),
+        ..Default::default()
+    };
+    let parsed = message.parsed_content();
+
+    assert_eq!(parsed.user_mentions(), vec!["42"]);
+    assert_eq!(parsed.plain_text(), "hi @42 secret");
+}
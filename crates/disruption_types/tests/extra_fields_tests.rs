@@ -0,0 +1,44 @@
+#![cfg(feature = "extra-fields")]
+/// Tests for preserving unknown fields with the `extra-fields` feature
+mod common;
+
+use common::*;
+use disruption_types::channel::MessageApiType;
+use disruption_types::interactions::InteractionApiType;
+use serde_json::{json, Value};
+
+#[test]
+fn test_unknown_message_fields_roundtrip() {
+    let json = load_fixture("messages", "basic_message.json");
+    let mut value: Value = serde_json::from_str(&json).unwrap();
+    value["shiny_new_field"] = json!({ "nested": [1, 2, 3] });
+    value["author"]["future_flag"] = json!(true);
+
+    let message: MessageApiType = serde_json::from_value(value).unwrap();
+    assert_eq!(
+        message.extra.get("shiny_new_field"),
+        Some(&json!({ "nested": [1, 2, 3] }))
+    );
+    assert_eq!(message.author.extra.get("future_flag"), Some(&json!(true)));
+    assert!(!message.extra.contains_key("content"));
+
+    let reserialized = serde_json::to_value(&message).unwrap();
+    assert_eq!(reserialized["shiny_new_field"]["nested"], json!([1, 2, 3]));
+    assert_eq!(reserialized["author"]["future_flag"], json!(true));
+}
+
+#[test]
+fn test_unknown_interaction_fields_roundtrip() {
+    let json = load_fixture("interactions", "ping_command.json");
+    let mut value: Value = serde_json::from_str(&json).unwrap();
+    value["attachment_size_limit"] = json!(26214400);
+
+    let interaction: InteractionApiType = serde_json::from_value(value).unwrap();
+    assert_eq!(
+        interaction.extra.get("attachment_size_limit"),
+        Some(&json!(26214400))
+    );
+
+    let reserialized = serde_json::to_value(&interaction).unwrap();
+    assert_eq!(reserialized["attachment_size_limit"], json!(26214400));
+}
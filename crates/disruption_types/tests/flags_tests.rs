@@ -0,0 +1,97 @@
+/// Tests for bit flag types
+mod common;
+
+use common::*;
+use disruption_types::channel::{MessageApiType, MessageFlags};
+use disruption_types::entities::{GuildMemberFlags, UserApiType, UserFlags};
+use disruption_types::interactions::InteractionCallbackMessageApiType;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn test_user_flags_from_fixture() {
+    let user: UserApiType = load_fixture_as("users", "full_user.json");
+
+    let flags = user.public_flags.expect("fixture has public flags");
+    assert!(flags.contains(UserFlags::HYPESQUAD_ONLINE_HOUSE_1));
+    assert!(!flags.contains(UserFlags::STAFF));
+    assert_eq!(flags.bits(), 64);
+}
+
+#[test]
+fn test_set_operations() {
+    let mut flags = MessageFlags::EPHEMERAL | MessageFlags::HAS_THREAD;
+
+    assert!(flags.contains(MessageFlags::EPHEMERAL));
+    assert!(flags.contains(MessageFlags::EPHEMERAL | MessageFlags::HAS_THREAD));
+    assert!(!flags.contains(MessageFlags::EPHEMERAL | MessageFlags::URGENT));
+    assert!(flags.intersects(MessageFlags::EPHEMERAL | MessageFlags::URGENT));
+
+    flags.remove(MessageFlags::HAS_THREAD);
+    assert_eq!(flags, MessageFlags::EPHEMERAL);
+
+    flags.insert(MessageFlags::LOADING);
+    flags.toggle(MessageFlags::EPHEMERAL);
+    assert_eq!(flags, MessageFlags::LOADING);
+
+    flags.set(MessageFlags::SUPPRESS_EMBEDS, true);
+    assert_eq!(flags - MessageFlags::LOADING, MessageFlags::SUPPRESS_EMBEDS);
+    assert_eq!(flags & MessageFlags::LOADING, MessageFlags::LOADING);
+    assert!((flags ^ flags).is_empty());
+    assert!(!(!flags).intersects(flags));
+
+    let collected: MessageFlags = [MessageFlags::URGENT, MessageFlags::CROSSPOSTED]
+        .into_iter()
+        .collect();
+    assert_eq!(collected.bits(), (1 << 4) | 1);
+}
+
+#[test]
+fn test_unknown_bits_are_kept() {
+    let flags: GuildMemberFlags = serde_json::from_value(json!((1 << 1) | (1 << 20))).unwrap();
+
+    assert!(flags.contains(GuildMemberFlags::COMPLETED_ONBOARDING));
+    assert_eq!(
+        serde_json::to_value(flags).unwrap(),
+        json!((1 << 1) | (1 << 20))
+    );
+    assert_eq!(
+        GuildMemberFlags::from_bits_truncate(flags.bits()),
+        GuildMemberFlags::COMPLETED_ONBOARDING
+    );
+    assert_eq!(
+        format!("{flags:?}"),
+        "GuildMemberFlags(COMPLETED_ONBOARDING | 0x100000)"
+    );
+    assert_eq!(
+        format!("{:?}", GuildMemberFlags::empty()),
+        "GuildMemberFlags()"
+    );
+}
+
+#[test]
+fn test_message_flags_field() {
+    let mut message = MessageApiType {
+        content: "thinking".to_string(),
+        ..Default::default()
+    };
+    message.flags = Some(MessageFlags::LOADING | MessageFlags::EPHEMERAL);
+
+    let value = serde_json::to_value(&message).unwrap();
+    assert_eq!(value["flags"], json!(192));
+
+    let message: MessageApiType = serde_json::from_value(value).unwrap();
+    assert!(message
+        .flags
+        .is_some_and(|flags| flags.contains(MessageFlags::EPHEMERAL)));
+}
+
+#[test]
+fn test_ephemeral_response() {
+    let response = InteractionCallbackMessageApiType::new("only for you");
+    assert!(!response.is_ephemeral());
+
+    let response = response.ephemeral();
+    assert!(response.is_ephemeral());
+    assert_eq!(response.flags, Some(MessageFlags::EPHEMERAL));
+}
@@ -0,0 +1,80 @@
+/// Tests for mention, timestamp and markdown formatting
+mod common;
+
+use common::*;
+use disruption_types::entities::{EmojiApiType, RoleApiType, UserApiType};
+use disruption_types::formatting::*;
+use disruption_types::timestamp::Timestamp;
+
+#[test]
+fn test_entity_mentions() {
+    let user: UserApiType = load_fixture_as("users", "basic_user.json");
+    assert_eq!(user.mention(), "<@80351110224678912>");
+
+    let role: RoleApiType = load_fixture_as("roles", "basic_role.json");
+    assert_eq!(role.mention(), format!("<@&{}>", role.id));
+
+    assert_eq!(channel_mention("41771983423143937"), "<#41771983423143937>");
+    assert_eq!(slash_command_mention("tag get", "1089"), "</tag get:1089>");
+
+    let emoji: EmojiApiType = serde_json::from_value(serde_json::json!({
+        "id": "41771983429993937",
+        "name": "LUL",
+        "animated": true
+    }))
+    .unwrap();
+    assert_eq!(emoji.mention(), "<a:LUL:41771983429993937>");
+
+    let unicode: EmojiApiType =
+        serde_json::from_value(serde_json::json!({ "id": null, "name": "🔥" })).unwrap();
+    assert_eq!(unicode.mention(), "🔥");
+}
+
+#[test]
+fn test_timestamp_styles() {
+    let timestamp = Timestamp::from_unix_secs(1_618_953_630);
+    assert_eq!(timestamp.mention(), "<t:1618953630>");
+    assert_eq!(
+        timestamp_mention(timestamp, Some(TimestampStyle::Relative)),
+        "<t:1618953630:R>"
+    );
+
+    for flag in ['t', 'T', 'd', 'D', 'f', 'F', 'R'] {
+        let style = TimestampStyle::from_flag(flag).unwrap();
+        assert_eq!(style.flag(), flag);
+    }
+    assert_eq!(TimestampStyle::from_flag('x'), None);
+}
+
+#[test]
+fn test_markdown_helpers() {
+    assert_eq!(bold("hi"), "**hi**");
+    assert_eq!(spoiler("hi"), "||hi||");
+    assert_eq!(heading(5, "hi"), "### hi");
+    assert_eq!(inline_code("a`b"), "`` a`b ``");
+    assert_eq!(quote("a\nb"), "> a\n> b");
+    assert_eq!(
+        code_block(Some("rust"), "let x = 1;"),
+        "```rust\nlet x = 1;\n```"
+    );
+    assert!(!code_block(None, "```").contains("````"));
+}
+
+#[test]
+fn test_escaping_user_input() {
+    assert_eq!(escape_markdown("**not bold**"), r"\*\*not bold\*\*");
+    assert_eq!(escape_markdown(r"a_b\c"), r"a\_b\\c");
+
+    let escaped = escape_mentions("@everyone @here <@80351110224678912>");
+    assert!(!escaped.contains("@everyone"));
+    assert!(!escaped.contains("@here"));
+    assert!(!escaped.contains("<@"));
+    assert_eq!(
+        escaped.replace('\u{200B}', ""),
+        "@everyone @here <@80351110224678912>"
+    );
+
+    let both = escape("||@everyone||");
+    assert!(!both.contains("||"));
+    assert!(!both.contains("@everyone"));
+}
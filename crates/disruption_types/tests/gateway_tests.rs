@@ -2,9 +2,11 @@
 mod common;
 
 use common::*;
-use disruption_types::gateway::Event;
+use disruption_types::gateway::{Event, Intents};
 use disruption_types::opcodes::GatewayOpcode;
-use disruption_types::payloads::{HelloPayloadData, Payload, ReadyPayloadData};
+use disruption_types::payloads::{
+    HelloPayloadData, IdentifyConnectionProperties, IdentifyPayloadData, Payload, ReadyPayloadData,
+};
 
 #[test]
 fn test_hello_payload_deserialization() {
@@ -65,9 +67,9 @@ fn test_gateway_opcodes() {
 
     for (code, name) in opcodes {
         let json = format!(r#"{{"op": {}, "d": null}}"#, code);
-        let payload: Payload =
-            serde_json::from_str(&json).expect(&format!("Failed to deserialize {} opcode", name));
-        assert_eq!(payload.op as u8, code);
+        let payload: Payload = serde_json::from_str(&json)
+            .unwrap_or_else(|_| panic!("Failed to deserialize {} opcode", name));
+        assert_eq!(u8::from(payload.op), code);
     }
 }
 
@@ -109,3 +111,31 @@ fn test_heartbeat_payload() {
         serde_json::from_value(payload.d.unwrap()).expect("Failed to parse sequence number");
     assert_eq!(seq, 251);
 }
+
+#[test]
+fn test_unknown_gateway_opcode() {
+    let payload: Payload = serde_json::from_str(r#"{"op": 42, "d": null}"#)
+        .expect("unknown opcodes must not fail parsing");
+    assert_eq!(payload.op, GatewayOpcode::Unknown(42));
+    assert_eq!(GatewayOpcode::from(10), GatewayOpcode::Hello);
+
+    let reserialized = serde_json::to_value(&payload).unwrap();
+    assert_eq!(reserialized["op"], 42);
+}
+
+#[test]
+fn test_identify_intents_are_combined() {
+    let identify = IdentifyPayloadData {
+        token: "token".to_string(),
+        properties: IdentifyConnectionProperties::new("linux", "disruption", "disruption"),
+        intents: Intents::GUILD_MESSAGES | Intents::MESSAGE_CONTENT,
+        ..Default::default()
+    };
+
+    let value = serde_json::to_value(&identify).unwrap();
+    assert_eq!(value["intents"], (1 << 9) | (1 << 15));
+
+    let identify: IdentifyPayloadData = serde_json::from_value(value).unwrap();
+    assert!(identify.intents.contains(Intents::MESSAGE_CONTENT));
+    assert!(!identify.intents.contains(Intents::GUILD_MEMBERS));
+}
@@ -0,0 +1,285 @@
+/// Tests for Interaction-related types serialization and deserialization
+mod common;
+
+use common::*;
+use disruption_types::channel::ComponentApiType;
+use disruption_types::channel::{ChannelTypeApiType, MessageComponentApiType};
+use disruption_types::interactions::{
+    ApplicationCommandOptionChoiceApiType, ApplicationCommandOptionType,
+    ApplicationCommandOptionValue, ApplicationCommandType, InteractionApiType,
+    InteractionCallbackDataApiType, InteractionCallbackMessageApiType,
+    InteractionCallbackModalApiType, InteractionCallbackType, InteractionDataApiType,
+    InteractionResponseApiType, InteractionType,
+};
+use disruption_types::locale::Locale;
+use serde_json::json;
+
+#[test]
+fn test_ping_command_deserialization() {
+    let interaction: InteractionApiType = load_fixture_as("interactions", "ping_command.json");
+
+    assert_eq!(interaction.type_, InteractionType::APPLICATION_COMMAND);
+
+    let guild = interaction.guild.expect("guild should be present");
+    assert_eq!(guild.id, "197038439483310086");
+    assert_eq!(guild.locale, Locale::EnglishUs);
+    assert_eq!(guild.features, vec!["COMMUNITY".to_string()]);
+
+    let channel = interaction.channel.expect("channel should be present");
+    assert_eq!(channel.type_, ChannelTypeApiType::GUILD_TEXT);
+    assert_eq!(channel.name, Some("general".to_string()));
+
+    let data = interaction.data.expect("data should be present");
+    let command = data
+        .as_application_command()
+        .expect("data should be application command data");
+    assert_eq!(command.name, "ping");
+    assert_eq!(command.type_, ApplicationCommandType::CHAT_INPUT);
+    assert!(command.options.is_none());
+}
+
+#[test]
+fn test_slash_command_nested_option_values() {
+    let interaction: InteractionApiType =
+        load_fixture_as("interactions", "slash_command_options.json");
+    let Some(InteractionDataApiType::ApplicationCommand(command)) = interaction.data else {
+        panic!("expected application command data");
+    };
+
+    let subcommand = command.option("timeout").expect("subcommand missing");
+    assert_eq!(subcommand.type_, ApplicationCommandOptionType::SUB_COMMAND);
+    assert!(subcommand.value.is_none());
+
+    let user = subcommand.option("user").and_then(|o| o.value.as_ref());
+    assert_eq!(
+        user,
+        Some(&ApplicationCommandOptionValue::User(
+            "80351110224678912".to_string()
+        ))
+    );
+
+    let minutes = subcommand.option("minutes").and_then(|o| o.value.as_ref());
+    assert_eq!(minutes.and_then(|v| v.as_i64()), Some(15));
+
+    let factor = subcommand.option("factor").and_then(|o| o.value.as_ref());
+    assert_eq!(factor.and_then(|v| v.as_f64()), Some(1.5));
+
+    let silent = subcommand.option("silent").and_then(|o| o.value.as_ref());
+    assert_eq!(silent.and_then(|v| v.as_bool()), Some(true));
+
+    let reason = subcommand.option("reason").and_then(|o| o.value.as_ref());
+    assert_eq!(reason.and_then(|v| v.as_str()), Some("spam"));
+
+    let users = command.resolved.and_then(|r| r.users).unwrap();
+    assert_eq!(users["80351110224678912"].username, "Nelly");
+}
+
+#[test]
+fn test_autocomplete_focused_option() {
+    let interaction: InteractionApiType = load_fixture_as("interactions", "autocomplete.json");
+    assert_eq!(
+        interaction.type_,
+        InteractionType::APPLICATION_COMMAND_AUTOCOMPLETE
+    );
+
+    let command = interaction
+        .data
+        .as_ref()
+        .and_then(|d| d.as_application_command())
+        .expect("autocomplete should carry application command data");
+
+    let focused = command.option("minutes").unwrap();
+    assert_eq!(focused.focused, Some(true));
+    assert_eq!(
+        focused.value,
+        Some(ApplicationCommandOptionValue::Focused("1".to_string()))
+    );
+}
+
+#[test]
+fn test_message_component_data() {
+    let interaction: InteractionApiType = load_fixture_as("interactions", "role_select.json");
+    let component = interaction
+        .data
+        .as_ref()
+        .and_then(|d| d.as_message_component())
+        .expect("expected message component data");
+
+    assert_eq!(component.custom_id, "role_picker");
+    assert_eq!(
+        component.component_type,
+        MessageComponentApiType::RoleSelect
+    );
+    assert_eq!(
+        component.values,
+        Some(vec!["41771983423143936".to_string()])
+    );
+}
+
+#[test]
+fn test_modal_submit_data() {
+    let interaction: InteractionApiType = load_fixture_as("interactions", "modal_submit.json");
+    let modal = interaction
+        .data
+        .as_ref()
+        .and_then(|d| d.as_modal_submit())
+        .expect("expected modal submit data");
+
+    assert_eq!(modal.custom_id, "feedback");
+    assert_eq!(modal.value("summary"), Some("Great bot!"));
+    assert_eq!(modal.value("missing"), None);
+}
+
+#[test]
+fn test_interaction_data_must_match_type() {
+    let json = load_fixture("interactions", "modal_submit.json");
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    value["type"] = serde_json::json!(2);
+
+    let result = serde_json::from_value::<InteractionApiType>(value);
+    assert!(result.is_err(), "modal data must not parse as command data");
+}
+
+#[test]
+fn test_option_value_must_match_option_type() {
+    let json = load_fixture("interactions", "slash_command_options.json");
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    value["data"]["options"][0]["options"][1]["value"] = serde_json::json!("fifteen");
+
+    let result = serde_json::from_value::<InteractionApiType>(value);
+    assert!(result.is_err(), "string value must not parse as INTEGER");
+}
+
+#[test]
+fn test_interaction_serialization_roundtrip() {
+    for fixture in [
+        "ping_command.json",
+        "slash_command_options.json",
+        "autocomplete.json",
+        "role_select.json",
+        "modal_submit.json",
+    ] {
+        let json = load_fixture("interactions", fixture);
+        let interaction: InteractionApiType = serde_json::from_str(&json).unwrap();
+        let reserialized = serde_json::to_string(&interaction).unwrap();
+        let reparsed: InteractionApiType = serde_json::from_str(&reserialized)
+            .unwrap_or_else(|e| panic!("Failed to re-deserialize {}: {}", fixture, e));
+
+        assert_eq!(interaction.type_, reparsed.type_);
+        assert_eq!(
+            serde_json::to_value(&interaction.data).unwrap(),
+            serde_json::to_value(&reparsed.data).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_ephemeral_message_response_serialization() {
+    let response = InteractionResponseApiType::message(
+        InteractionCallbackMessageApiType::new("Only you can see this").ephemeral(),
+    );
+
+    assert_eq!(
+        serde_json::to_value(&response).unwrap(),
+        json!({
+            "type": 4,
+            "data": { "content": "Only you can see this", "flags": 64 }
+        })
+    );
+}
+
+#[test]
+fn test_deferred_responses_serialization() {
+    assert_eq!(
+        serde_json::to_value(InteractionResponseApiType::deferred_message(false)).unwrap(),
+        json!({ "type": 5 })
+    );
+    assert_eq!(
+        serde_json::to_value(InteractionResponseApiType::deferred_message(true)).unwrap(),
+        json!({ "type": 5, "data": { "flags": 64 } })
+    );
+    assert_eq!(
+        serde_json::to_value(InteractionResponseApiType::pong()).unwrap(),
+        json!({ "type": 1 })
+    );
+}
+
+#[test]
+fn test_autocomplete_response_serialization() {
+    let response =
+        InteractionResponseApiType::autocomplete(vec![ApplicationCommandOptionChoiceApiType {
+            name: "Five minutes".to_string(),
+            name_localizations: None,
+            value: json!(5),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }]);
+
+    assert_eq!(
+        serde_json::to_value(&response).unwrap(),
+        json!({
+            "type": 8,
+            "data": { "choices": [{ "name": "Five minutes", "value": 5 }] }
+        })
+    );
+}
+
+#[test]
+fn test_modal_response_roundtrip() {
+    let mut input = ComponentApiType::new(MessageComponentApiType::TextInput);
+    input.custom_id = Some("summary".to_string());
+    input.label = Some("Summary".to_string());
+    input.style = Some(1);
+
+    let response = InteractionResponseApiType::modal(InteractionCallbackModalApiType {
+        custom_id: "feedback".to_string(),
+        title: "Feedback".to_string(),
+        components: vec![ComponentApiType::action_row(vec![input])],
+        #[cfg(feature = "extra-fields")]
+        extra: Default::default(),
+    });
+
+    let json = serde_json::to_string(&response).unwrap();
+    let parsed: InteractionResponseApiType = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(parsed.type_(), InteractionCallbackType::MODAL);
+    let Some(InteractionCallbackDataApiType::Modal(modal)) = parsed.data() else {
+        panic!("expected modal data");
+    };
+    assert_eq!(modal.title, "Feedback");
+    assert_eq!(
+        modal.components[0].components.as_ref().unwrap()[0].custom_id,
+        Some("summary".to_string())
+    );
+}
+
+#[test]
+fn test_mismatched_response_data_is_rejected() {
+    let message =
+        InteractionCallbackDataApiType::Message(InteractionCallbackMessageApiType::new("hello"));
+
+    assert!(
+        InteractionResponseApiType::new(InteractionCallbackType::MODAL, Some(message.clone()))
+            .is_err()
+    );
+    assert!(
+        InteractionResponseApiType::new(InteractionCallbackType::PONG, Some(message.clone()))
+            .is_err()
+    );
+    assert!(InteractionResponseApiType::new(
+        InteractionCallbackType::CHANNEL_MESSAGE_WITH_SOURCE,
+        None
+    )
+    .is_err());
+    assert!(InteractionResponseApiType::new(
+        InteractionCallbackType::UPDATE_MESSAGE,
+        Some(message)
+    )
+    .is_ok());
+
+    let result = serde_json::from_value::<InteractionResponseApiType>(json!({
+        "type": 9,
+        "data": { "content": "not a modal" }
+    }));
+    assert!(result.is_err(), "message data must not parse as a modal");
+}
@@ -0,0 +1,64 @@
+/// Tests for JSON error responses of the REST API
+mod common;
+
+use common::*;
+use disruption_types::opcodes::{JsonErrorApiType, JsonErrorCode, JsonFieldError};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn test_invalid_form_body() {
+    let error: JsonErrorApiType = load_fixture_as("errors", "invalid_form_body.json");
+
+    assert_eq!(error.code, JsonErrorCode::InvalidFormBody);
+    assert_eq!(
+        error.field_errors(),
+        vec![
+            JsonFieldError {
+                path: "activities[0].platform".to_string(),
+                code: "BASE_TYPE_CHOICES".to_string(),
+                message: "Value must be one of ('desktop', 'android', 'ios').".to_string(),
+            },
+            JsonFieldError {
+                path: "activities[0].type".to_string(),
+                code: "BASE_TYPE_CHOICES".to_string(),
+                message: "Value must be one of (0, 1, 2, 3, 4, 5).".to_string(),
+            },
+        ]
+    );
+
+    let json = load_fixture("errors", "invalid_form_body.json");
+    assert_json_roundtrip::<JsonErrorApiType>(&json);
+}
+
+#[test]
+fn test_request_body_errors() {
+    let error: JsonErrorApiType = serde_json::from_value(json!({
+        "code": 50035,
+        "message": "Invalid Form Body",
+        "errors": {
+            "_errors": [{ "code": "MESSAGE_BLOCKED_BY_AUTOMOD", "message": "Message was blocked" }]
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(error.field_errors()[0].path, "");
+    assert_eq!(
+        error.to_string(),
+        "Invalid Form Body (code 50035): Message was blocked"
+    );
+}
+
+#[test]
+fn test_error_codes() {
+    let error: JsonErrorApiType =
+        serde_json::from_value(json!({ "code": 10008, "message": "Unknown Message" })).unwrap();
+
+    assert_eq!(error.code, JsonErrorCode::UnknownMessage);
+    assert!(error.code.is_unknown_resource());
+    assert!(error.field_errors().is_empty());
+    assert_eq!(error.to_string(), "Unknown Message (code 10008)");
+
+    assert!(!JsonErrorCode::MissingPermissions.is_unknown_resource());
+    assert_eq!(JsonErrorCode::from(12345), JsonErrorCode::Unknown(12345));
+}
@@ -0,0 +1,103 @@
+/// Tests for locales and localization maps
+mod common;
+
+use common::*;
+use disruption_types::interactions::{ApplicationCommandApiType, InteractionApiType};
+use disruption_types::locale::{Locale, Localizations};
+use disruption_types::resources::{AuditLogChange, AuditLogValueChange};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn test_locale_roundtrip() {
+    for locale in Locale::ALL {
+        let value = serde_json::to_value(locale).unwrap();
+        let parsed: Locale = serde_json::from_value(value.clone()).unwrap();
+
+        assert_eq!(&parsed, locale);
+        assert_eq!(value, json!(locale.as_str()));
+        assert!(parsed.is_known());
+    }
+
+    assert_eq!(Locale::ALL.len(), 32);
+}
+
+#[test]
+fn test_locale_parsing() {
+    assert_eq!(Locale::from("es-419"), Locale::SpanishLatam);
+    assert_eq!("pt-BR".parse::<Locale>(), Ok(Locale::PortugueseBrazilian));
+    assert_eq!(Locale::ChineseTaiwan.to_string(), "zh-TW");
+
+    // locales are case sensitive on the wire
+    assert_eq!(Locale::from("en-us"), Locale::Unknown("en-us".to_string()));
+}
+
+#[test]
+fn test_unknown_locale_is_kept() {
+    let locale: Locale = serde_json::from_value(json!("tlh")).unwrap();
+
+    assert_eq!(locale, Locale::Unknown("tlh".to_string()));
+    assert!(!locale.is_known());
+    assert_eq!(serde_json::to_value(&locale).unwrap(), json!("tlh"));
+}
+
+#[test]
+fn test_interaction_locales() {
+    let interaction: InteractionApiType = load_fixture_as("interactions", "ping_command.json");
+
+    assert_eq!(interaction.locale, Some(Locale::EnglishUs));
+    assert_eq!(interaction.guild.unwrap().locale, Locale::EnglishUs);
+}
+
+#[test]
+fn test_command_localizations() {
+    let command: ApplicationCommandApiType = serde_json::from_value(json!({
+        "id": "1",
+        "application_id": "2",
+        "name": "ping",
+        "name_localizations": { "de": "ping", "fr": "ping", "es-ES": "ping" },
+        "description": "Check the latency",
+        "description_localizations": { "de": "Prüfe die Latenz", "ja": "レイテンシを確認" },
+        "default_member_permissions": null,
+        "version": "1"
+    }))
+    .unwrap();
+
+    let descriptions = command.description_localizations.as_ref().unwrap();
+    assert_eq!(descriptions[&Locale::German], "Prüfe die Latenz");
+    assert_eq!(descriptions[&Locale::Japanese], "レイテンシを確認");
+    assert_eq!(command.name_localizations.as_ref().unwrap().len(), 3);
+
+    let value = serde_json::to_value(&command).unwrap();
+    assert_eq!(value["description_localizations"]["de"], "Prüfe die Latenz");
+}
+
+#[test]
+fn test_build_localizations() {
+    let localizations = Localizations::from([
+        (Locale::German, "hallo".to_string()),
+        (Locale::EnglishUk, "hello".to_string()),
+    ]);
+
+    let value = serde_json::to_value(&localizations).unwrap();
+    assert_eq!(value, json!({ "de": "hallo", "en-GB": "hello" }));
+}
+
+#[test]
+fn test_preferred_locale_audit_log_change() {
+    let change: AuditLogChange = serde_json::from_value(json!({
+        "key": "preferred_locale",
+        "old_value": "en-US",
+        "new_value": "de"
+    }))
+    .unwrap();
+    let AuditLogChange::PreferredLocale(AuditLogValueChange {
+        old_value,
+        new_value,
+    }) = change
+    else {
+        panic!("expected preferred_locale change, got {change:?}");
+    };
+    assert_eq!(old_value, Some(Locale::EnglishUs));
+    assert_eq!(new_value, Some(Locale::German));
+}
@@ -3,6 +3,7 @@ mod common;
 
 use common::*;
 use disruption_types::channel::{MessageApiType, MessageTypeApiType};
+use disruption_types::color::Color;
 
 #[test]
 fn test_basic_message_deserialization() {
@@ -37,7 +38,7 @@ fn test_message_with_embed_deserialization() {
         embed.description,
         Some("This is an embed description".to_string())
     );
-    assert_eq!(embed.color, Some(3066993));
+    assert_eq!(embed.color, Some(Color::new(3066993)));
 
     let fields = embed.fields.as_ref().unwrap();
     assert_eq!(fields.len(), 2);
@@ -86,6 +87,7 @@ fn test_message_types() {
             MessageTypeApiType::CHAT_INPUT_COMMAND,
             "CHAT_INPUT_COMMAND",
         ),
+        (250, MessageTypeApiType::Unknown(250), "unknown"),
     ];
 
     for (type_num, expected_type, type_name) in message_types {
@@ -115,8 +117,11 @@ fn test_message_types() {
         );
 
         let message: MessageApiType = serde_json::from_str(&json)
-            .expect(&format!("Failed to deserialize {} message", type_name));
+            .unwrap_or_else(|_| panic!("Failed to deserialize {} message", type_name));
         assert_eq!(message.type_, expected_type);
+
+        let reserialized = serde_json::to_value(&message).expect("Failed to serialize");
+        assert_eq!(reserialized["type"], type_num);
     }
 }
 
@@ -0,0 +1,62 @@
+/// Tests for parsing and formatting emojis of reactions
+use disruption_types::channel::{ReactionType, ReactionTypeApiType};
+use disruption_types::entities::EmojiApiType;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn test_parse_reaction_types() {
+    assert_eq!("👍".parse(), Ok(ReactionType::unicode("👍")));
+    assert_eq!("blob:123".parse(), Ok(ReactionType::custom("blob", "123")));
+    assert_eq!(
+        "<:blob:123>".parse(),
+        Ok(ReactionType::custom("blob", "123"))
+    );
+    assert_eq!(
+        "<a:dance:456>".parse(),
+        Ok(ReactionType::Custom {
+            name: Some("dance".to_string()),
+            id: "456".to_string(),
+            animated: true,
+        })
+    );
+
+    for input in ["", "  ", "<:blob:abc>", "blob:", "<blob>"] {
+        assert!(
+            input.parse::<ReactionType>().is_err(),
+            "{input:?} should not parse"
+        );
+    }
+}
+
+#[test]
+fn test_reaction_type_display() {
+    assert_eq!(ReactionType::from('🔥').to_string(), "🔥");
+    assert_eq!(ReactionType::custom("blob", "123").to_string(), "blob:123");
+
+    let nameless = ReactionType::Custom {
+        name: None,
+        id: "123".to_string(),
+        animated: false,
+    };
+    assert_eq!(nameless.to_string(), "_:123");
+}
+
+#[test]
+fn test_reaction_type_from_emoji() {
+    let custom: EmojiApiType = serde_json::from_value(
+        json!({ "id": "41771983429993937", "name": "LUL", "animated": true }),
+    )
+    .unwrap();
+    assert_eq!(
+        ReactionType::from(&custom),
+        "<a:LUL:41771983429993937>".parse().unwrap()
+    );
+
+    let unicode: EmojiApiType =
+        serde_json::from_value(json!({ "id": null, "name": "🔥" })).unwrap();
+    assert_eq!(ReactionType::from(unicode), ReactionType::unicode("🔥"));
+
+    assert_eq!(u8::from(ReactionTypeApiType::BURST), 1);
+    assert_eq!(ReactionTypeApiType::default(), ReactionTypeApiType::NORMAL);
+}
@@ -0,0 +1,143 @@
+/// Tests for request bodies and tri-state fields
+use disruption_types::channel::{
+    BulkDeleteMessagesApiType, ChannelTypeApiType, CreateChannelApiType, CreateMessageApiType,
+    EditMessageApiType, MessageFlags,
+};
+use disruption_types::entities::{CreateRoleApiType, GuildMemberFlags, ModifyGuildMemberApiType};
+use disruption_types::nullable::Nullable;
+use disruption_types::timestamp::Timestamp;
+use disruption_types::validation::{Validate, ValidationErrorKind};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[test]
+fn test_create_message_only_sends_set_fields() {
+    let message = CreateMessageApiType::from("hello there");
+    assert_eq!(
+        serde_json::to_value(&message).unwrap(),
+        json!({ "content": "hello there" })
+    );
+
+    let reply = CreateMessageApiType::new("pong").reply_to("1234");
+    assert_eq!(
+        serde_json::to_value(&reply).unwrap(),
+        json!({ "content": "pong", "message_reference": { "message_id": "1234" } })
+    );
+}
+
+#[test]
+fn test_create_message_validation() {
+    assert_eq!(CreateMessageApiType::from("hi").validate(), Ok(()));
+
+    let errors = CreateMessageApiType::default().validate().unwrap_err();
+    assert!(matches!(
+        errors.get(""),
+        Some(ValidationErrorKind::Invalid(_))
+    ));
+
+    let mut message = CreateMessageApiType::from("a".repeat(2001));
+    message.sticker_ids = Some(vec!["1".to_string(); 4]);
+    let errors = message.validate().unwrap_err();
+    assert!(errors.get("content").is_some());
+    assert_eq!(
+        errors.get("sticker_ids"),
+        Some(&ValidationErrorKind::TooMany { max: 3, count: 4 })
+    );
+}
+
+#[test]
+fn test_edit_message_tri_state() {
+    let edit = EditMessageApiType {
+        content: "edited".to_string().into(),
+        embeds: Nullable::Null,
+        flags: Nullable::Value(MessageFlags::SUPPRESS_EMBEDS),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        serde_json::to_value(&edit).unwrap(),
+        json!({ "content": "edited", "embeds": null, "flags": 4 })
+    );
+    assert_eq!(
+        serde_json::to_value(EditMessageApiType::default()).unwrap(),
+        json!({})
+    );
+}
+
+#[test]
+fn test_nullable_deserialize() {
+    let edit: EditMessageApiType =
+        serde_json::from_value(json!({ "content": null, "flags": 4 })).unwrap();
+
+    assert!(edit.content.is_null());
+    assert!(edit.embeds.is_absent());
+    assert_eq!(edit.flags, Nullable::Value(MessageFlags::SUPPRESS_EMBEDS));
+    assert_eq!(
+        edit.flags.into_option(),
+        Some(Some(MessageFlags::SUPPRESS_EMBEDS))
+    );
+    assert_eq!(Nullable::<u8>::from(None), Nullable::Null);
+}
+
+#[test]
+fn test_modify_guild_member() {
+    let modify = ModifyGuildMemberApiType {
+        nick: Nullable::Null,
+        communication_disabled_until: Timestamp::from_unix_secs(1_700_000_000).into(),
+        flags: GuildMemberFlags::BYPASSES_VERIFICATION.into(),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        serde_json::to_value(&modify).unwrap(),
+        json!({
+            "nick": null,
+            "communication_disabled_until": "2023-11-14T22:13:20+00:00",
+            "flags": 4
+        })
+    );
+}
+
+#[test]
+fn test_create_channel_and_role() {
+    let channel = CreateChannelApiType::new("general", ChannelTypeApiType::GUILD_TEXT);
+    assert_eq!(
+        serde_json::to_value(&channel).unwrap(),
+        json!({ "name": "general", "type": 0 })
+    );
+
+    let mut role = CreateRoleApiType::new("moderators");
+    role.hoist = Some(true);
+    assert_eq!(
+        serde_json::to_value(&role).unwrap(),
+        json!({ "name": "moderators", "hoist": true })
+    );
+}
+
+#[test]
+fn test_bulk_delete_messages() {
+    let request = BulkDeleteMessagesApiType::new(["1", "2"]);
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({ "messages": ["1", "2"] })
+    );
+    assert_eq!(request.validate(), Ok(()));
+
+    assert_eq!(
+        BulkDeleteMessagesApiType::new(["1"])
+            .validate()
+            .unwrap_err()
+            .get("messages"),
+        Some(&ValidationErrorKind::TooFew { min: 2, count: 1 })
+    );
+    assert_eq!(
+        BulkDeleteMessagesApiType::new(0..101)
+            .validate()
+            .unwrap_err()
+            .get("messages"),
+        Some(&ValidationErrorKind::TooMany {
+            max: 100,
+            count: 101
+        })
+    );
+}
@@ -0,0 +1,101 @@
+/// Tests for parsing, formatting and arithmetic of timestamps
+mod common;
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use common::*;
+use disruption_types::channel::MessageApiType;
+use disruption_types::timestamp::Timestamp;
+
+#[test]
+fn test_parse_discord_formats() {
+    let with_micros: Timestamp = "2021-06-15T13:45:30.123456+00:00".parse().unwrap();
+    assert_eq!(with_micros.unix_millis(), 1_623_764_730_123);
+
+    let without_fraction: Timestamp = "2021-06-15T13:45:30+00:00".parse().unwrap();
+    assert_eq!(without_fraction.unix_secs(), 1_623_764_730);
+
+    let zulu: Timestamp = "2021-06-15T13:45:30.1Z".parse().unwrap();
+    assert_eq!(zulu.unix_millis(), 1_623_764_730_100);
+
+    let offset: Timestamp = "2021-06-15T15:45:30+02:00".parse().unwrap();
+    assert_eq!(offset, without_fraction);
+}
+
+#[test]
+fn test_invalid_timestamps_are_rejected() {
+    for input in [
+        "",
+        "2021-06-15",
+        "2021-06-15T13:45:30",
+        "2021-13-01T00:00:00+00:00",
+        "2021-02-29T00:00:00+00:00",
+        "2021-06-15T24:00:00+00:00",
+        "2021-06-15T13:45:30.+00:00",
+        "2021-06-15T13:45:30+00:00 ",
+    ] {
+        assert!(input.parse::<Timestamp>().is_err(), "{input:?} should fail");
+    }
+    assert!("2020-02-29T00:00:00+00:00".parse::<Timestamp>().is_ok());
+}
+
+#[test]
+fn test_format_roundtrip() {
+    for input in [
+        "2020-01-01T00:00:00.000000+00:00",
+        "2021-06-15T13:45:30.123000+00:00",
+        "2021-06-15T13:45:30+00:00",
+        "1969-12-31T23:59:59.999000+00:00",
+    ] {
+        let timestamp: Timestamp = input.parse().unwrap();
+        assert_eq!(timestamp.to_string(), input);
+    }
+
+    assert_eq!(
+        Timestamp::from_unix_millis(1_623_764_730_123).to_string(),
+        "2021-06-15T13:45:30.123000+00:00"
+    );
+}
+
+#[test]
+fn test_system_time_and_arithmetic() {
+    let joined: Timestamp = "2021-06-15T13:45:30+00:00".parse().unwrap();
+    assert_eq!(
+        joined.to_system_time(),
+        UNIX_EPOCH + Duration::from_secs(1_623_764_730)
+    );
+    assert_eq!(Timestamp::from(SystemTime::from(joined)), joined);
+
+    let timeout = joined + Duration::from_secs(5 * 60);
+    assert_eq!(timeout.duration_since(joined), Duration::from_secs(300));
+    assert_eq!(joined.duration_since(timeout), Duration::ZERO);
+    assert_eq!(timeout - Duration::from_secs(300), joined);
+    assert!(timeout > joined);
+    assert!(joined.elapsed() > Duration::ZERO);
+}
+
+#[test]
+fn test_message_timestamps() {
+    let message: MessageApiType = load_fixture_as("messages", "basic_message.json");
+    let sent: Timestamp = "2016-03-24T23:15:59.605000+00:00".parse().unwrap();
+
+    assert_eq!(message.timestamp, sent);
+    assert!(message.edited_timestamp.is_none());
+
+    let reserialized = serde_json::to_value(&message).unwrap();
+    assert_eq!(
+        reserialized["timestamp"],
+        "2016-03-24T23:15:59.605000+00:00"
+    );
+}
+
+#[test]
+fn test_snowflake_timestamps() {
+    let created = Timestamp::from_snowflake("175928847299117063").unwrap();
+    assert_eq!(created.unix_millis(), 1_462_015_105_796);
+    assert!(Timestamp::from_snowflake("not a snowflake").is_none());
+
+    let id = created.to_snowflake().unwrap();
+    assert_eq!(Timestamp::from_snowflake(&id), Some(created));
+    assert!(Timestamp::from_unix_secs(0).to_snowflake().is_none());
+}
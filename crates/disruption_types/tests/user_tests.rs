@@ -2,7 +2,8 @@
 mod common;
 
 use common::*;
-use disruption_types::entities::UserApiType;
+use disruption_types::entities::{PremiumType, UserApiType, UserFlags};
+use disruption_types::locale::Locale;
 
 #[test]
 fn test_basic_user_deserialization() {
@@ -30,7 +31,7 @@ fn test_full_user_deserialization() {
     assert_eq!(user.mfa_enabled, Some(true));
     assert_eq!(user.verified, Some(true));
     assert_eq!(user.email, Some("nelly@example.com".to_string()));
-    assert_eq!(user.premium_type, Some(2));
+    assert_eq!(user.premium_type, Some(PremiumType::Nitro));
 }
 
 #[test]
@@ -57,15 +58,17 @@ fn test_user_serialization_roundtrip() {
         mfa_enabled: Some(false),
         banner: None,
         accent_color: None,
-        locale: Some("en-US".to_string()),
+        locale: Some(Locale::EnglishUs),
         verified: Some(true),
         email: None,
-        flags: Some(0),
-        premium_type: Some(0),
-        public_flags: Some(0),
+        flags: Some(UserFlags::empty()),
+        premium_type: Some(PremiumType::None),
+        public_flags: Some(UserFlags::empty()),
         avatar_decoration_data: None,
         collectibles: None,
         primary_guild: None,
+        #[cfg(feature = "extra-fields")]
+        extra: Default::default(),
     };
 
     // Serialize and deserialize to ensure roundtrip works
@@ -102,3 +105,10 @@ fn test_user_default() {
     assert_eq!(user.username, "");
     assert_eq!(user.discriminator, "");
 }
+
+#[test]
+fn test_unknown_premium_type_is_preserved() {
+    let premium_type: PremiumType = serde_json::from_str("7").unwrap();
+    assert_eq!(premium_type, PremiumType::Unknown(7));
+    assert_eq!(serde_json::to_string(&premium_type).unwrap(), "7");
+}
@@ -0,0 +1,394 @@
+/// Tests for the client side validation of payload limits
+use std::time::Duration;
+
+use disruption_types::channel::{
+    ChannelTypeApiType, ComponentApiType, CreateChannelApiType, EmbedApiType, MessageApiType,
+};
+use disruption_types::entities::{
+    BulkGuildBanApiType, CreateGuildBanApiType, CreateRoleApiType, ModifyGuildMemberApiType,
+};
+use disruption_types::interactions::{
+    ApplicationCommandApiType, InteractionCallbackModalApiType, InteractionResponseApiType,
+};
+use disruption_types::resources::{PollApiType, PollCreateRequestApiType};
+use disruption_types::timestamp::Timestamp;
+use disruption_types::validation::*;
+use pretty_assertions::assert_eq;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+fn parse<T: DeserializeOwned>(value: Value) -> T {
+    serde_json::from_value(value).expect("valid test payload")
+}
+
+fn message(content: impl ToString) -> MessageApiType {
+    MessageApiType {
+        content: content.to_string(),
+        ..Default::default()
+    }
+}
+
+fn command(value: Value) -> ApplicationCommandApiType {
+    let mut command = json!({
+        "id": "1",
+        "application_id": "2",
+        "name": "ping",
+        "description": "Check the latency",
+        "default_member_permissions": null,
+        "version": "1"
+    });
+    command
+        .as_object_mut()
+        .unwrap()
+        .extend(value.as_object().unwrap().clone());
+    parse(command)
+}
+
+#[test]
+fn test_valid_message() {
+    let mut message = message("hello there");
+    message.embeds = vec![parse(json!({
+        "title": "Title",
+        "fields": [{ "name": "a", "value": "b" }]
+    }))];
+
+    assert_eq!(message.validate(), Ok(()));
+}
+
+#[test]
+fn test_message_limits() {
+    let mut message = message("a".repeat(2001));
+    message.embeds = vec![parse(json!({ "title": "embed" })); 11];
+    message.components = Some(vec![ComponentApiType::action_row(vec![]); 6]);
+
+    let errors = message.validate().unwrap_err();
+    assert_eq!(
+        errors.get("content"),
+        Some(&ValidationErrorKind::TooLong {
+            max: MESSAGE_CONTENT_LENGTH,
+            length: 2001
+        })
+    );
+    assert_eq!(
+        errors.get("embeds"),
+        Some(&ValidationErrorKind::TooMany {
+            max: MAX_EMBEDS,
+            count: 11
+        })
+    );
+    assert_eq!(
+        errors.get("components"),
+        Some(&ValidationErrorKind::TooMany {
+            max: MAX_ACTION_ROWS,
+            count: 6
+        })
+    );
+    assert_eq!(
+        errors.get("components[0].components"),
+        Some(&ValidationErrorKind::TooFew { min: 1, count: 0 })
+    );
+}
+
+#[test]
+fn test_embed_limits() {
+    let fields: Vec<Value> = (0..26)
+        .map(|_| json!({ "name": "", "value": "v" }))
+        .collect();
+    let embed: EmbedApiType = parse(json!({
+        "title": "t".repeat(257),
+        "description": "d".repeat(4096),
+        "fields": fields,
+        "footer": { "text": "f".repeat(2048) }
+    }));
+
+    let errors = embed.validate().unwrap_err();
+    assert_eq!(
+        errors.get("title"),
+        Some(&ValidationErrorKind::TooLong {
+            max: EMBED_TITLE_LENGTH,
+            length: 257
+        })
+    );
+    assert_eq!(
+        errors.get("fields"),
+        Some(&ValidationErrorKind::TooMany {
+            max: MAX_EMBED_FIELDS,
+            count: 26
+        })
+    );
+    assert_eq!(
+        errors.get("fields[25].name"),
+        Some(&ValidationErrorKind::TooShort { min: 1, length: 0 })
+    );
+    assert_eq!(
+        errors.get(""),
+        Some(&ValidationErrorKind::TooLong {
+            max: EMBED_TOTAL_LENGTH,
+            length: 257 + 4096 + 26 + 2048
+        })
+    );
+    assert_eq!(
+        errors.to_string().lines().count(),
+        1,
+        "all errors are reported in one line"
+    );
+}
+
+#[test]
+fn test_combined_embed_length() {
+    let mut message = message("");
+    message.embeds = vec![parse(json!({ "description": "d".repeat(4000) })); 2];
+
+    let errors = message.validate().unwrap_err();
+    assert_eq!(errors.errors().len(), 1);
+    assert_eq!(errors.errors()[0].path, "embeds");
+}
+
+#[test]
+fn test_component_limits() {
+    let row: ComponentApiType = parse(json!({
+        "type": 1,
+        "components": [
+            { "type": 2, "style": 1, "label": "x".repeat(81) },
+            { "type": 2, "style": 5, "custom_id": "link" },
+            { "type": 3, "custom_id": "select", "options": [], "max_values": 26 },
+            { "type": 1, "components": [] }
+        ]
+    }));
+
+    let errors = row.validate().unwrap_err();
+    let paths: Vec<_> = errors
+        .errors()
+        .iter()
+        .map(|error| error.path.as_str())
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "components",
+            "components[0].label",
+            "components[0].custom_id",
+            "components[1].url",
+            "components[1].custom_id",
+            "components[2].max_values",
+            "components[2].options",
+            "components[3].type",
+        ]
+    );
+    assert_eq!(
+        errors.get("components[1].custom_id"),
+        Some(&ValidationErrorKind::NotAllowed)
+    );
+}
+
+#[test]
+fn test_text_input_limits() {
+    let input: ComponentApiType = parse(json!({
+        "type": 4,
+        "custom_id": "reason",
+        "style": 2,
+        "max_length": 4001
+    }));
+
+    let errors = input.validate().unwrap_err();
+    assert_eq!(errors.get("label"), Some(&ValidationErrorKind::Missing));
+    assert_eq!(
+        errors.get("max_length"),
+        Some(&ValidationErrorKind::OutOfRange {
+            min: 1,
+            max: 4000,
+            value: 4001
+        })
+    );
+}
+
+#[test]
+fn test_command_names() {
+    assert_eq!(command(json!({ "name": "tag-get_2" })).validate(), Ok(()));
+    assert_eq!(command(json!({ "name": "ปิง" })).validate(), Ok(()));
+
+    for name in ["Ping", "tag get", "", &"a".repeat(33)] {
+        let errors = command(json!({ "name": name })).validate().unwrap_err();
+        assert!(errors.get("name").is_some(), "{name:?} should be invalid");
+    }
+
+    // context menu commands may use spaces and capital letters
+    let context_menu = command(json!({ "type": 2, "name": "Show Avatar", "description": "" }));
+    assert_eq!(context_menu.validate(), Ok(()));
+}
+
+#[test]
+fn test_command_options() {
+    let choices: Vec<Value> = (0..26)
+        .map(|i| json!({ "name": format!("choice {i}"), "value": i }))
+        .collect();
+    let command = command(json!({
+        "name_localizations": { "de": "Ping" },
+        "options": [
+            { "type": 3, "name": "first", "description": "optional" },
+            { "type": 3, "name": "second", "description": "required", "required": true },
+            { "type": 4, "name": "third", "description": "", "choices": choices, "autocomplete": true },
+            { "type": 1, "name": "sub", "description": "subcommand", "options": [
+                { "type": 3, "name": "Nested", "description": "nested", "max_length": 6001 }
+            ]}
+        ]
+    }));
+
+    let errors = command.validate().unwrap_err();
+    assert!(errors.get("name_localizations.de").is_some());
+    assert!(matches!(
+        errors.get("options[1].required"),
+        Some(ValidationErrorKind::Invalid(_))
+    ));
+    assert_eq!(
+        errors.get("options[2].description"),
+        Some(&ValidationErrorKind::TooShort { min: 1, length: 0 })
+    );
+    assert_eq!(
+        errors.get("options[2].choices"),
+        Some(&ValidationErrorKind::TooMany {
+            max: MAX_OPTION_CHOICES,
+            count: 26
+        })
+    );
+    assert_eq!(
+        errors.get("options[2].autocomplete"),
+        Some(&ValidationErrorKind::NotAllowed)
+    );
+    assert!(errors.get("options[3].options[0].name").is_some());
+    assert!(errors.get("options[3].options[0].max_length").is_some());
+}
+
+#[test]
+fn test_poll_limits() {
+    let answers: Vec<Value> = (0..11)
+        .map(|i| json!({ "answer_id": i, "poll_media": { "text": "a".repeat(56) } }))
+        .collect();
+    let poll: PollApiType = parse(json!({
+        "question": { "text": "q".repeat(301) },
+        "answers": answers,
+        "expiry": null,
+        "allow_multiselect": false,
+        "layout_type": 1
+    }));
+
+    let errors = poll.validate().unwrap_err();
+    assert!(errors.get("question.text").is_some());
+    assert_eq!(
+        errors.get("answers"),
+        Some(&ValidationErrorKind::TooMany {
+            max: MAX_POLL_ANSWERS,
+            count: 11
+        })
+    );
+    assert_eq!(
+        errors.get("answers[10].poll_media.text"),
+        Some(&ValidationErrorKind::TooLong {
+            max: POLL_ANSWER_LENGTH,
+            length: 56
+        })
+    );
+
+    let request: PollCreateRequestApiType = parse(json!({
+        "question": { "text": "Lunch?" },
+        "answers": [{ "poll_media": { "text": "Pizza" } }],
+        "duration": 769
+    }));
+    let errors = request.validate().unwrap_err();
+    assert_eq!(errors.errors().len(), 1);
+    assert_eq!(
+        errors.errors()[0].to_string(),
+        "duration: 769 is not between 1 and 768"
+    );
+}
+
+#[test]
+fn test_member_moderation_limits() {
+    let timeout =
+        ModifyGuildMemberApiType::timeout(Some(Timestamp::now() + Duration::from_secs(60)));
+    assert!(timeout.validate().is_ok());
+    assert!(ModifyGuildMemberApiType::timeout(None).validate().is_ok());
+
+    let timeout = ModifyGuildMemberApiType::timeout(Some(
+        Timestamp::now() + Duration::from_secs((MAX_TIMEOUT_DAYS + 1) * 24 * 60 * 60),
+    ));
+    let errors = timeout.validate().unwrap_err();
+    assert_eq!(
+        errors.get("communication_disabled_until"),
+        Some(&ValidationErrorKind::Invalid(
+            "timeouts can last at most 28 days"
+        ))
+    );
+
+    let ban = CreateGuildBanApiType::delete_messages(MAX_DELETE_MESSAGE_SECONDS as u32 + 1);
+    assert!(ban
+        .validate()
+        .unwrap_err()
+        .get("delete_message_seconds")
+        .is_some());
+    assert!(CreateGuildBanApiType::delete_messages(3600)
+        .validate()
+        .is_ok());
+
+    let bans = BulkGuildBanApiType::new((0..=MAX_BULK_BAN_USERS).map(|id| id.to_string()));
+    assert_eq!(
+        bans.validate().unwrap_err().get("user_ids"),
+        Some(&ValidationErrorKind::TooMany {
+            max: MAX_BULK_BAN_USERS,
+            count: MAX_BULK_BAN_USERS + 1
+        })
+    );
+    let bans = BulkGuildBanApiType::new(Vec::<String>::new());
+    assert!(bans.validate().unwrap_err().get("user_ids").is_some());
+}
+
+#[test]
+fn test_channel_and_role_limits() {
+    let mut channel = CreateChannelApiType::new("general", ChannelTypeApiType::GUILD_TEXT);
+    channel.topic = Some("a".repeat(CHANNEL_TOPIC_LENGTH + 1));
+    channel.rate_limit_per_user = Some(MAX_RATE_LIMIT_PER_USER + 1);
+    let errors = channel.validate().unwrap_err();
+    assert!(errors.get("topic").is_some());
+    assert!(errors.get("rate_limit_per_user").is_some());
+
+    // forum channels allow longer topics
+    channel.type_ = Some(ChannelTypeApiType::GUILD_FORUM);
+    channel.rate_limit_per_user = None;
+    assert!(channel.validate().is_ok());
+
+    let channel = CreateChannelApiType::new("", ChannelTypeApiType::GUILD_TEXT);
+    assert_eq!(
+        channel.validate().unwrap_err().get("name"),
+        Some(&ValidationErrorKind::TooShort { min: 1, length: 0 })
+    );
+
+    let role = CreateRoleApiType::new("a".repeat(ROLE_NAME_LENGTH + 1));
+    assert!(role.validate().unwrap_err().get("name").is_some());
+}
+
+#[test]
+fn test_interaction_response_limits() {
+    let choices = (0..=MAX_OPTION_CHOICES)
+        .map(|i| parse(json!({ "name": i.to_string(), "value": i })))
+        .collect();
+    let errors = InteractionResponseApiType::autocomplete(choices)
+        .validate()
+        .unwrap_err();
+    assert!(errors.get("data.choices").is_some());
+
+    let modal = InteractionResponseApiType::modal(InteractionCallbackModalApiType {
+        custom_id: "feedback".to_string(),
+        title: "a".repeat(MODAL_TITLE_LENGTH + 1),
+        components: vec![],
+        #[cfg(feature = "extra-fields")]
+        extra: Default::default(),
+    });
+    let errors = modal.validate().unwrap_err();
+    assert!(errors.get("data.title").is_some());
+    assert_eq!(
+        errors.get("data.components"),
+        Some(&ValidationErrorKind::TooFew { min: 1, count: 0 })
+    );
+
+    assert!(InteractionResponseApiType::pong().validate().is_ok());
+}
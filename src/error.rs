@@ -1,5 +1,7 @@
 //! Error types for the Disruption Discord library
 
+use disruption_types::opcodes::{JsonErrorApiType, JsonErrorCode};
+use serde_json::Value;
 use thiserror::Error;
 
 /// Main error type for the Disruption library
@@ -27,7 +29,12 @@ pub enum RestError {
 
     /// Discord API returned an error
     #[error("Discord API error (status {status}): {message}")]
-    ApiError { status: u16, message: String },
+    ApiError {
+        status: u16,
+        message: String,
+        /// the JSON error body, if Discord sent one
+        error: Option<Box<JsonErrorApiType>>,
+    },
 
     /// Rate limit exceeded
     #[error("Rate limit exceeded. Retry after {retry_after} seconds")]
@@ -39,12 +46,21 @@ pub enum RestError {
 
     /// Resource not found
     #[error("Resource not found: {resource_type} with ID {id}")]
-    NotFound { resource_type: String, id: String },
+    NotFound {
+        resource_type: String,
+        id: String,
+        /// the JSON error body, if Discord sent one
+        error: Option<Box<JsonErrorApiType>>,
+    },
 
     /// Invalid request parameters
     #[error("Invalid request: {0}")]
     InvalidRequest(String),
 
+    /// Request body exceeds the limits of the Discord API
+    #[error("Invalid payload: {0}")]
+    Validation(#[from] disruption_types::validation::ValidationErrors),
+
     /// Failed to serialize request body
     #[error("Failed to serialize request: {0}")]
     SerializationError(#[from] serde_json::Error),
@@ -54,14 +70,107 @@ pub enum RestError {
     ResponseParseError(String),
 
     /// Missing required permission
-    #[error("Missing permission: {0}")]
-    MissingPermission(String),
+    ///
+    /// Discord also reports this when the bot has the permission, but its highest role is not
+    /// above the role of the member or the role it manages.
+    #[error("Missing permission: {message}{}", required_hint(required))]
+    MissingPermission {
+        message: String,
+        /// the JSON error body, if Discord sent one
+        error: Option<Box<JsonErrorApiType>>,
+        /// permissions the request needs, if known, e.g. `BAN_MEMBERS`
+        required: Option<String>,
+    },
 
     /// Invalid URL or endpoint
     #[error("Invalid URL: {0}")]
     InvalidUrl(String),
 }
 
+impl RestError {
+    /// Map an unsuccessful response of the API to an error.
+    ///
+    /// `path` is the requested API path and `body` the raw response body, which is parsed as a
+    /// Discord JSON error if possible.
+    pub fn from_response(status: u16, path: &str, body: &[u8]) -> Self {
+        let error = serde_json::from_slice::<JsonErrorApiType>(body)
+            .ok()
+            .map(Box::new);
+        let code = error.as_ref().map(|error| error.code);
+
+        match status {
+            401 => RestError::AuthenticationFailed,
+            403 if matches!(
+                code,
+                Some(JsonErrorCode::MissingPermissions | JsonErrorCode::MissingAccess)
+            ) =>
+            {
+                RestError::MissingPermission {
+                    message: error
+                        .as_ref()
+                        .map(|error| error.message.clone())
+                        .unwrap_or_default(),
+                    error,
+                    required: None,
+                }
+            }
+            404 if code.is_none_or(|code| code.is_unknown_resource()) => RestError::NotFound {
+                resource_type: error
+                    .as_ref()
+                    .and_then(|error| error.message.strip_prefix("Unknown "))
+                    .map_or_else(|| "resource".to_owned(), str::to_lowercase),
+                id: path
+                    .split(['/', '?'])
+                    .rfind(|segment| {
+                        !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit())
+                    })
+                    .unwrap_or_default()
+                    .to_owned(),
+                error,
+            },
+            429 => {
+                let retry_after = serde_json::from_slice::<Value>(body)
+                    .ok()
+                    .and_then(|body| body["retry_after"].as_f64())
+                    .unwrap_or(1.0);
+                RestError::RateLimited {
+                    retry_after: retry_after.ceil() as u64,
+                }
+            }
+            _ => RestError::ApiError {
+                status,
+                message: match &error {
+                    Some(error) => error.to_string(),
+                    None if body.is_empty() => reqwest::StatusCode::from_u16(status)
+                        .ok()
+                        .and_then(|status| status.canonical_reason())
+                        .unwrap_or_default()
+                        .to_owned(),
+                    None => String::from_utf8_lossy(body).into_owned(),
+                },
+                error,
+            },
+        }
+    }
+
+    /// The JSON error code Discord responded with, if any.
+    pub fn code(&self) -> Option<JsonErrorCode> {
+        match self {
+            RestError::ApiError { error, .. }
+            | RestError::MissingPermission { error, .. }
+            | RestError::NotFound { error, .. } => error.as_ref().map(|error| error.code),
+            _ => None,
+        }
+    }
+}
+
+fn required_hint(required: &Option<String>) -> String {
+    required
+        .as_ref()
+        .map(|required| format!(" (requires {required} and a role above the target)"))
+        .unwrap_or_default()
+}
+
 /// Result type for Disruption library operations
 pub type Result<T> = std::result::Result<T, Error>;
 
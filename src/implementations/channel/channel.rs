@@ -1,7 +1,23 @@
-use disruption_types::channel::{ChannelApiType, MessageApiType};
+use std::collections::HashSet;
+use std::time::Duration;
 
+use disruption_types::channel::{BulkDeleteMessagesApiType, ChannelApiType, CreateMessageApiType};
+use disruption_types::timestamp::Timestamp;
+use disruption_types::validation::{BULK_DELETE_MAX_AGE_DAYS, MAX_BULK_DELETE_MESSAGES};
+
+use futures::{Stream, TryStreamExt};
+
+use crate::pagination::{paginate, sort_page, Cursor, Pagination};
 use crate::{internal::RestClient, Result};
 
+use super::Message;
+
+/// Messages younger than the bulk delete limit by less than this are deleted one by one, as they
+/// could exceed the limit by the time the request arrives.
+const BULK_DELETE_AGE_MARGIN: Duration = Duration::from_secs(60);
+/// Maximum number of messages per request of the history
+const MESSAGES_PAGE_SIZE: usize = 100;
+
 #[derive(Debug, Clone)]
 pub struct Channel {
     rest: RestClient,
@@ -16,9 +32,8 @@ impl Channel {
     }
 
     /// Create a new channel from it's channel id.
-    pub async fn from_id(rest: RestClient, channel_id: &String) -> Result<Self> {
-        let res = rest.get(&format!("channels/{channel_id}")).await?;
-        let channel = res.json::<ChannelApiType>().await?;
+    pub async fn from_id(rest: RestClient, channel_id: &str) -> Result<Self> {
+        let channel = rest.get_channel(channel_id).await?;
         Ok(Channel::from_api_type(rest, channel))
     }
 
@@ -34,10 +49,89 @@ impl Channel {
     }
 
     /// Send a message into this channel.
-    pub(crate) async fn send(&self, message: impl Into<MessageApiType>) -> Result<()> {
-        self.rest
-            .post(&format!("channels/{}/messages", self.id()), &message.into())
-            .await?;
+    ///
+    /// The message is validated before sending, so exceeded limits are reported without a request.
+    pub(crate) async fn send(&self, message: impl Into<CreateMessageApiType>) -> Result<()> {
+        let message = message.into();
+        self.rest.create_message(self.id(), &message).await?;
+        Ok(())
+    }
+
+    /// Fetch a message of this channel.
+    pub async fn fetch_message(&self, message_id: &str) -> Result<Message> {
+        let msg = self.rest.get_message(self.id(), message_id).await?;
+        Ok(Message::in_channel(self.rest.clone(), msg, self))
+    }
+
+    /// Read the message history of this channel.
+    ///
+    /// Messages are fetched page by page while the stream is consumed. Paging backwards yields the
+    /// newest messages first, paging forwards (`Pagination::after`) yields the oldest first.
+    pub fn messages(&self, pagination: Pagination) -> impl Stream<Item = Result<Message>> {
+        let channel = self.clone();
+        let fetch = move |cursor: Option<Cursor>, limit: usize| {
+            let channel = channel.clone();
+            async move {
+                let mut messages = channel
+                    .rest
+                    .get_channel_messages(channel.id(), cursor.as_ref(), limit as u8)
+                    .await?;
+                let forwards = matches!(cursor, Some(Cursor::After(_)));
+                sort_page(&mut messages, |message| message.id.as_str(), forwards);
+                Ok(messages
+                    .into_iter()
+                    .map(|msg| Message::in_channel(channel.rest.clone(), msg, &channel))
+                    .collect::<Vec<_>>()
+                    .into())
+            }
+        };
+
+        paginate(
+            pagination,
+            MESSAGES_PAGE_SIZE,
+            |message: &Message| message.id().to_owned(),
+            fetch,
+        )
+        .map_err(Into::into)
+    }
+
+    /// Delete many messages of this channel.
+    ///
+    /// Messages are bulk deleted in groups of up to 100. Messages that are older than 14 days
+    /// cannot be bulk deleted and are deleted one by one instead, just like a group with a single
+    /// message.
+    pub async fn bulk_delete(
+        &self,
+        message_ids: impl IntoIterator<Item = impl AsRef<str>>,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let max_age = Duration::from_secs(BULK_DELETE_MAX_AGE_DAYS * 24 * 60 * 60);
+        let cutoff = Timestamp::now() - (max_age - BULK_DELETE_AGE_MARGIN);
+
+        let mut seen = HashSet::new();
+        let (recent, old): (Vec<String>, Vec<String>) = message_ids
+            .into_iter()
+            .map(|id| id.as_ref().to_owned())
+            .filter(|id| seen.insert(id.clone()))
+            .partition(|id| Timestamp::from_snowflake(id).is_some_and(|created| created > cutoff));
+
+        for chunk in recent.chunks(MAX_BULK_DELETE_MESSAGES) {
+            if let [message_id] = chunk {
+                self.rest
+                    .delete_message(self.id(), message_id, reason)
+                    .await?;
+            } else {
+                let messages = BulkDeleteMessagesApiType::new(chunk);
+                self.rest
+                    .bulk_delete_messages(self.id(), &messages, reason)
+                    .await?;
+            }
+        }
+        for message_id in &old {
+            self.rest
+                .delete_message(self.id(), message_id, reason)
+                .await?;
+        }
         Ok(())
     }
 }
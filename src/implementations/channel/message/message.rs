@@ -1,7 +1,15 @@
-use disruption_types::channel::{MessageApiType, MessageReferenceApiType};
+use disruption_types::channel::{
+    CreateMessageApiType, EditMessageApiType, MessageApiType, ReactionType, ReactionTypeApiType,
+};
+use disruption_types::entities::UserApiType;
+use futures::{Stream, TryStreamExt};
 
+use crate::pagination::{paginate, Cursor, Pagination};
 use crate::{implementations::channel::Channel, internal::RestClient, Result};
 
+/// Maximum number of users per request of the reactions of a message
+const REACTIONS_PAGE_SIZE: usize = 100;
+
 /// Struct representing a message send in a Discord channel.
 #[derive(Debug, Clone)]
 pub struct Message {
@@ -20,6 +28,20 @@ impl Message {
         }
     }
 
+    /// Create a message of a channel that was already fetched.
+    pub(crate) fn in_channel(rest: RestClient, msg: MessageApiType, channel: &Channel) -> Self {
+        Message {
+            rest,
+            msg,
+            channel: Some(channel.clone()),
+        }
+    }
+
+    /// The ID of this message.
+    pub fn id(&self) -> &str {
+        self.msg.id.as_str()
+    }
+
     /// Get the content of the message.
     pub fn content(&self) -> &str {
         self.msg.content.as_str()
@@ -41,17 +63,142 @@ impl Message {
             None => (),
             Some(channel) => {
                 channel
-                    .send(MessageApiType {
-                        content: content.to_owned(),
-                        message_reference: Some(MessageReferenceApiType {
-                            message_id: Some(self.msg.id.clone()),
-                            ..Default::default()
-                        }),
-                        ..Default::default()
-                    })
+                    .send(CreateMessageApiType::new(content).reply_to(&self.msg.id))
                     .await?;
             }
         }
         Ok(())
     }
+
+    /// Edit this message, which must have been sent by the bot to change anything but its flags.
+    ///
+    /// The message is replaced with the edited version returned by Discord.
+    pub async fn edit(&mut self, message: impl Into<EditMessageApiType>) -> Result<()> {
+        let message = message.into();
+        self.msg = self
+            .rest
+            .edit_message(&self.msg.channel_id, &self.msg.id, &message)
+            .await?;
+        Ok(())
+    }
+
+    /// Delete this message.
+    pub async fn delete(&self, reason: Option<&str>) -> Result<()> {
+        self.rest
+            .delete_message(&self.msg.channel_id, &self.msg.id, reason)
+            .await?;
+        Ok(())
+    }
+
+    /// Publish this message of an announcement channel to the channels following it.
+    pub async fn crosspost(&mut self) -> Result<()> {
+        self.msg = self
+            .rest
+            .crosspost_message(&self.msg.channel_id, &self.msg.id)
+            .await?;
+        Ok(())
+    }
+
+    /// Pin this message in its channel.
+    pub async fn pin(&self, reason: Option<&str>) -> Result<()> {
+        self.rest
+            .pin_message(&self.msg.channel_id, &self.msg.id, reason)
+            .await?;
+        Ok(())
+    }
+
+    /// Unpin this message from its channel.
+    pub async fn unpin(&self, reason: Option<&str>) -> Result<()> {
+        self.rest
+            .unpin_message(&self.msg.channel_id, &self.msg.id, reason)
+            .await?;
+        Ok(())
+    }
+
+    /// React to this message with an emoji.
+    pub async fn react(&self, emoji: impl Into<ReactionType>) -> Result<()> {
+        self.rest
+            .create_reaction(&self.msg.channel_id, &self.msg.id, &emoji.into())
+            .await?;
+        Ok(())
+    }
+
+    /// Remove the reaction of the bot.
+    pub async fn remove_own_reaction(&self, emoji: impl Into<ReactionType>) -> Result<()> {
+        self.rest
+            .delete_own_reaction(&self.msg.channel_id, &self.msg.id, &emoji.into())
+            .await?;
+        Ok(())
+    }
+
+    /// Remove the reaction of another user.
+    pub async fn remove_user_reaction(
+        &self,
+        emoji: impl Into<ReactionType>,
+        user_id: &str,
+    ) -> Result<()> {
+        self.rest
+            .delete_user_reaction(&self.msg.channel_id, &self.msg.id, &emoji.into(), user_id)
+            .await?;
+        Ok(())
+    }
+
+    /// Remove all reactions from this message.
+    pub async fn remove_all_reactions(&self) -> Result<()> {
+        self.rest
+            .delete_all_reactions(&self.msg.channel_id, &self.msg.id)
+            .await?;
+        Ok(())
+    }
+
+    /// Remove all reactions with the given emoji from this message.
+    pub async fn remove_reactions_for_emoji(&self, emoji: impl Into<ReactionType>) -> Result<()> {
+        self.rest
+            .delete_all_reactions_for_emoji(&self.msg.channel_id, &self.msg.id, &emoji.into())
+            .await?;
+        Ok(())
+    }
+
+    /// List the users that reacted with an emoji, either normally or with a burst (super)
+    /// reaction.
+    ///
+    /// Users are listed in the order of their ids, so only `Pagination::after` is used as cursor.
+    pub fn reactions(
+        &self,
+        emoji: impl Into<ReactionType>,
+        kind: ReactionTypeApiType,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<UserApiType>> {
+        let rest = self.rest.clone();
+        let channel_id = self.msg.channel_id.clone();
+        let message_id = self.msg.id.clone();
+        let emoji = emoji.into();
+        let fetch = move |cursor: Option<Cursor>, limit: usize| {
+            let rest = rest.clone();
+            let (channel_id, message_id, emoji) =
+                (channel_id.clone(), message_id.clone(), emoji.clone());
+            async move {
+                let after = match &cursor {
+                    Some(Cursor::After(id)) => Some(id.as_str()),
+                    _ => None,
+                };
+                let users = rest
+                    .get_reactions(&channel_id, &message_id, &emoji, kind, after, limit as u8)
+                    .await?;
+                Ok(users.into())
+            }
+        };
+
+        let pagination = match pagination.cursor() {
+            Some(Cursor::After(_)) => pagination,
+            _ => pagination.after(0),
+        };
+        paginate(
+            pagination,
+            REACTIONS_PAGE_SIZE,
+            |user: &UserApiType| user.id.clone(),
+            fetch,
+        )
+        .map_err(Into::into)
+    }
 }
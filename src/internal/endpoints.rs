@@ -0,0 +1,864 @@
+//! Typed wrappers around the routes of the REST API
+
+use disruption_types::channel::{
+    BulkDeleteMessagesApiType, ChannelApiType, CreateChannelApiType, CreateMessageApiType,
+    EditMessageApiType, MessageApiType, ReactionType, ReactionTypeApiType, ThreadListApiType,
+};
+use disruption_types::entities::{
+    BanApiType, BulkBanResponseApiType, BulkGuildBanApiType, CreateGuildBanApiType,
+    CreateRoleApiType, GuildApiType, GuildMemberApiType, ModifyGuildMemberApiType, RoleApiType,
+    UserApiType,
+};
+use disruption_types::interactions::InteractionResponseApiType;
+use disruption_types::resources::{AuditLogApiType, AuditLogEntryApiType};
+use disruption_types::timestamp::Timestamp;
+use disruption_types::validation::Validate;
+use futures::Stream;
+use reqwest::Response;
+use serde::de::DeserializeOwned;
+
+use crate::error::{RestError, RestResult};
+use crate::pagination::{paginate, sort_page, Cursor, Page, Pagination};
+use crate::route::Route;
+
+use super::RestClient;
+
+/// Maximum number of members or bans per request of a listing
+const MEMBERS_PAGE_SIZE: usize = 1000;
+/// Maximum number of archived threads per request of a listing
+const THREADS_PAGE_SIZE: usize = 100;
+/// Maximum number of audit log entries per request of a listing
+const AUDIT_LOG_PAGE_SIZE: usize = 100;
+
+impl RestClient {
+    /// <https://discord.com/developers/docs/resources/channel#get-channel>
+    pub async fn get_channel(&self, channel_id: &str) -> RestResult<ChannelApiType> {
+        parse(self.request(Route::GetChannel { channel_id }).await?).await
+    }
+
+    /// <https://discord.com/developers/docs/resources/channel#deleteclose-channel>
+    pub async fn delete_channel(
+        &self,
+        channel_id: &str,
+        reason: Option<&str>,
+    ) -> RestResult<ChannelApiType> {
+        let route = Route::DeleteChannel { channel_id };
+        parse(self.request_with_reason(route, reason).await?).await
+    }
+
+    /// <https://discord.com/developers/docs/resources/channel#trigger-typing-indicator>
+    pub async fn trigger_typing_indicator(&self, channel_id: &str) -> RestResult<()> {
+        self.request(Route::TriggerTypingIndicator { channel_id })
+            .await?;
+        Ok(())
+    }
+
+    /// <https://discord.com/developers/docs/resources/message#get-channel-message>
+    pub async fn get_message(
+        &self,
+        channel_id: &str,
+        message_id: &str,
+    ) -> RestResult<MessageApiType> {
+        let route = Route::GetChannelMessage {
+            channel_id,
+            message_id,
+        };
+        parse(self.request(route).await?).await
+    }
+
+    /// <https://discord.com/developers/docs/resources/message#get-channel-messages>
+    pub async fn get_channel_messages(
+        &self,
+        channel_id: &str,
+        cursor: Option<&Cursor>,
+        limit: u8,
+    ) -> RestResult<Vec<MessageApiType>> {
+        let (mut around, mut before, mut after) = (None, None, None);
+        match cursor {
+            Some(Cursor::Around(id)) => around = Some(id.as_str()),
+            Some(Cursor::Before(id)) => before = Some(id.as_str()),
+            Some(Cursor::After(id)) => after = Some(id.as_str()),
+            None => {}
+        }
+        let route = Route::GetChannelMessages {
+            channel_id,
+            around,
+            before,
+            after,
+            limit: Some(limit),
+        };
+        parse(self.request(route).await?).await
+    }
+
+    /// <https://discord.com/developers/docs/resources/message#create-message>
+    pub async fn create_message(
+        &self,
+        channel_id: &str,
+        message: &CreateMessageApiType,
+    ) -> RestResult<MessageApiType> {
+        message.validate()?;
+        let route = Route::CreateMessage { channel_id };
+        parse(self.request_json(route, message).await?).await
+    }
+
+    /// <https://discord.com/developers/docs/resources/message#edit-message>
+    pub async fn edit_message(
+        &self,
+        channel_id: &str,
+        message_id: &str,
+        message: &EditMessageApiType,
+    ) -> RestResult<MessageApiType> {
+        message.validate()?;
+        let route = Route::EditMessage {
+            channel_id,
+            message_id,
+        };
+        parse(self.request_json(route, message).await?).await
+    }
+
+    /// <https://discord.com/developers/docs/resources/message#delete-message>
+    pub async fn delete_message(
+        &self,
+        channel_id: &str,
+        message_id: &str,
+        reason: Option<&str>,
+    ) -> RestResult<()> {
+        let route = Route::DeleteMessage {
+            channel_id,
+            message_id,
+        };
+        self.request_with_reason(route, reason).await?;
+        Ok(())
+    }
+
+    /// <https://discord.com/developers/docs/resources/message#bulk-delete-messages>
+    pub async fn bulk_delete_messages(
+        &self,
+        channel_id: &str,
+        messages: &BulkDeleteMessagesApiType,
+        reason: Option<&str>,
+    ) -> RestResult<()> {
+        messages.validate()?;
+        let route = Route::BulkDeleteMessages { channel_id };
+        self.request_json_with_reason(route, messages, reason)
+            .await?;
+        Ok(())
+    }
+
+    /// <https://discord.com/developers/docs/resources/message#crosspost-message>
+    pub async fn crosspost_message(
+        &self,
+        channel_id: &str,
+        message_id: &str,
+    ) -> RestResult<MessageApiType> {
+        let route = Route::CrosspostMessage {
+            channel_id,
+            message_id,
+        };
+        parse(self.request(route).await?).await
+    }
+
+    /// <https://discord.com/developers/docs/resources/message#pin-message>
+    pub async fn pin_message(
+        &self,
+        channel_id: &str,
+        message_id: &str,
+        reason: Option<&str>,
+    ) -> RestResult<()> {
+        let route = Route::PinMessage {
+            channel_id,
+            message_id,
+        };
+        self.request_with_reason(route, reason).await?;
+        Ok(())
+    }
+
+    /// <https://discord.com/developers/docs/resources/message#unpin-message>
+    pub async fn unpin_message(
+        &self,
+        channel_id: &str,
+        message_id: &str,
+        reason: Option<&str>,
+    ) -> RestResult<()> {
+        let route = Route::UnpinMessage {
+            channel_id,
+            message_id,
+        };
+        self.request_with_reason(route, reason).await?;
+        Ok(())
+    }
+
+    /// <https://discord.com/developers/docs/resources/message#create-reaction>
+    pub async fn create_reaction(
+        &self,
+        channel_id: &str,
+        message_id: &str,
+        emoji: &ReactionType,
+    ) -> RestResult<()> {
+        let emoji = emoji.to_string();
+        let route = Route::CreateReaction {
+            channel_id,
+            message_id,
+            emoji: &emoji,
+        };
+        self.request(route).await?;
+        Ok(())
+    }
+
+    /// <https://discord.com/developers/docs/resources/message#delete-own-reaction>
+    pub async fn delete_own_reaction(
+        &self,
+        channel_id: &str,
+        message_id: &str,
+        emoji: &ReactionType,
+    ) -> RestResult<()> {
+        let emoji = emoji.to_string();
+        let route = Route::DeleteOwnReaction {
+            channel_id,
+            message_id,
+            emoji: &emoji,
+        };
+        self.request(route).await?;
+        Ok(())
+    }
+
+    /// <https://discord.com/developers/docs/resources/message#delete-user-reaction>
+    pub async fn delete_user_reaction(
+        &self,
+        channel_id: &str,
+        message_id: &str,
+        emoji: &ReactionType,
+        user_id: &str,
+    ) -> RestResult<()> {
+        let emoji = emoji.to_string();
+        let route = Route::DeleteUserReaction {
+            channel_id,
+            message_id,
+            emoji: &emoji,
+            user_id,
+        };
+        self.request(route).await?;
+        Ok(())
+    }
+
+    /// <https://discord.com/developers/docs/resources/message#get-reactions>
+    pub async fn get_reactions(
+        &self,
+        channel_id: &str,
+        message_id: &str,
+        emoji: &ReactionType,
+        kind: ReactionTypeApiType,
+        after: Option<&str>,
+        limit: u8,
+    ) -> RestResult<Vec<UserApiType>> {
+        let emoji = emoji.to_string();
+        let route = Route::GetReactions {
+            channel_id,
+            message_id,
+            emoji: &emoji,
+            type_: Some(kind.into()),
+            after,
+            limit: Some(limit),
+        };
+        parse(self.request(route).await?).await
+    }
+
+    /// <https://discord.com/developers/docs/resources/message#delete-all-reactions>
+    pub async fn delete_all_reactions(&self, channel_id: &str, message_id: &str) -> RestResult<()> {
+        let route = Route::DeleteAllReactions {
+            channel_id,
+            message_id,
+        };
+        self.request(route).await?;
+        Ok(())
+    }
+
+    /// <https://discord.com/developers/docs/resources/message#delete-all-reactions-for-emoji>
+    pub async fn delete_all_reactions_for_emoji(
+        &self,
+        channel_id: &str,
+        message_id: &str,
+        emoji: &ReactionType,
+    ) -> RestResult<()> {
+        let emoji = emoji.to_string();
+        let route = Route::DeleteAllReactionsForEmoji {
+            channel_id,
+            message_id,
+            emoji: &emoji,
+        };
+        self.request(route).await?;
+        Ok(())
+    }
+
+    /// <https://discord.com/developers/docs/resources/channel#list-public-archived-threads>
+    pub async fn list_public_archived_threads(
+        &self,
+        channel_id: &str,
+        before: Option<&str>,
+        limit: u8,
+    ) -> RestResult<ThreadListApiType> {
+        let route = Route::ListPublicArchivedThreads {
+            channel_id,
+            before,
+            limit: Some(limit),
+        };
+        parse(self.request(route).await?).await
+    }
+
+    /// <https://discord.com/developers/docs/resources/channel#list-private-archived-threads>
+    pub async fn list_private_archived_threads(
+        &self,
+        channel_id: &str,
+        before: Option<&str>,
+        limit: u8,
+    ) -> RestResult<ThreadListApiType> {
+        let route = Route::ListPrivateArchivedThreads {
+            channel_id,
+            before,
+            limit: Some(limit),
+        };
+        parse(self.request(route).await?).await
+    }
+
+    /// List the public archived threads of a channel, most recently archived first.
+    ///
+    /// Archived threads are paged by their archive timestamp, so `Pagination::before` takes an
+    /// ISO8601 timestamp and `Pagination::until_time` stops at an archive time.
+    pub fn public_archived_threads(
+        &self,
+        channel_id: &str,
+        pagination: Pagination,
+    ) -> impl Stream<Item = RestResult<ChannelApiType>> {
+        self.archived_threads(channel_id, false, pagination)
+    }
+
+    /// List the private archived threads of a channel, most recently archived first.
+    ///
+    /// Paged like [`RestClient::public_archived_threads`].
+    pub fn private_archived_threads(
+        &self,
+        channel_id: &str,
+        pagination: Pagination,
+    ) -> impl Stream<Item = RestResult<ChannelApiType>> {
+        self.archived_threads(channel_id, true, pagination)
+    }
+
+    fn archived_threads(
+        &self,
+        channel_id: &str,
+        private: bool,
+        pagination: Pagination,
+    ) -> impl Stream<Item = RestResult<ChannelApiType>> {
+        let rest = self.clone();
+        let channel_id = channel_id.to_owned();
+        let fetch = move |cursor: Option<Cursor>, limit: usize| {
+            let (rest, channel_id) = (rest.clone(), channel_id.clone());
+            async move {
+                let before = match &cursor {
+                    Some(Cursor::Before(timestamp)) => Some(timestamp.as_str()),
+                    None => None,
+                    Some(Cursor::After(_) | Cursor::Around(_)) => {
+                        return Err(RestError::InvalidRequest(
+                            "archived threads can only be paged backwards".to_owned(),
+                        ))
+                    }
+                };
+                let list = match private {
+                    true => {
+                        rest.list_private_archived_threads(&channel_id, before, limit as u8)
+                            .await?
+                    }
+                    false => {
+                        rest.list_public_archived_threads(&channel_id, before, limit as u8)
+                            .await?
+                    }
+                };
+                Ok(Page {
+                    items: list.threads,
+                    has_more: Some(list.has_more),
+                })
+            }
+        };
+
+        paginate(pagination, THREADS_PAGE_SIZE, archive_timestamp, fetch)
+    }
+
+    /// <https://discord.com/developers/docs/resources/audit-log#get-guild-audit-log>
+    pub async fn get_guild_audit_log(
+        &self,
+        guild_id: &str,
+        cursor: Option<&Cursor>,
+        limit: u8,
+    ) -> RestResult<AuditLogApiType> {
+        let (mut before, mut after) = (None, None);
+        match cursor {
+            Some(Cursor::Before(id)) => before = Some(id.as_str()),
+            Some(Cursor::After(id)) => after = Some(id.as_str()),
+            Some(Cursor::Around(_)) | None => {}
+        }
+        let route = Route::GetGuildAuditLog {
+            guild_id,
+            user_id: None,
+            action_type: None,
+            before,
+            after,
+            limit: Some(limit),
+        };
+        parse(self.request(route).await?).await
+    }
+
+    /// List the audit log entries of a guild, newest first.
+    ///
+    /// Paging forwards with `Pagination::after` lists the oldest entries first. The users,
+    /// threads and other objects referenced by the entries are not part of the stream, use
+    /// [`RestClient::get_guild_audit_log`] to get them.
+    pub fn guild_audit_log_entries(
+        &self,
+        guild_id: &str,
+        pagination: Pagination,
+    ) -> impl Stream<Item = RestResult<AuditLogEntryApiType>> {
+        let rest = self.clone();
+        let guild_id = guild_id.to_owned();
+        let fetch = move |cursor: Option<Cursor>, limit: usize| {
+            let (rest, guild_id) = (rest.clone(), guild_id.clone());
+            async move {
+                if let Some(Cursor::Around(_)) = cursor {
+                    return Err(RestError::InvalidRequest(
+                        "audit logs can not be paged around an entry".to_owned(),
+                    ));
+                }
+                let mut entries = rest
+                    .get_guild_audit_log(&guild_id, cursor.as_ref(), limit as u8)
+                    .await?
+                    .audit_log_entries;
+                let forwards = matches!(cursor, Some(Cursor::After(_)));
+                sort_page(&mut entries, |entry| entry.id.as_str(), forwards);
+                Ok(entries.into())
+            }
+        };
+
+        paginate(
+            pagination,
+            AUDIT_LOG_PAGE_SIZE,
+            |entry: &AuditLogEntryApiType| entry.id.clone(),
+            fetch,
+        )
+    }
+
+    /// <https://discord.com/developers/docs/resources/guild#get-guild>
+    pub async fn get_guild(&self, guild_id: &str) -> RestResult<GuildApiType> {
+        let route = Route::GetGuild {
+            guild_id,
+            with_counts: None,
+        };
+        parse(self.request(route).await?).await
+    }
+
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-channels>
+    pub async fn get_guild_channels(&self, guild_id: &str) -> RestResult<Vec<ChannelApiType>> {
+        parse(self.request(Route::GetGuildChannels { guild_id }).await?).await
+    }
+
+    /// <https://discord.com/developers/docs/resources/guild#create-guild-channel>
+    pub async fn create_guild_channel(
+        &self,
+        guild_id: &str,
+        channel: &CreateChannelApiType,
+        reason: Option<&str>,
+    ) -> RestResult<ChannelApiType> {
+        channel.validate()?;
+        let route = Route::CreateGuildChannel { guild_id };
+        parse(
+            self.request_json_with_reason(route, channel, reason)
+                .await?,
+        )
+        .await
+    }
+
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-member>
+    pub async fn get_guild_member(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+    ) -> RestResult<GuildMemberApiType> {
+        parse(
+            self.request(Route::GetGuildMember { guild_id, user_id })
+                .await?,
+        )
+        .await
+    }
+
+    /// <https://discord.com/developers/docs/resources/guild#list-guild-members>
+    pub async fn list_guild_members(
+        &self,
+        guild_id: &str,
+        after: Option<&str>,
+        limit: u16,
+    ) -> RestResult<Vec<GuildMemberApiType>> {
+        let route = Route::ListGuildMembers {
+            guild_id,
+            limit: Some(limit),
+            after,
+        };
+        parse(self.request(route).await?).await
+    }
+
+    /// List all members of a guild, which requires the `GUILD_MEMBERS` intent.
+    ///
+    /// Members are listed in the order of their user ids, so only `Pagination::after` is supported
+    /// as cursor. Other cursors fail with [`RestError::InvalidRequest`].
+    pub fn guild_members(
+        &self,
+        guild_id: &str,
+        pagination: Pagination,
+    ) -> impl Stream<Item = RestResult<GuildMemberApiType>> {
+        let rest = self.clone();
+        let guild_id = guild_id.to_owned();
+        let fetch = move |cursor: Option<Cursor>, limit: usize| {
+            let (rest, guild_id) = (rest.clone(), guild_id.clone());
+            async move {
+                let Some(Cursor::After(after)) = &cursor else {
+                    return Err(RestError::InvalidRequest(
+                        "guild members can only be paged forwards".to_owned(),
+                    ));
+                };
+                let members = rest
+                    .list_guild_members(&guild_id, Some(after), limit as u16)
+                    .await?;
+                Ok(members.into())
+            }
+        };
+
+        let pagination = match pagination.cursor() {
+            Some(_) => pagination,
+            None => pagination.after(0),
+        };
+        paginate(pagination, MEMBERS_PAGE_SIZE, member_id, fetch)
+    }
+
+    /// <https://discord.com/developers/docs/resources/guild#search-guild-members>
+    pub async fn search_guild_members(
+        &self,
+        guild_id: &str,
+        query: &str,
+        limit: u16,
+    ) -> RestResult<Vec<GuildMemberApiType>> {
+        let route = Route::SearchGuildMembers {
+            guild_id,
+            query: Some(query),
+            limit: Some(limit),
+        };
+        parse(self.request(route).await?).await
+    }
+
+    /// <https://discord.com/developers/docs/resources/guild#modify-guild-member>
+    pub async fn modify_guild_member(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        member: &ModifyGuildMemberApiType,
+        reason: Option<&str>,
+    ) -> RestResult<GuildMemberApiType> {
+        member.validate()?;
+        let route = Route::ModifyGuildMember { guild_id, user_id };
+        let response = self
+            .request_json_with_reason(route, member, reason)
+            .await
+            .map_err(|error| match modify_member_permissions(member) {
+                Some(permissions) => require_permissions(error, &permissions),
+                None => error,
+            })?;
+        parse(response).await
+    }
+
+    /// Time out a member until the given time, or remove the timeout with `None`.
+    pub async fn timeout_guild_member(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        until: Option<Timestamp>,
+        reason: Option<&str>,
+    ) -> RestResult<GuildMemberApiType> {
+        let member = ModifyGuildMemberApiType::timeout(until);
+        self.modify_guild_member(guild_id, user_id, &member, reason)
+            .await
+    }
+
+    /// <https://discord.com/developers/docs/resources/guild#add-guild-member-role>
+    pub async fn add_guild_member_role(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        role_id: &str,
+        reason: Option<&str>,
+    ) -> RestResult<()> {
+        let route = Route::AddGuildMemberRole {
+            guild_id,
+            user_id,
+            role_id,
+        };
+        self.request_with_reason(route, reason)
+            .await
+            .map_err(|error| require_permissions(error, "MANAGE_ROLES"))?;
+        Ok(())
+    }
+
+    /// <https://discord.com/developers/docs/resources/guild#remove-guild-member-role>
+    pub async fn remove_guild_member_role(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        role_id: &str,
+        reason: Option<&str>,
+    ) -> RestResult<()> {
+        let route = Route::RemoveGuildMemberRole {
+            guild_id,
+            user_id,
+            role_id,
+        };
+        self.request_with_reason(route, reason)
+            .await
+            .map_err(|error| require_permissions(error, "MANAGE_ROLES"))?;
+        Ok(())
+    }
+
+    /// Kick a member from a guild.
+    ///
+    /// <https://discord.com/developers/docs/resources/guild#remove-guild-member>
+    pub async fn remove_guild_member(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        reason: Option<&str>,
+    ) -> RestResult<()> {
+        let route = Route::RemoveGuildMember { guild_id, user_id };
+        self.request_with_reason(route, reason)
+            .await
+            .map_err(|error| require_permissions(error, "KICK_MEMBERS"))?;
+        Ok(())
+    }
+
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-bans>
+    pub async fn get_guild_bans(
+        &self,
+        guild_id: &str,
+        cursor: Option<&Cursor>,
+        limit: u16,
+    ) -> RestResult<Vec<BanApiType>> {
+        let (mut before, mut after) = (None, None);
+        match cursor {
+            Some(Cursor::Before(id)) => before = Some(id.as_str()),
+            Some(Cursor::After(id)) => after = Some(id.as_str()),
+            Some(Cursor::Around(_)) | None => {}
+        }
+        let route = Route::GetGuildBans {
+            guild_id,
+            limit: Some(limit),
+            before,
+            after,
+        };
+        let response = self
+            .request(route)
+            .await
+            .map_err(|error| require_permissions(error, "BAN_MEMBERS"))?;
+        parse(response).await
+    }
+
+    /// List the bans of a guild, in the order of the user ids of the banned users.
+    ///
+    /// Paging backwards with `Pagination::before` lists the highest user ids first.
+    pub fn guild_bans(
+        &self,
+        guild_id: &str,
+        pagination: Pagination,
+    ) -> impl Stream<Item = RestResult<BanApiType>> {
+        let rest = self.clone();
+        let guild_id = guild_id.to_owned();
+        let fetch = move |cursor: Option<Cursor>, limit: usize| {
+            let (rest, guild_id) = (rest.clone(), guild_id.clone());
+            async move {
+                let mut bans = rest
+                    .get_guild_bans(&guild_id, cursor.as_ref(), limit as u16)
+                    .await?;
+                let forwards = !matches!(cursor, Some(Cursor::Before(_)));
+                sort_page(&mut bans, |ban| ban.user.id.as_str(), forwards);
+                Ok(bans.into())
+            }
+        };
+
+        let pagination = match pagination.cursor() {
+            Some(Cursor::Before(_) | Cursor::After(_)) => pagination,
+            _ => pagination.after(0),
+        };
+        paginate(
+            pagination,
+            MEMBERS_PAGE_SIZE,
+            |ban: &BanApiType| ban.user.id.clone(),
+            fetch,
+        )
+    }
+
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-ban>
+    pub async fn get_guild_ban(&self, guild_id: &str, user_id: &str) -> RestResult<BanApiType> {
+        parse(
+            self.request(Route::GetGuildBan { guild_id, user_id })
+                .await?,
+        )
+        .await
+    }
+
+    /// <https://discord.com/developers/docs/resources/guild#create-guild-ban>
+    pub async fn create_guild_ban(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        ban: &CreateGuildBanApiType,
+        reason: Option<&str>,
+    ) -> RestResult<()> {
+        ban.validate()?;
+        let route = Route::CreateGuildBan { guild_id, user_id };
+        self.request_json_with_reason(route, ban, reason)
+            .await
+            .map_err(|error| require_permissions(error, "BAN_MEMBERS"))?;
+        Ok(())
+    }
+
+    /// <https://discord.com/developers/docs/resources/guild#remove-guild-ban>
+    pub async fn remove_guild_ban(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        reason: Option<&str>,
+    ) -> RestResult<()> {
+        let route = Route::RemoveGuildBan { guild_id, user_id };
+        self.request_with_reason(route, reason)
+            .await
+            .map_err(|error| require_permissions(error, "BAN_MEMBERS"))?;
+        Ok(())
+    }
+
+    /// <https://discord.com/developers/docs/resources/guild#bulk-guild-ban>
+    pub async fn bulk_guild_ban(
+        &self,
+        guild_id: &str,
+        bans: &BulkGuildBanApiType,
+        reason: Option<&str>,
+    ) -> RestResult<BulkBanResponseApiType> {
+        bans.validate()?;
+        let route = Route::BulkGuildBan { guild_id };
+        let response = self
+            .request_json_with_reason(route, bans, reason)
+            .await
+            .map_err(|error| require_permissions(error, "BAN_MEMBERS, MANAGE_GUILD"))?;
+        parse(response).await
+    }
+
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-roles>
+    pub async fn get_guild_roles(&self, guild_id: &str) -> RestResult<Vec<RoleApiType>> {
+        parse(self.request(Route::GetGuildRoles { guild_id }).await?).await
+    }
+
+    /// <https://discord.com/developers/docs/resources/guild#create-guild-role>
+    pub async fn create_guild_role(
+        &self,
+        guild_id: &str,
+        role: &CreateRoleApiType,
+        reason: Option<&str>,
+    ) -> RestResult<RoleApiType> {
+        role.validate()?;
+        let route = Route::CreateGuildRole { guild_id };
+        parse(self.request_json_with_reason(route, role, reason).await?).await
+    }
+
+    /// <https://discord.com/developers/docs/resources/guild#delete-guild-role>
+    pub async fn delete_guild_role(
+        &self,
+        guild_id: &str,
+        role_id: &str,
+        reason: Option<&str>,
+    ) -> RestResult<()> {
+        let route = Route::DeleteGuildRole { guild_id, role_id };
+        self.request_with_reason(route, reason).await?;
+        Ok(())
+    }
+
+    /// <https://discord.com/developers/docs/resources/user#get-current-user>
+    pub async fn get_current_user(&self) -> RestResult<UserApiType> {
+        parse(self.request(Route::GetCurrentUser {}).await?).await
+    }
+
+    /// <https://discord.com/developers/docs/resources/user#get-user>
+    pub async fn get_user(&self, user_id: &str) -> RestResult<UserApiType> {
+        parse(self.request(Route::GetUser { user_id }).await?).await
+    }
+
+    /// <https://discord.com/developers/docs/interactions/receiving-and-responding#create-interaction-response>
+    pub async fn create_interaction_response(
+        &self,
+        interaction_id: &str,
+        interaction_token: &str,
+        response: &InteractionResponseApiType,
+    ) -> RestResult<()> {
+        response.validate()?;
+        let route = Route::CreateInteractionResponse {
+            interaction_id,
+            interaction_token,
+            with_response: None,
+        };
+        self.request_json(route, response).await?;
+        Ok(())
+    }
+}
+
+/// Name the permissions a moderation action needs when Discord rejects it as missing permission.
+fn require_permissions(error: RestError, permissions: &str) -> RestError {
+    match error {
+        RestError::MissingPermission { message, error, .. } => RestError::MissingPermission {
+            message,
+            error,
+            required: Some(permissions.to_owned()),
+        },
+        error => error,
+    }
+}
+
+/// Permissions that are needed to change the fields of a member that are set, if any.
+fn modify_member_permissions(member: &ModifyGuildMemberApiType) -> Option<String> {
+    let permissions: Vec<&str> = [
+        (member.nick.is_absent(), "MANAGE_NICKNAMES"),
+        (member.roles.is_absent(), "MANAGE_ROLES"),
+        (member.mute.is_absent(), "MUTE_MEMBERS"),
+        (member.deaf.is_absent(), "DEAFEN_MEMBERS"),
+        (member.channel_id.is_absent(), "MOVE_MEMBERS"),
+        (
+            member.communication_disabled_until.is_absent(),
+            "MODERATE_MEMBERS",
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(absent, permission)| (!absent).then_some(permission))
+    .collect();
+    (!permissions.is_empty()).then(|| permissions.join(", "))
+}
+
+/// Cursor value of an archived thread, threads without metadata fall back to their id.
+fn archive_timestamp(thread: &ChannelApiType) -> String {
+    match &thread.thread_metadata {
+        Some(metadata) => metadata.archive_timestamp.to_string(),
+        None => thread.id.clone(),
+    }
+}
+
+fn member_id(member: &GuildMemberApiType) -> String {
+    member
+        .user
+        .as_ref()
+        .map(|user| user.id.clone())
+        .unwrap_or_default()
+}
+
+/// Deserialize the JSON body of a response.
+async fn parse<T: DeserializeOwned>(response: Response) -> RestResult<T> {
+    let body = response.bytes().await?;
+    serde_json::from_slice(&body).map_err(|error| RestError::ResponseParseError(error.to_string()))
+}
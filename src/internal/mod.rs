@@ -1,62 +1,293 @@
+mod endpoints;
+mod ratelimit;
+
 use core::fmt::Debug;
-use reqwest::Response;
+use std::time::Duration;
+
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE, USER_AGENT};
+use reqwest::{Method, Response};
 use serde::Serialize;
 
-use crate::error::RestResult;
+use disruption_types::validation::validate_audit_log_reason;
+
+use crate::error::{RestError, RestResult};
+use crate::retry::{RetryEvent, RetryPolicy};
+use crate::route::{percent_encode, Route};
+
+use self::ratelimit::{RateLimiter, RouteKey};
+
+/// Number of times a request is retried after being rate limited.
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
 
+/// Number of the API version that is used by default.
+const DEFAULT_API_VERSION: u8 = 10;
+const DEFAULT_BASE_URL: &str = "https://discord.com/api";
+/// Header with the reason of a change that is shown in the audit log.
+const AUDIT_LOG_REASON: &str = "X-Audit-Log-Reason";
+
+/// Client for the Discord REST API. (basically a wrapper around reqwest)
+///
+/// The client can be used without a gateway connection; clones share their rate limits.
 #[derive(Clone)]
 pub struct RestClient {
     bot_token: String,
-    base_url: String,
+    api_url: String,
+    user_agent: String,
+    timeout: Option<Duration>,
     client: reqwest::Client,
+    ratelimiter: RateLimiter,
+    retry_policy: RetryPolicy,
 }
 
-/// Client for the Discord REST API. (basically a wrapper around reqwest)
 impl RestClient {
-    pub fn new(bot_token: &str, api_version: u8) -> Self {
-        Self {
-            base_url: format!("https://discord.com/api/v{api_version}/"),
-            bot_token: bot_token.to_owned(),
-            client: reqwest::Client::new(),
-        }
+    /// Create a client for the default API version with the given bot token.
+    pub fn new(bot_token: impl ToString) -> Self {
+        Self::builder(bot_token).build()
+    }
+
+    /// Configure a client with the given bot token.
+    pub fn builder(bot_token: impl ToString) -> RestClientBuilder {
+        RestClientBuilder::new(bot_token)
+    }
+
+    /// Use the given policy to retry failed requests.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Send a request without a body to the given route.
+    pub async fn request(&self, route: Route<'_>) -> RestResult<Response> {
+        self.request_with_reason(route, None).await
     }
 
-    /// Send a POST to the API.
-    pub async fn post<T: Serialize + ?Sized>(
+    /// Send a request without a body to the given route, recording the reason in the audit log.
+    pub async fn request_with_reason(
         &self,
-        uri: &String,
-        content: &T,
+        route: Route<'_>,
+        reason: Option<&str>,
     ) -> RestResult<Response> {
-        self.client
-            .post(format!("{}/{}", self.base_url, uri))
-            .header("Authorization", format!("Bot {}", self.bot_token))
-            .header(
-                "User-Agent",
-                "DiscordBot (https://github.com/H1ghBre4k3r/disruption, 0.1.0)",
-            )
-            .json(content)
-            .send()
+        let idempotent = route.method() != Method::POST;
+        self.send_with_retries(route, None, reason, idempotent)
             .await
-            .map_err(Into::into)
-    }
-
-    /// Issue a GET request to the API.
-    pub async fn get(&self, uri: &String) -> RestResult<Response> {
-        self.client
-            .get(format!("{}/{}", self.base_url, uri))
-            .header("Authorization", format!("Bot {}", self.bot_token))
-            .header(
-                "User-Agent",
-                "DiscordBot (https://github.com/H1ghBre4k3r/disruption, 0.1.0)",
-            )
-            .send()
+    }
+
+    /// Send a request with a JSON body to the given route.
+    pub async fn request_json<T: Serialize + ?Sized>(
+        &self,
+        route: Route<'_>,
+        content: &T,
+    ) -> RestResult<Response> {
+        self.request_json_with_reason(route, content, None).await
+    }
+
+    /// Send a request with a JSON body to the given route, recording the reason in the audit log.
+    pub async fn request_json_with_reason<T: Serialize + ?Sized>(
+        &self,
+        route: Route<'_>,
+        content: &T,
+        reason: Option<&str>,
+    ) -> RestResult<Response> {
+        let body = serde_json::to_value(content)?;
+        // with an enforced nonce, Discord discards duplicates of a request that was processed
+        let idempotent = route.method() != Method::POST
+            || (body["enforce_nonce"] == true && !body["nonce"].is_null());
+        let body = serde_json::to_vec(&body)?;
+        self.send_with_retries(route, Some(body), reason, idempotent)
             .await
-            .map_err(Into::into)
+    }
+
+    /// Send a request, retrying failed attempts according to the retry policy.
+    async fn send_with_retries(
+        &self,
+        route: Route<'_>,
+        body: Option<Vec<u8>>,
+        reason: Option<&str>,
+        idempotent: bool,
+    ) -> RestResult<Response> {
+        let reason = match reason {
+            Some(reason) if route.supports_audit_log_reason() => {
+                validate_audit_log_reason(reason)?;
+                Some(percent_encode(reason))
+            }
+            Some(_) => {
+                log::warn!("{} does not support an audit log reason", route.name());
+                None
+            }
+            None => None,
+        };
+        let method = route.method();
+        let path = route.path();
+        let mut attempt = 1;
+        loop {
+            let error = match self
+                .send(&route, &path, body.as_deref(), reason.as_deref())
+                .await
+            {
+                Ok(response) => return Ok(response),
+                Err(error) => error,
+            };
+            let Some(delay) = self.retry_policy.retry_delay(attempt, &error, idempotent) else {
+                return Err(error);
+            };
+
+            self.retry_policy.notify(&RetryEvent {
+                method: method.as_str(),
+                path: &path,
+                attempt,
+                error: &error,
+                delay,
+            });
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Send a request once its rate limit bucket allows it, retrying when Discord responds with
+    /// a 429.
+    async fn send(
+        &self,
+        route: &Route<'_>,
+        path: &str,
+        body: Option<&[u8]>,
+        reason: Option<&str>,
+    ) -> RestResult<Response> {
+        let key = RouteKey::new(route);
+        let mut retries = 0;
+
+        loop {
+            let ticket = self.ratelimiter.acquire(&key).await;
+
+            let mut request = self
+                .client
+                .request(route.method(), format!("{}/{}", self.api_url, path))
+                .header(USER_AGENT, &self.user_agent);
+            // interaction endpoints are authorized by the token in their path
+            if !route.is_interaction() {
+                request = request.header(AUTHORIZATION, format!("Bot {}", self.bot_token));
+            }
+            if let Some(reason) = reason {
+                request = request.header(AUDIT_LOG_REASON, reason);
+            }
+            if let Some(timeout) = self.timeout {
+                request = request.timeout(timeout);
+            }
+            if let Some(body) = body {
+                request = request
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(body.to_vec());
+            }
+            let response = request.send().await?;
+
+            match ticket.complete(response.status(), response.headers()).await {
+                None if response.status().is_success() => return Ok(response),
+                None => {
+                    let status = response.status().as_u16();
+                    let body = response.bytes().await?;
+                    return Err(RestError::from_response(status, path, &body));
+                }
+                Some(_) if retries < MAX_RATE_LIMIT_RETRIES => retries += 1,
+                Some(retry_after) => {
+                    return Err(RestError::RateLimited {
+                        retry_after: retry_after.as_secs_f64().ceil() as u64,
+                    })
+                }
+            }
+        }
     }
 }
 
 impl Debug for RestClient {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(&format!("RestClient {{ base_url: '{}' }}", self.base_url))
+        f.write_str(&format!("RestClient {{ api_url: '{}' }}", self.api_url))
+    }
+}
+
+/// Builder for a [`RestClient`]
+#[derive(Debug)]
+pub struct RestClientBuilder {
+    bot_token: String,
+    base_url: String,
+    api_version: u8,
+    timeout: Option<Duration>,
+    user_agent_suffix: Option<String>,
+    client: Option<reqwest::Client>,
+    retry_policy: RetryPolicy,
+}
+
+impl RestClientBuilder {
+    fn new(bot_token: impl ToString) -> Self {
+        RestClientBuilder {
+            bot_token: bot_token.to_string(),
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            api_version: DEFAULT_API_VERSION,
+            timeout: None,
+            user_agent_suffix: None,
+            client: None,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// URL of the API without the version, e.g. to send requests to a proxy or a mock server.
+    pub fn base_url(mut self, base_url: impl ToString) -> Self {
+        self.base_url = base_url.to_string();
+        self
+    }
+
+    /// Version of the API to use.
+    pub fn api_version(mut self, api_version: u8) -> Self {
+        self.api_version = api_version;
+        self
+    }
+
+    /// Maximum duration of a single request, including reading the response.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Text appended to the `User-Agent` header, e.g. the name and version of the bot.
+    pub fn user_agent_suffix(mut self, suffix: impl ToString) -> Self {
+        self.user_agent_suffix = Some(suffix.to_string());
+        self
+    }
+
+    /// Send requests with the given client, e.g. to share its connection pool.
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Use the given policy to retry failed requests.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Create the client.
+    pub fn build(self) -> RestClient {
+        let mut user_agent = format!(
+            "DiscordBot ({}, {})",
+            env!("CARGO_PKG_REPOSITORY"),
+            env!("CARGO_PKG_VERSION")
+        );
+        if let Some(suffix) = &self.user_agent_suffix {
+            user_agent.push(' ');
+            user_agent.push_str(suffix);
+        }
+
+        RestClient {
+            bot_token: self.bot_token,
+            api_url: format!(
+                "{}/v{}",
+                self.base_url.trim_end_matches('/'),
+                self.api_version
+            ),
+            user_agent,
+            timeout: self.timeout,
+            client: self.client.unwrap_or_default(),
+            ratelimiter: RateLimiter::new(),
+            retry_policy: self.retry_policy,
+        }
     }
 }
@@ -0,0 +1,402 @@
+//! Rate limiting of REST requests.
+//!
+//! <https://discord.com/developers/docs/topics/rate-limits>
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+use tokio::time::{sleep_until, Instant};
+
+use crate::route::Route;
+
+/// Number of requests a bot may send per second across all routes.
+const GLOBAL_LIMIT: u32 = 50;
+const GLOBAL_WINDOW: Duration = Duration::from_secs(1);
+/// Wait time for a 429 response that does not say how long to wait.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// Identifies the rate limit of a request: the endpoint and its major parameter.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct RouteKey {
+    /// name of the endpoint, e.g. `CreateMessage`
+    route: &'static str,
+    /// the channel, guild or webhook the request is made for
+    major: String,
+    /// whether the request counts towards the global rate limit
+    global: bool,
+}
+
+impl RouteKey {
+    /// Derive the key of a request to the given route.
+    pub(crate) fn new(route: &Route<'_>) -> Self {
+        RouteKey {
+            route: route.name(),
+            major: route.major_parameter(),
+            // interaction endpoints are not bound to the global rate limit
+            global: !route.is_interaction(),
+        }
+    }
+}
+
+/// State of a single bucket, shared by all requests that are queued for it.
+#[derive(Debug, Default)]
+struct BucketState {
+    /// requests left until the bucket resets, if known
+    remaining: Option<u64>,
+    /// when the bucket resets
+    reset_at: Option<Instant>,
+}
+
+#[derive(Debug)]
+struct GlobalState {
+    /// set after a global 429, no request may be sent before this
+    blocked_until: Option<Instant>,
+    window_start: Instant,
+    count: u32,
+}
+
+#[derive(Debug)]
+struct Inner {
+    /// bucket hashes that were discovered for endpoints
+    routes: Mutex<HashMap<&'static str, String>>,
+    /// queues of requests, keyed by bucket and major parameter
+    buckets: Mutex<HashMap<String, Arc<AsyncMutex<BucketState>>>>,
+    global: AsyncMutex<GlobalState>,
+}
+
+/// Queues requests per bucket and waits for the limits reported by Discord.
+///
+/// Requests of a bucket are sent one after another, so the `X-RateLimit-Remaining` of one
+/// response is known before the next request is sent. Buckets of different routes or major
+/// parameters do not block each other.
+#[derive(Debug, Clone)]
+pub(crate) struct RateLimiter {
+    inner: Arc<Inner>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new() -> Self {
+        RateLimiter {
+            inner: Arc::new(Inner {
+                routes: Mutex::default(),
+                buckets: Mutex::default(),
+                global: AsyncMutex::new(GlobalState {
+                    blocked_until: None,
+                    window_start: Instant::now(),
+                    count: 0,
+                }),
+            }),
+        }
+    }
+
+    /// Wait until a request for the given route may be sent.
+    ///
+    /// The bucket stays locked until the returned ticket is completed with the response.
+    pub(crate) async fn acquire(&self, route: &RouteKey) -> Ticket {
+        let bucket = self.bucket(route);
+        let mut state = bucket.lock_owned().await;
+        if state.remaining == Some(0) {
+            if let Some(reset_at) = state.reset_at {
+                sleep_until(reset_at).await;
+            }
+            state.remaining = None;
+        }
+
+        if route.global {
+            self.wait_global().await;
+        }
+
+        Ticket {
+            limiter: self.clone(),
+            route: route.clone(),
+            state,
+        }
+    }
+
+    /// The queue of the bucket the route belongs to, with the route itself as bucket until its
+    /// hash is discovered.
+    fn bucket(&self, route: &RouteKey) -> Arc<AsyncMutex<BucketState>> {
+        let bucket = self
+            .inner
+            .routes
+            .lock()
+            .expect("rate limit routes poisoned")
+            .get(route.route)
+            .cloned()
+            .unwrap_or_else(|| route.route.to_owned());
+
+        self.inner
+            .buckets
+            .lock()
+            .expect("rate limit buckets poisoned")
+            .entry(format!("{bucket}:{}", route.major))
+            .or_default()
+            .clone()
+    }
+
+    async fn wait_global(&self) {
+        let mut global = self.inner.global.lock().await;
+        if let Some(blocked_until) = global.blocked_until.take() {
+            sleep_until(blocked_until).await;
+        }
+
+        if global.window_start.elapsed() >= GLOBAL_WINDOW {
+            global.window_start = Instant::now();
+            global.count = 0;
+        }
+        if global.count >= GLOBAL_LIMIT {
+            sleep_until(global.window_start + GLOBAL_WINDOW).await;
+            global.window_start = Instant::now();
+            global.count = 0;
+        }
+        global.count += 1;
+    }
+
+    /// Remember the bucket hash of a route, keeping the state of the bucket the route was queued
+    /// in so far.
+    fn discover(&self, route: &RouteKey, hash: &str, bucket: &Arc<AsyncMutex<BucketState>>) {
+        let mut routes = self
+            .inner
+            .routes
+            .lock()
+            .expect("rate limit routes poisoned");
+        if routes.get(route.route).map(String::as_str) == Some(hash) {
+            return;
+        }
+
+        log::debug!("discovered rate limit bucket {hash} for {}", route.route);
+        routes.insert(route.route, hash.to_owned());
+        self.inner
+            .buckets
+            .lock()
+            .expect("rate limit buckets poisoned")
+            .entry(format!("{hash}:{}", route.major))
+            .or_insert_with(|| bucket.clone());
+    }
+}
+
+/// Permission to send a single request, holding the lock on its bucket.
+pub(crate) struct Ticket {
+    limiter: RateLimiter,
+    route: RouteKey,
+    state: OwnedMutexGuard<BucketState>,
+}
+
+impl Ticket {
+    /// Update the bucket from the headers of the response.
+    ///
+    /// Returns how long to wait before retrying if the request was rate limited.
+    pub(crate) async fn complete(
+        mut self,
+        status: StatusCode,
+        headers: &HeaderMap,
+    ) -> Option<Duration> {
+        let now = Instant::now();
+        let reset_after = header_secs(headers, "x-ratelimit-reset-after");
+
+        if let Some(remaining) =
+            header(headers, "x-ratelimit-remaining").and_then(|v| v.parse().ok())
+        {
+            self.state.remaining = Some(remaining);
+        }
+        if let Some(reset_after) = reset_after {
+            self.state.reset_at = Some(now + reset_after);
+        }
+        if let Some(hash) = header(headers, "x-ratelimit-bucket") {
+            self.limiter
+                .discover(&self.route, hash, OwnedMutexGuard::mutex(&self.state));
+        }
+
+        if status != StatusCode::TOO_MANY_REQUESTS {
+            return None;
+        }
+
+        let retry_after = header_secs(headers, "retry-after")
+            .or(reset_after)
+            .unwrap_or(DEFAULT_RETRY_AFTER);
+        let scope = header(headers, "x-ratelimit-scope").unwrap_or("user");
+        log::warn!(
+            "rate limited on {} ({scope}), retrying after {retry_after:?}",
+            self.route.route
+        );
+
+        if header(headers, "x-ratelimit-global") == Some("true") {
+            self.limiter.inner.global.lock().await.blocked_until = Some(now + retry_after);
+        } else {
+            // per-route, sub-limits and shared limits of a resource only block this bucket
+            self.state.remaining = Some(0);
+            self.state.reset_at = Some(now + retry_after);
+        }
+        Some(retry_after)
+    }
+}
+
+fn header<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name)?.to_str().ok()
+}
+
+fn header_secs(headers: &HeaderMap, name: &str) -> Option<Duration> {
+    let secs: f64 = header(headers, name)?.parse().ok()?;
+    Duration::try_from_secs_f64(secs).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::{HeaderName, HeaderValue};
+
+    use super::*;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|(name, value)| {
+                (
+                    HeaderName::from_static(name),
+                    HeaderValue::from_str(value).unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    fn message_route(channel_id: &str) -> RouteKey {
+        RouteKey::new(&Route::CreateMessage { channel_id })
+    }
+
+    /// Time it takes until a request for the route may be sent.
+    async fn wait_time(limiter: &RateLimiter, route: &RouteKey) -> Duration {
+        let start = Instant::now();
+        let ticket = limiter.acquire(route).await;
+        let waited = start.elapsed();
+        ticket.complete(StatusCode::OK, &HeaderMap::new()).await;
+        waited
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_remaining_zero_waits_for_reset() {
+        let limiter = RateLimiter::new();
+        let route = message_route("1");
+
+        let ticket = limiter.acquire(&route).await;
+        let retry = ticket
+            .complete(
+                StatusCode::OK,
+                &headers(&[
+                    ("x-ratelimit-remaining", "0"),
+                    ("x-ratelimit-reset-after", "2.5"),
+                ]),
+            )
+            .await;
+        assert_eq!(retry, None);
+
+        assert_eq!(
+            wait_time(&limiter, &route).await,
+            Duration::from_millis(2500)
+        );
+        // the bucket was reset, the next request is sent right away
+        assert_eq!(wait_time(&limiter, &route).await, Duration::ZERO);
+        // other channels are limited separately
+        assert_eq!(
+            wait_time(&limiter, &message_route("2")).await,
+            Duration::ZERO
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_routes_sharing_a_bucket_are_queued_together() {
+        let limiter = RateLimiter::new();
+        let create = message_route("1");
+        let delete = RouteKey::new(&Route::DeleteMessage {
+            channel_id: "1",
+            message_id: "2",
+        });
+
+        for route in [&create, &delete] {
+            let ticket = limiter.acquire(route).await;
+            ticket
+                .complete(
+                    StatusCode::OK,
+                    &headers(&[
+                        ("x-ratelimit-bucket", "abcd"),
+                        ("x-ratelimit-remaining", "5"),
+                    ]),
+                )
+                .await;
+        }
+        assert_eq!(
+            limiter.inner.routes.lock().unwrap().get(delete.route),
+            Some(&"abcd".to_owned())
+        );
+
+        let ticket = limiter.acquire(&create).await;
+        ticket
+            .complete(
+                StatusCode::OK,
+                &headers(&[
+                    ("x-ratelimit-bucket", "abcd"),
+                    ("x-ratelimit-remaining", "0"),
+                    ("x-ratelimit-reset-after", "1"),
+                ]),
+            )
+            .await;
+        assert_eq!(wait_time(&limiter, &delete).await, Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_global_rate_limit_blocks_all_routes() {
+        let limiter = RateLimiter::new();
+
+        let ticket = limiter.acquire(&message_route("1")).await;
+        let retry = ticket
+            .complete(
+                StatusCode::TOO_MANY_REQUESTS,
+                &headers(&[
+                    ("retry-after", "3"),
+                    ("x-ratelimit-global", "true"),
+                    ("x-ratelimit-scope", "global"),
+                ]),
+            )
+            .await;
+        assert_eq!(retry, Some(Duration::from_secs(3)));
+
+        // interaction responses are not bound to the global rate limit
+        let interaction = RouteKey::new(&Route::CreateInteractionResponse {
+            interaction_id: "1",
+            interaction_token: "token",
+            with_response: None,
+        });
+        assert_eq!(wait_time(&limiter, &interaction).await, Duration::ZERO);
+        assert_eq!(
+            wait_time(&limiter, &message_route("2")).await,
+            Duration::from_secs(3)
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_shared_rate_limit_blocks_only_its_bucket() {
+        let limiter = RateLimiter::new();
+        let route = message_route("1");
+
+        let ticket = limiter.acquire(&route).await;
+        let retry = ticket
+            .complete(
+                StatusCode::TOO_MANY_REQUESTS,
+                &headers(&[
+                    ("retry-after", "2"),
+                    ("x-ratelimit-remaining", "3"),
+                    ("x-ratelimit-scope", "shared"),
+                ]),
+            )
+            .await;
+        assert_eq!(retry, Some(Duration::from_secs(2)));
+
+        assert_eq!(
+            wait_time(&limiter, &message_route("2")).await,
+            Duration::ZERO
+        );
+        assert_eq!(wait_time(&limiter, &route).await, Duration::from_secs(2));
+    }
+}
@@ -2,6 +2,9 @@ mod error;
 mod events;
 mod implementations;
 mod internal;
+mod pagination;
+mod retry;
+mod route;
 
 pub mod traits;
 
@@ -16,11 +19,14 @@ use disruption_types::{
 };
 pub use error::{Error, RestError, Result};
 pub use implementations::*;
+pub use internal::{RestClient, RestClientBuilder};
+pub use pagination::{Cursor, Pagination};
+pub use retry::{RetryEvent, RetryPolicy};
+pub use route::Route;
 
 pub use disruption_gateway::*;
 use events::*;
 use implementations::channel::Message;
-use internal::RestClient;
 
 /// Handler trait for Discord gateway events.
 /// All methods have default implementations for backward compatibility.
@@ -104,7 +110,7 @@ pub struct Client<'a> {
     token: String,
     gateway: Option<Gateway>,
     handler: &'a mut (dyn Handler + Send),
-    rest_client: Option<RestClient>,
+    rest_client: RestClient,
 }
 
 impl<'a> Client<'a> {
@@ -113,10 +119,27 @@ impl<'a> Client<'a> {
             token: token.to_string(),
             handler,
             gateway: None,
-            rest_client: None,
+            rest_client: RestClient::new(token),
         }
     }
 
+    /// Use the given client for REST requests, e.g. one configured with [`RestClient::builder`].
+    pub fn with_rest_client(mut self, rest_client: RestClient) -> Self {
+        self.rest_client = rest_client;
+        self
+    }
+
+    /// Use the given policy to retry REST requests that failed for transient reasons.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.rest_client = self.rest_client.with_retry_policy(retry_policy);
+        self
+    }
+
+    /// The client used for REST requests.
+    pub fn rest(&self) -> &RestClient {
+        &self.rest_client
+    }
+
     pub async fn connect(&mut self) -> Result<()> {
         self.gateway = Some(Gateway::connect(&self.token).await?);
         Ok(())
@@ -241,11 +264,11 @@ impl<'a> Client<'a> {
 
                 // ===== Message Events =====
                 Event::MESSAGE_CREATE => {
-                    let (Some(d), Some(rest_client)) = (payload.d, &self.rest_client) else {
+                    let Some(d) = payload.d else {
                         continue;
                     };
                     let message: MessageApiType = serde_json::from_value(d)?;
-                    let message = Message::new(rest_client.clone(), message).await;
+                    let message = Message::new(self.rest_client.clone(), message).await;
                     self.handler.on_message(message).await;
                 }
                 Event::MESSAGE_UPDATE => {
@@ -325,7 +348,5 @@ impl<'a> Client<'a> {
         if let Some(gateway) = &self.gateway {
             gateway.set_session_id(data.session_id.clone()).await;
         }
-
-        self.rest_client = Some(RestClient::new(&self.token, data.v));
     }
 }
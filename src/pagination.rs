@@ -0,0 +1,197 @@
+//! Paging through list endpoints of the REST API
+
+use std::future::Future;
+
+use disruption_types::timestamp::Timestamp;
+use futures::stream::{self, Stream, TryStreamExt};
+
+use crate::error::{RestError, RestResult};
+
+/// Where a paginated listing starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cursor {
+    /// page backwards, starting right before the given id
+    Before(String),
+    /// page forwards, starting right after the given id
+    After(String),
+    /// a single page of entries around the given id
+    Around(String),
+}
+
+/// Options of a paginated listing: where to start and when to stop.
+///
+/// Without a cursor, listings start at their default end, e.g. the newest message of a channel
+/// or the first member of a guild.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Pagination {
+    cursor: Option<Cursor>,
+    limit: Option<usize>,
+    until: Option<u64>,
+}
+
+impl Pagination {
+    /// List everything, starting at the default end.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Page backwards from the given id.
+    pub fn before(mut self, id: impl ToString) -> Self {
+        self.cursor = Some(Cursor::Before(id.to_string()));
+        self
+    }
+
+    /// Page forwards from the given id.
+    pub fn after(mut self, id: impl ToString) -> Self {
+        self.cursor = Some(Cursor::After(id.to_string()));
+        self
+    }
+
+    /// Fetch a single page around the given id.
+    pub fn around(mut self, id: impl ToString) -> Self {
+        self.cursor = Some(Cursor::Around(id.to_string()));
+        self
+    }
+
+    /// Stop after the given number of entries.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Stop when reaching the given id, e.g. a snapshot of the newest message taken before
+    /// paging forwards. The entry with the id itself is not included.
+    pub fn until_id(mut self, id: &str) -> Self {
+        self.until = id.parse().ok();
+        self
+    }
+
+    /// Stop when reaching entries created at the given time.
+    pub fn until_time(mut self, time: Timestamp) -> Self {
+        self.until = Some(
+            time.to_snowflake()
+                .and_then(|id| id.parse().ok())
+                .unwrap_or_default(),
+        );
+        self
+    }
+
+    /// Where the listing starts.
+    pub fn cursor(&self) -> Option<&Cursor> {
+        self.cursor.as_ref()
+    }
+
+    fn forwards(&self) -> bool {
+        matches!(self.cursor, Some(Cursor::After(_)))
+    }
+
+    /// Whether an entry lies beyond the boundary of the listing.
+    fn is_past_until(&self, key: &str) -> bool {
+        let (Some(until), Some(position)) = (self.until, position(key)) else {
+            return false;
+        };
+        if self.forwards() {
+            position >= until
+        } else {
+            position <= until
+        }
+    }
+}
+
+/// A page returned by a list endpoint.
+pub(crate) struct Page<T> {
+    pub(crate) items: Vec<T>,
+    /// whether the endpoint reported more entries, for endpoints that do
+    pub(crate) has_more: Option<bool>,
+}
+
+impl<T> From<Vec<T>> for Page<T> {
+    fn from(items: Vec<T>) -> Self {
+        Page {
+            items,
+            has_more: None,
+        }
+    }
+}
+
+/// Page through a list endpoint.
+///
+/// `fetch` requests the page at a cursor with up to the given number of entries and returns its
+/// entries in listing order. `key` returns the value that is passed as cursor to continue after
+/// an entry: a snowflake id or, for archived threads, a timestamp. Requests go through the
+/// [`RestClient`](crate::RestClient), so rate limits are respected between pages.
+pub(crate) fn paginate<T, F, Fut>(
+    pagination: Pagination,
+    page_size: usize,
+    key: fn(&T) -> String,
+    fetch: F,
+) -> impl Stream<Item = RestResult<T>>
+where
+    F: FnMut(Option<Cursor>, usize) -> Fut,
+    Fut: Future<Output = RestResult<Page<T>>>,
+{
+    let state = PageState {
+        cursor: pagination.cursor.clone(),
+        remaining: pagination.limit.unwrap_or(usize::MAX),
+        done: false,
+        pagination,
+        fetch,
+    };
+
+    stream::try_unfold(state, move |mut state| async move {
+        if state.done || state.remaining == 0 {
+            return Ok::<_, RestError>(None);
+        }
+
+        let size = page_size.min(state.remaining);
+        let page = (state.fetch)(state.cursor.clone(), size).await?;
+        let full = page.items.len() >= size;
+        state.done =
+            !page.has_more.unwrap_or(full) || matches!(state.cursor, Some(Cursor::Around(_)));
+
+        let mut items = Vec::with_capacity(page.items.len());
+        for item in page.items {
+            let item_key = key(&item);
+            if state.remaining == 0 || state.pagination.is_past_until(&item_key) {
+                state.done = true;
+                break;
+            }
+            state.cursor = Some(if state.pagination.forwards() {
+                Cursor::After(item_key)
+            } else {
+                Cursor::Before(item_key)
+            });
+            state.remaining -= 1;
+            items.push(Ok(item));
+        }
+        if items.is_empty() {
+            state.done = true;
+        }
+
+        Ok(Some((stream::iter(items), state)))
+    })
+    .try_flatten()
+}
+
+struct PageState<F> {
+    pagination: Pagination,
+    cursor: Option<Cursor>,
+    remaining: usize,
+    done: bool,
+    fetch: F,
+}
+
+/// Position of a cursor value in time: a snowflake or a timestamp converted to one.
+fn position(key: &str) -> Option<u64> {
+    key.parse()
+        .ok()
+        .or_else(|| key.parse::<Timestamp>().ok()?.to_snowflake()?.parse().ok())
+}
+
+/// Sort entries by their snowflake ids, newest first when paging backwards.
+pub(crate) fn sort_page<T>(items: &mut [T], id: impl Fn(&T) -> &str, forwards: bool) {
+    items.sort_by_key(|item| id(item).parse::<u64>().unwrap_or_default());
+    if !forwards {
+        items.reverse();
+    }
+}
@@ -0,0 +1,184 @@
+//! Retrying of REST requests that failed for transient reasons
+
+use std::collections::hash_map::RandomState;
+use std::fmt::Debug;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::RestError;
+
+type RetryPredicate = Arc<dyn Fn(&RestError) -> bool + Send + Sync>;
+type RetryHook = Arc<dyn Fn(&RetryEvent<'_>) + Send + Sync>;
+
+/// Information about a request that is about to be retried
+#[derive(Debug)]
+pub struct RetryEvent<'a> {
+    /// HTTP method of the request
+    pub method: &'a str,
+    /// API path of the request
+    pub path: &'a str,
+    /// the attempt that failed, starting at 1
+    pub attempt: u32,
+    /// the error of the failed attempt
+    pub error: &'a RestError,
+    /// how long to wait before the next attempt
+    pub delay: Duration,
+}
+
+/// Decides whether and when a failed REST request is sent again.
+///
+/// Failed attempts are retried with exponential backoff. Requests that are not idempotent (POST
+/// without an enforced nonce) are only retried if Discord cannot have processed them, e.g. when
+/// the connection could not be established or the request was rate limited.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    retryable: RetryPredicate,
+    on_retry: Option<RetryHook>,
+}
+
+impl RetryPolicy {
+    /// Retry transient failures up to 3 attempts in total, starting with a delay of 500ms.
+    pub fn new() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            jitter: true,
+            retryable: Arc::new(RetryPolicy::is_transient),
+            on_retry: None,
+        }
+    }
+
+    /// Never retry failed requests.
+    pub fn none() -> Self {
+        RetryPolicy::new().max_attempts(1)
+    }
+
+    /// Total number of attempts of a request, including the first one.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Delay before the first retry, doubled for every further retry up to `max`.
+    pub fn backoff(mut self, base: Duration, max: Duration) -> Self {
+        self.base_delay = base;
+        self.max_delay = max.max(base);
+        self
+    }
+
+    /// Whether to randomize delays, so clients do not retry in lockstep.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Decide which errors are retried, instead of [`RetryPolicy::is_transient`].
+    pub fn retry_if(
+        mut self,
+        retryable: impl Fn(&RestError) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.retryable = Arc::new(retryable);
+        self
+    }
+
+    /// Call a hook before every retry, e.g. for logging or metrics.
+    pub fn on_retry(mut self, hook: impl Fn(&RetryEvent<'_>) + Send + Sync + 'static) -> Self {
+        self.on_retry = Some(Arc::new(hook));
+        self
+    }
+
+    /// Errors that are likely to go away when the request is sent again: connection failures,
+    /// timeouts, rate limits and 500, 502, 503 and 504 responses.
+    pub fn is_transient(error: &RestError) -> bool {
+        match error {
+            RestError::RequestFailed(error) => {
+                error.is_connect() || error.is_timeout() || error.is_request()
+            }
+            RestError::RateLimited { .. } => true,
+            RestError::ApiError { status, .. } => matches!(status, 500 | 502 | 503 | 504),
+            _ => false,
+        }
+    }
+
+    /// Whether Discord can have processed a request that failed with this error.
+    fn may_be_processed(error: &RestError) -> bool {
+        match error {
+            RestError::RequestFailed(error) => !error.is_connect(),
+            RestError::RateLimited { .. } => false,
+            _ => true,
+        }
+    }
+
+    /// How long to wait before retrying a request whose attempt failed, or `None` if the error
+    /// should be returned.
+    pub fn retry_delay(
+        &self,
+        attempt: u32,
+        error: &RestError,
+        idempotent: bool,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts || !(self.retryable)(error) {
+            return None;
+        }
+        if !idempotent && RetryPolicy::may_be_processed(error) {
+            return None;
+        }
+
+        let exponent = attempt.saturating_sub(1).min(31);
+        let mut delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+        if self.jitter {
+            // somewhere between half and the full delay
+            delay = delay / 2 + delay.mul_f64(random_fraction() / 2.0);
+        }
+        if let RestError::RateLimited { retry_after } = error {
+            delay = delay.max(Duration::from_secs(*retry_after));
+        }
+        Some(delay)
+    }
+
+    pub(crate) fn notify(&self, event: &RetryEvent<'_>) {
+        log::warn!(
+            "{} {} failed (attempt {}): {}, retrying in {:?}",
+            event.method,
+            event.path,
+            event.attempt,
+            event.error,
+            event.delay
+        );
+        if let Some(hook) = &self.on_retry {
+            hook(event);
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::new()
+    }
+}
+
+impl Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("base_delay", &self.base_delay)
+            .field("max_delay", &self.max_delay)
+            .field("jitter", &self.jitter)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A random number in `[0, 1)`, good enough to spread out retries.
+fn random_fraction() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
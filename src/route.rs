@@ -0,0 +1,718 @@
+//! Endpoints of the Discord REST API
+//!
+//! [`Route`] has a variant for every endpoint a bot can call, with a few deliberate gaps:
+//!
+//! - Modify Current User Nick is deprecated in favor of [`Route::ModifyCurrentMember`]
+//! - Create Group DM shares its method and path with [`Route::CreateDm`]
+//! - the lobby endpoints belong to the Social SDK and are not available to bots
+
+use std::fmt::Display;
+
+use reqwest::Method;
+
+/// Declare the `Route` enum from a table of endpoints.
+///
+/// Every entry lists the path parameters of an endpoint, optionally followed by its query
+/// parameters, the HTTP method, the path and flags:
+///
+/// - `reason`: the endpoint accepts an `X-Audit-Log-Reason` header
+/// - `interaction`: the endpoint is authorized by an interaction token instead of the bot token
+macro_rules! routes {
+    (
+        $(
+            $(#[$meta:meta])*
+            $variant:ident { $($param:ident),* $(; $($query:ident: $query_ty:ty),*)? }
+                => $method:ident $path:literal $([$($flag:ident),*])?,
+        )*
+    ) => {
+        /// An endpoint of the Discord REST API together with its parameters.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum Route<'a> {
+            $(
+                $(#[$meta])*
+                $variant {
+                    $($param: &'a str,)*
+                    $($($query: Option<$query_ty>,)*)?
+                },
+            )*
+        }
+
+        impl<'a> Route<'a> {
+            /// Name of the endpoint, e.g. `CreateMessage`.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Route::$variant { .. } => stringify!($variant),)*
+                }
+            }
+
+            /// HTTP method of the endpoint.
+            pub fn method(&self) -> Method {
+                match self {
+                    $(Route::$variant { .. } => Method::$method,)*
+                }
+            }
+
+            /// Path of the endpoint relative to the API version, including the query string.
+            pub fn path(&self) -> String {
+                match self {
+                    $(
+                        #[allow(unused_variables)]
+                        Route::$variant { $($param,)* $($($query,)*)? } => {
+                            $(let $param = percent_encode($param);)*
+                            #[allow(unused_mut)]
+                            let mut path = format!($path);
+                            $(
+                                let mut query = Query::default();
+                                $(query.push(stringify!($query), $query);)*
+                                path.push_str(&query.finish());
+                            )?
+                            path
+                        }
+                    )*
+                }
+            }
+
+            /// Path parameters of the endpoint with their names.
+            fn params(&self) -> Vec<(&'static str, &'a str)> {
+                match self {
+                    $(
+                        Route::$variant { $($param,)* .. } => {
+                            vec![$((stringify!($param), *$param)),*]
+                        }
+                    )*
+                }
+            }
+
+            /// Whether the endpoint accepts an audit log reason.
+            pub fn supports_audit_log_reason(&self) -> bool {
+                match self {
+                    $(Route::$variant { .. } => false $($(|| stringify!($flag) == "reason")*)?,)*
+                }
+            }
+
+            /// Whether the endpoint is authorized by an interaction token, so it does not need the
+            /// bot token and is not subject to the global rate limit.
+            pub fn is_interaction(&self) -> bool {
+                match self {
+                    $(Route::$variant { .. } => false $($(|| stringify!($flag) == "interaction")*)?,)*
+                }
+            }
+        }
+    };
+}
+
+routes! {
+    // ===== Audit Log =====
+    /// <https://discord.com/developers/docs/resources/audit-log#get-guild-audit-log>
+    GetGuildAuditLog { guild_id; user_id: &'a str, action_type: u16, before: &'a str, after: &'a str, limit: u8 }
+        => GET "guilds/{guild_id}/audit-logs",
+
+    // ===== Auto Moderation =====
+    /// <https://discord.com/developers/docs/resources/auto-moderation#list-auto-moderation-rules-for-guild>
+    ListAutoModerationRules { guild_id } => GET "guilds/{guild_id}/auto-moderation/rules",
+    /// <https://discord.com/developers/docs/resources/auto-moderation#get-auto-moderation-rule>
+    GetAutoModerationRule { guild_id, rule_id }
+        => GET "guilds/{guild_id}/auto-moderation/rules/{rule_id}",
+    /// <https://discord.com/developers/docs/resources/auto-moderation#create-auto-moderation-rule>
+    CreateAutoModerationRule { guild_id }
+        => POST "guilds/{guild_id}/auto-moderation/rules" [reason],
+    /// <https://discord.com/developers/docs/resources/auto-moderation#modify-auto-moderation-rule>
+    ModifyAutoModerationRule { guild_id, rule_id }
+        => PATCH "guilds/{guild_id}/auto-moderation/rules/{rule_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/auto-moderation#delete-auto-moderation-rule>
+    DeleteAutoModerationRule { guild_id, rule_id }
+        => DELETE "guilds/{guild_id}/auto-moderation/rules/{rule_id}" [reason],
+
+    // ===== Channel =====
+    /// <https://discord.com/developers/docs/resources/channel#get-channel>
+    GetChannel { channel_id } => GET "channels/{channel_id}",
+    /// <https://discord.com/developers/docs/resources/channel#modify-channel>
+    ModifyChannel { channel_id } => PATCH "channels/{channel_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/channel#deleteclose-channel>
+    DeleteChannel { channel_id } => DELETE "channels/{channel_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/channel#edit-channel-permissions>
+    EditChannelPermissions { channel_id, overwrite_id }
+        => PUT "channels/{channel_id}/permissions/{overwrite_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/channel#delete-channel-permission>
+    DeleteChannelPermission { channel_id, overwrite_id }
+        => DELETE "channels/{channel_id}/permissions/{overwrite_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/channel#get-channel-invites>
+    GetChannelInvites { channel_id } => GET "channels/{channel_id}/invites",
+    /// <https://discord.com/developers/docs/resources/channel#create-channel-invite>
+    CreateChannelInvite { channel_id } => POST "channels/{channel_id}/invites" [reason],
+    /// <https://discord.com/developers/docs/resources/channel#follow-announcement-channel>
+    FollowAnnouncementChannel { channel_id } => POST "channels/{channel_id}/followers" [reason],
+    /// <https://discord.com/developers/docs/resources/channel#trigger-typing-indicator>
+    TriggerTypingIndicator { channel_id } => POST "channels/{channel_id}/typing",
+    /// <https://discord.com/developers/docs/resources/message#get-channel-pins>
+    GetPinnedMessages { channel_id; before: &'a str, limit: u8 }
+        => GET "channels/{channel_id}/messages/pins",
+    /// <https://discord.com/developers/docs/resources/message#pin-message>
+    PinMessage { channel_id, message_id }
+        => PUT "channels/{channel_id}/messages/pins/{message_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/message#unpin-message>
+    UnpinMessage { channel_id, message_id }
+        => DELETE "channels/{channel_id}/messages/pins/{message_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/channel#group-dm-add-recipient>
+    GroupDmAddRecipient { channel_id, user_id } => PUT "channels/{channel_id}/recipients/{user_id}",
+    /// <https://discord.com/developers/docs/resources/channel#group-dm-remove-recipient>
+    GroupDmRemoveRecipient { channel_id, user_id }
+        => DELETE "channels/{channel_id}/recipients/{user_id}",
+    /// <https://discord.com/developers/docs/resources/channel#start-thread-from-message>
+    StartThreadFromMessage { channel_id, message_id }
+        => POST "channels/{channel_id}/messages/{message_id}/threads" [reason],
+    /// <https://discord.com/developers/docs/resources/channel#start-thread-without-message>
+    StartThreadWithoutMessage { channel_id } => POST "channels/{channel_id}/threads" [reason],
+    /// <https://discord.com/developers/docs/resources/channel#join-thread>
+    JoinThread { channel_id } => PUT "channels/{channel_id}/thread-members/@me",
+    /// <https://discord.com/developers/docs/resources/channel#add-thread-member>
+    AddThreadMember { channel_id, user_id } => PUT "channels/{channel_id}/thread-members/{user_id}",
+    /// <https://discord.com/developers/docs/resources/channel#leave-thread>
+    LeaveThread { channel_id } => DELETE "channels/{channel_id}/thread-members/@me",
+    /// <https://discord.com/developers/docs/resources/channel#remove-thread-member>
+    RemoveThreadMember { channel_id, user_id }
+        => DELETE "channels/{channel_id}/thread-members/{user_id}",
+    /// <https://discord.com/developers/docs/resources/channel#get-thread-member>
+    GetThreadMember { channel_id, user_id; with_member: bool }
+        => GET "channels/{channel_id}/thread-members/{user_id}",
+    /// <https://discord.com/developers/docs/resources/channel#list-thread-members>
+    ListThreadMembers { channel_id; with_member: bool, after: &'a str, limit: u8 }
+        => GET "channels/{channel_id}/thread-members",
+    /// <https://discord.com/developers/docs/resources/channel#list-public-archived-threads>
+    ListPublicArchivedThreads { channel_id; before: &'a str, limit: u8 }
+        => GET "channels/{channel_id}/threads/archived/public",
+    /// <https://discord.com/developers/docs/resources/channel#list-private-archived-threads>
+    ListPrivateArchivedThreads { channel_id; before: &'a str, limit: u8 }
+        => GET "channels/{channel_id}/threads/archived/private",
+    /// <https://discord.com/developers/docs/resources/channel#list-joined-private-archived-threads>
+    ListJoinedPrivateArchivedThreads { channel_id; before: &'a str, limit: u8 }
+        => GET "channels/{channel_id}/users/@me/threads/archived/private",
+
+    // ===== Message =====
+    /// <https://discord.com/developers/docs/resources/message#get-channel-messages>
+    GetChannelMessages { channel_id; around: &'a str, before: &'a str, after: &'a str, limit: u8 }
+        => GET "channels/{channel_id}/messages",
+    /// <https://discord.com/developers/docs/resources/message#get-channel-message>
+    GetChannelMessage { channel_id, message_id }
+        => GET "channels/{channel_id}/messages/{message_id}",
+    /// <https://discord.com/developers/docs/resources/message#create-message>
+    CreateMessage { channel_id } => POST "channels/{channel_id}/messages",
+    /// <https://discord.com/developers/docs/resources/message#crosspost-message>
+    CrosspostMessage { channel_id, message_id }
+        => POST "channels/{channel_id}/messages/{message_id}/crosspost",
+    /// <https://discord.com/developers/docs/resources/message#create-reaction>
+    CreateReaction { channel_id, message_id, emoji }
+        => PUT "channels/{channel_id}/messages/{message_id}/reactions/{emoji}/@me",
+    /// <https://discord.com/developers/docs/resources/message#delete-own-reaction>
+    DeleteOwnReaction { channel_id, message_id, emoji }
+        => DELETE "channels/{channel_id}/messages/{message_id}/reactions/{emoji}/@me",
+    /// <https://discord.com/developers/docs/resources/message#delete-user-reaction>
+    DeleteUserReaction { channel_id, message_id, emoji, user_id }
+        => DELETE "channels/{channel_id}/messages/{message_id}/reactions/{emoji}/{user_id}",
+    /// <https://discord.com/developers/docs/resources/message#get-reactions>
+    GetReactions { channel_id, message_id, emoji; type_: u8, after: &'a str, limit: u8 }
+        => GET "channels/{channel_id}/messages/{message_id}/reactions/{emoji}",
+    /// <https://discord.com/developers/docs/resources/message#delete-all-reactions>
+    DeleteAllReactions { channel_id, message_id }
+        => DELETE "channels/{channel_id}/messages/{message_id}/reactions",
+    /// <https://discord.com/developers/docs/resources/message#delete-all-reactions-for-emoji>
+    DeleteAllReactionsForEmoji { channel_id, message_id, emoji }
+        => DELETE "channels/{channel_id}/messages/{message_id}/reactions/{emoji}",
+    /// <https://discord.com/developers/docs/resources/message#edit-message>
+    EditMessage { channel_id, message_id } => PATCH "channels/{channel_id}/messages/{message_id}",
+    /// <https://discord.com/developers/docs/resources/message#delete-message>
+    DeleteMessage { channel_id, message_id }
+        => DELETE "channels/{channel_id}/messages/{message_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/message#bulk-delete-messages>
+    BulkDeleteMessages { channel_id }
+        => POST "channels/{channel_id}/messages/bulk-delete" [reason],
+
+    // ===== Poll =====
+    /// <https://discord.com/developers/docs/resources/poll#get-answer-voters>
+    GetAnswerVoters { channel_id, message_id, answer_id; after: &'a str, limit: u8 }
+        => GET "channels/{channel_id}/polls/{message_id}/answers/{answer_id}",
+    /// <https://discord.com/developers/docs/resources/poll#end-poll>
+    EndPoll { channel_id, message_id } => POST "channels/{channel_id}/polls/{message_id}/expire",
+
+    // ===== Emoji =====
+    /// <https://discord.com/developers/docs/resources/emoji#list-guild-emojis>
+    ListGuildEmojis { guild_id } => GET "guilds/{guild_id}/emojis",
+    /// <https://discord.com/developers/docs/resources/emoji#get-guild-emoji>
+    GetGuildEmoji { guild_id, emoji_id } => GET "guilds/{guild_id}/emojis/{emoji_id}",
+    /// <https://discord.com/developers/docs/resources/emoji#create-guild-emoji>
+    CreateGuildEmoji { guild_id } => POST "guilds/{guild_id}/emojis" [reason],
+    /// <https://discord.com/developers/docs/resources/emoji#modify-guild-emoji>
+    ModifyGuildEmoji { guild_id, emoji_id } => PATCH "guilds/{guild_id}/emojis/{emoji_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/emoji#delete-guild-emoji>
+    DeleteGuildEmoji { guild_id, emoji_id } => DELETE "guilds/{guild_id}/emojis/{emoji_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/emoji#list-application-emojis>
+    ListApplicationEmojis { application_id } => GET "applications/{application_id}/emojis",
+    /// <https://discord.com/developers/docs/resources/emoji#get-application-emoji>
+    GetApplicationEmoji { application_id, emoji_id }
+        => GET "applications/{application_id}/emojis/{emoji_id}",
+    /// <https://discord.com/developers/docs/resources/emoji#create-application-emoji>
+    CreateApplicationEmoji { application_id } => POST "applications/{application_id}/emojis",
+    /// <https://discord.com/developers/docs/resources/emoji#modify-application-emoji>
+    ModifyApplicationEmoji { application_id, emoji_id }
+        => PATCH "applications/{application_id}/emojis/{emoji_id}",
+    /// <https://discord.com/developers/docs/resources/emoji#delete-application-emoji>
+    DeleteApplicationEmoji { application_id, emoji_id }
+        => DELETE "applications/{application_id}/emojis/{emoji_id}",
+
+    // ===== Guild =====
+    /// <https://discord.com/developers/docs/resources/guild#create-guild>
+    CreateGuild {} => POST "guilds",
+    /// <https://discord.com/developers/docs/resources/guild#get-guild>
+    GetGuild { guild_id; with_counts: bool } => GET "guilds/{guild_id}",
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-preview>
+    GetGuildPreview { guild_id } => GET "guilds/{guild_id}/preview",
+    /// <https://discord.com/developers/docs/resources/guild#modify-guild>
+    ModifyGuild { guild_id } => PATCH "guilds/{guild_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#delete-guild>
+    DeleteGuild { guild_id } => DELETE "guilds/{guild_id}",
+    /// <https://discord.com/developers/docs/resources/guild#modify-guild-mfa-level>
+    ModifyGuildMfaLevel { guild_id } => POST "guilds/{guild_id}/mfa" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-channels>
+    GetGuildChannels { guild_id } => GET "guilds/{guild_id}/channels",
+    /// <https://discord.com/developers/docs/resources/guild#create-guild-channel>
+    CreateGuildChannel { guild_id } => POST "guilds/{guild_id}/channels" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#modify-guild-channel-positions>
+    ModifyGuildChannelPositions { guild_id } => PATCH "guilds/{guild_id}/channels",
+    /// <https://discord.com/developers/docs/resources/guild#list-active-guild-threads>
+    ListActiveGuildThreads { guild_id } => GET "guilds/{guild_id}/threads/active",
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-member>
+    GetGuildMember { guild_id, user_id } => GET "guilds/{guild_id}/members/{user_id}",
+    /// <https://discord.com/developers/docs/resources/guild#list-guild-members>
+    ListGuildMembers { guild_id; limit: u16, after: &'a str } => GET "guilds/{guild_id}/members",
+    /// <https://discord.com/developers/docs/resources/guild#search-guild-members>
+    SearchGuildMembers { guild_id; query: &'a str, limit: u16 }
+        => GET "guilds/{guild_id}/members/search",
+    /// <https://discord.com/developers/docs/resources/guild#add-guild-member>
+    AddGuildMember { guild_id, user_id } => PUT "guilds/{guild_id}/members/{user_id}",
+    /// <https://discord.com/developers/docs/resources/guild#modify-guild-member>
+    ModifyGuildMember { guild_id, user_id }
+        => PATCH "guilds/{guild_id}/members/{user_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#modify-current-member>
+    ModifyCurrentMember { guild_id } => PATCH "guilds/{guild_id}/members/@me" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#add-guild-member-role>
+    AddGuildMemberRole { guild_id, user_id, role_id }
+        => PUT "guilds/{guild_id}/members/{user_id}/roles/{role_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#remove-guild-member-role>
+    RemoveGuildMemberRole { guild_id, user_id, role_id }
+        => DELETE "guilds/{guild_id}/members/{user_id}/roles/{role_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#remove-guild-member>
+    RemoveGuildMember { guild_id, user_id }
+        => DELETE "guilds/{guild_id}/members/{user_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-bans>
+    GetGuildBans { guild_id; limit: u16, before: &'a str, after: &'a str }
+        => GET "guilds/{guild_id}/bans",
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-ban>
+    GetGuildBan { guild_id, user_id } => GET "guilds/{guild_id}/bans/{user_id}",
+    /// <https://discord.com/developers/docs/resources/guild#create-guild-ban>
+    CreateGuildBan { guild_id, user_id } => PUT "guilds/{guild_id}/bans/{user_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#remove-guild-ban>
+    RemoveGuildBan { guild_id, user_id } => DELETE "guilds/{guild_id}/bans/{user_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#bulk-guild-ban>
+    BulkGuildBan { guild_id } => POST "guilds/{guild_id}/bulk-ban" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-roles>
+    GetGuildRoles { guild_id } => GET "guilds/{guild_id}/roles",
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-role>
+    GetGuildRole { guild_id, role_id } => GET "guilds/{guild_id}/roles/{role_id}",
+    /// <https://discord.com/developers/docs/resources/guild#create-guild-role>
+    CreateGuildRole { guild_id } => POST "guilds/{guild_id}/roles" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#modify-guild-role-positions>
+    ModifyGuildRolePositions { guild_id } => PATCH "guilds/{guild_id}/roles" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#modify-guild-role>
+    ModifyGuildRole { guild_id, role_id } => PATCH "guilds/{guild_id}/roles/{role_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#delete-guild-role>
+    DeleteGuildRole { guild_id, role_id } => DELETE "guilds/{guild_id}/roles/{role_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-prune-count>
+    GetGuildPruneCount { guild_id; days: u8, include_roles: &'a str }
+        => GET "guilds/{guild_id}/prune",
+    /// <https://discord.com/developers/docs/resources/guild#begin-guild-prune>
+    BeginGuildPrune { guild_id } => POST "guilds/{guild_id}/prune" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-voice-regions>
+    GetGuildVoiceRegions { guild_id } => GET "guilds/{guild_id}/regions",
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-invites>
+    GetGuildInvites { guild_id } => GET "guilds/{guild_id}/invites",
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-integrations>
+    GetGuildIntegrations { guild_id } => GET "guilds/{guild_id}/integrations",
+    /// <https://discord.com/developers/docs/resources/guild#delete-guild-integration>
+    DeleteGuildIntegration { guild_id, integration_id }
+        => DELETE "guilds/{guild_id}/integrations/{integration_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-widget-settings>
+    GetGuildWidgetSettings { guild_id } => GET "guilds/{guild_id}/widget",
+    /// <https://discord.com/developers/docs/resources/guild#modify-guild-widget>
+    ModifyGuildWidget { guild_id } => PATCH "guilds/{guild_id}/widget" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-widget>
+    GetGuildWidget { guild_id } => GET "guilds/{guild_id}/widget.json",
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-widget-image>
+    GetGuildWidgetImage { guild_id; style: &'a str } => GET "guilds/{guild_id}/widget.png",
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-vanity-url>
+    GetGuildVanityUrl { guild_id } => GET "guilds/{guild_id}/vanity-url",
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-welcome-screen>
+    GetGuildWelcomeScreen { guild_id } => GET "guilds/{guild_id}/welcome-screen",
+    /// <https://discord.com/developers/docs/resources/guild#modify-guild-welcome-screen>
+    ModifyGuildWelcomeScreen { guild_id } => PATCH "guilds/{guild_id}/welcome-screen" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#get-guild-onboarding>
+    GetGuildOnboarding { guild_id } => GET "guilds/{guild_id}/onboarding",
+    /// <https://discord.com/developers/docs/resources/guild#modify-guild-onboarding>
+    ModifyGuildOnboarding { guild_id } => PUT "guilds/{guild_id}/onboarding" [reason],
+    /// <https://discord.com/developers/docs/resources/guild#modify-guild-incident-actions>
+    ModifyGuildIncidentActions { guild_id } => PUT "guilds/{guild_id}/incident-actions",
+
+    // ===== Guild Scheduled Event =====
+    /// <https://discord.com/developers/docs/resources/guild-scheduled-event#list-scheduled-events-for-guild>
+    ListScheduledEventsForGuild { guild_id; with_user_count: bool }
+        => GET "guilds/{guild_id}/scheduled-events",
+    /// <https://discord.com/developers/docs/resources/guild-scheduled-event#create-guild-scheduled-event>
+    CreateGuildScheduledEvent { guild_id } => POST "guilds/{guild_id}/scheduled-events" [reason],
+    /// <https://discord.com/developers/docs/resources/guild-scheduled-event#get-guild-scheduled-event>
+    GetGuildScheduledEvent { guild_id, event_id; with_user_count: bool }
+        => GET "guilds/{guild_id}/scheduled-events/{event_id}",
+    /// <https://discord.com/developers/docs/resources/guild-scheduled-event#modify-guild-scheduled-event>
+    ModifyGuildScheduledEvent { guild_id, event_id }
+        => PATCH "guilds/{guild_id}/scheduled-events/{event_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/guild-scheduled-event#delete-guild-scheduled-event>
+    DeleteGuildScheduledEvent { guild_id, event_id }
+        => DELETE "guilds/{guild_id}/scheduled-events/{event_id}",
+    /// <https://discord.com/developers/docs/resources/guild-scheduled-event#get-guild-scheduled-event-users>
+    GetGuildScheduledEventUsers { guild_id, event_id; limit: u8, with_member: bool, before: &'a str, after: &'a str }
+        => GET "guilds/{guild_id}/scheduled-events/{event_id}/users",
+
+    // ===== Guild Template =====
+    /// <https://discord.com/developers/docs/resources/guild-template#get-guild-template>
+    GetGuildTemplate { template_code } => GET "guilds/templates/{template_code}",
+    /// <https://discord.com/developers/docs/resources/guild-template#create-guild-from-guild-template>
+    CreateGuildFromTemplate { template_code } => POST "guilds/templates/{template_code}",
+    /// <https://discord.com/developers/docs/resources/guild-template#get-guild-templates>
+    GetGuildTemplates { guild_id } => GET "guilds/{guild_id}/templates",
+    /// <https://discord.com/developers/docs/resources/guild-template#create-guild-template>
+    CreateGuildTemplate { guild_id } => POST "guilds/{guild_id}/templates",
+    /// <https://discord.com/developers/docs/resources/guild-template#sync-guild-template>
+    SyncGuildTemplate { guild_id, template_code }
+        => PUT "guilds/{guild_id}/templates/{template_code}",
+    /// <https://discord.com/developers/docs/resources/guild-template#modify-guild-template>
+    ModifyGuildTemplate { guild_id, template_code }
+        => PATCH "guilds/{guild_id}/templates/{template_code}",
+    /// <https://discord.com/developers/docs/resources/guild-template#delete-guild-template>
+    DeleteGuildTemplate { guild_id, template_code }
+        => DELETE "guilds/{guild_id}/templates/{template_code}",
+
+    // ===== Invite =====
+    /// <https://discord.com/developers/docs/resources/invite#get-invite>
+    GetInvite { invite_code; with_counts: bool, guild_scheduled_event_id: &'a str }
+        => GET "invites/{invite_code}",
+    /// <https://discord.com/developers/docs/resources/invite#delete-invite>
+    DeleteInvite { invite_code } => DELETE "invites/{invite_code}" [reason],
+
+    // ===== Stage Instance =====
+    /// <https://discord.com/developers/docs/resources/stage-instance#create-stage-instance>
+    CreateStageInstance {} => POST "stage-instances" [reason],
+    /// <https://discord.com/developers/docs/resources/stage-instance#get-stage-instance>
+    GetStageInstance { channel_id } => GET "stage-instances/{channel_id}",
+    /// <https://discord.com/developers/docs/resources/stage-instance#modify-stage-instance>
+    ModifyStageInstance { channel_id } => PATCH "stage-instances/{channel_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/stage-instance#delete-stage-instance>
+    DeleteStageInstance { channel_id } => DELETE "stage-instances/{channel_id}" [reason],
+
+    // ===== Sticker =====
+    /// <https://discord.com/developers/docs/resources/sticker#get-sticker>
+    GetSticker { sticker_id } => GET "stickers/{sticker_id}",
+    /// <https://discord.com/developers/docs/resources/sticker#list-sticker-packs>
+    ListStickerPacks {} => GET "sticker-packs",
+    /// <https://discord.com/developers/docs/resources/sticker#get-sticker-pack>
+    GetStickerPack { pack_id } => GET "sticker-packs/{pack_id}",
+    /// <https://discord.com/developers/docs/resources/sticker#list-guild-stickers>
+    ListGuildStickers { guild_id } => GET "guilds/{guild_id}/stickers",
+    /// <https://discord.com/developers/docs/resources/sticker#get-guild-sticker>
+    GetGuildSticker { guild_id, sticker_id } => GET "guilds/{guild_id}/stickers/{sticker_id}",
+    /// <https://discord.com/developers/docs/resources/sticker#create-guild-sticker>
+    CreateGuildSticker { guild_id } => POST "guilds/{guild_id}/stickers" [reason],
+    /// <https://discord.com/developers/docs/resources/sticker#modify-guild-sticker>
+    ModifyGuildSticker { guild_id, sticker_id }
+        => PATCH "guilds/{guild_id}/stickers/{sticker_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/sticker#delete-guild-sticker>
+    DeleteGuildSticker { guild_id, sticker_id }
+        => DELETE "guilds/{guild_id}/stickers/{sticker_id}" [reason],
+
+    // ===== User =====
+    /// <https://discord.com/developers/docs/resources/user#get-current-user>
+    GetCurrentUser {} => GET "users/@me",
+    /// <https://discord.com/developers/docs/resources/user#get-user>
+    GetUser { user_id } => GET "users/{user_id}",
+    /// <https://discord.com/developers/docs/resources/user#modify-current-user>
+    ModifyCurrentUser {} => PATCH "users/@me",
+    /// <https://discord.com/developers/docs/resources/user#get-current-user-guilds>
+    GetCurrentUserGuilds { ; before: &'a str, after: &'a str, limit: u8, with_counts: bool }
+        => GET "users/@me/guilds",
+    /// <https://discord.com/developers/docs/resources/user#get-current-user-guild-member>
+    GetCurrentUserGuildMember { guild_id } => GET "users/@me/guilds/{guild_id}/member",
+    /// <https://discord.com/developers/docs/resources/user#leave-guild>
+    LeaveGuild { guild_id } => DELETE "users/@me/guilds/{guild_id}",
+    /// <https://discord.com/developers/docs/resources/user#create-dm>
+    CreateDm {} => POST "users/@me/channels",
+    /// <https://discord.com/developers/docs/resources/user#get-current-user-connections>
+    GetCurrentUserConnections {} => GET "users/@me/connections",
+    /// <https://discord.com/developers/docs/resources/user#get-current-user-application-role-connection>
+    GetCurrentUserApplicationRoleConnection { application_id }
+        => GET "users/@me/applications/{application_id}/role-connection",
+    /// <https://discord.com/developers/docs/resources/user#update-current-user-application-role-connection>
+    UpdateCurrentUserApplicationRoleConnection { application_id }
+        => PUT "users/@me/applications/{application_id}/role-connection",
+
+    // ===== Voice =====
+    /// <https://discord.com/developers/docs/resources/voice#list-voice-regions>
+    ListVoiceRegions {} => GET "voice/regions",
+    /// <https://discord.com/developers/docs/resources/voice#get-current-user-voice-state>
+    GetCurrentUserVoiceState { guild_id } => GET "guilds/{guild_id}/voice-states/@me",
+    /// <https://discord.com/developers/docs/resources/voice#get-user-voice-state>
+    GetUserVoiceState { guild_id, user_id } => GET "guilds/{guild_id}/voice-states/{user_id}",
+    /// <https://discord.com/developers/docs/resources/voice#modify-current-user-voice-state>
+    ModifyCurrentUserVoiceState { guild_id } => PATCH "guilds/{guild_id}/voice-states/@me",
+    /// <https://discord.com/developers/docs/resources/voice#modify-user-voice-state>
+    ModifyUserVoiceState { guild_id, user_id } => PATCH "guilds/{guild_id}/voice-states/{user_id}",
+
+    // ===== Webhook =====
+    /// <https://discord.com/developers/docs/resources/webhook#create-webhook>
+    CreateWebhook { channel_id } => POST "channels/{channel_id}/webhooks" [reason],
+    /// <https://discord.com/developers/docs/resources/webhook#get-channel-webhooks>
+    GetChannelWebhooks { channel_id } => GET "channels/{channel_id}/webhooks",
+    /// <https://discord.com/developers/docs/resources/webhook#get-guild-webhooks>
+    GetGuildWebhooks { guild_id } => GET "guilds/{guild_id}/webhooks",
+    /// <https://discord.com/developers/docs/resources/webhook#get-webhook>
+    GetWebhook { webhook_id } => GET "webhooks/{webhook_id}",
+    /// <https://discord.com/developers/docs/resources/webhook#get-webhook-with-token>
+    GetWebhookWithToken { webhook_id, webhook_token } => GET "webhooks/{webhook_id}/{webhook_token}",
+    /// <https://discord.com/developers/docs/resources/webhook#modify-webhook>
+    ModifyWebhook { webhook_id } => PATCH "webhooks/{webhook_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/webhook#modify-webhook-with-token>
+    ModifyWebhookWithToken { webhook_id, webhook_token }
+        => PATCH "webhooks/{webhook_id}/{webhook_token}",
+    /// <https://discord.com/developers/docs/resources/webhook#delete-webhook>
+    DeleteWebhook { webhook_id } => DELETE "webhooks/{webhook_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/webhook#delete-webhook-with-token>
+    DeleteWebhookWithToken { webhook_id, webhook_token }
+        => DELETE "webhooks/{webhook_id}/{webhook_token}",
+    /// <https://discord.com/developers/docs/resources/webhook#execute-webhook>
+    ExecuteWebhook { webhook_id, webhook_token; wait: bool, thread_id: &'a str }
+        => POST "webhooks/{webhook_id}/{webhook_token}",
+    /// <https://discord.com/developers/docs/resources/webhook#execute-slackcompatible-webhook>
+    ExecuteSlackCompatibleWebhook { webhook_id, webhook_token; thread_id: &'a str, wait: bool }
+        => POST "webhooks/{webhook_id}/{webhook_token}/slack",
+    /// <https://discord.com/developers/docs/resources/webhook#execute-githubcompatible-webhook>
+    ExecuteGitHubCompatibleWebhook { webhook_id, webhook_token; thread_id: &'a str, wait: bool }
+        => POST "webhooks/{webhook_id}/{webhook_token}/github",
+    /// <https://discord.com/developers/docs/resources/webhook#get-webhook-message>
+    GetWebhookMessage { webhook_id, webhook_token, message_id; thread_id: &'a str }
+        => GET "webhooks/{webhook_id}/{webhook_token}/messages/{message_id}",
+    /// <https://discord.com/developers/docs/resources/webhook#edit-webhook-message>
+    EditWebhookMessage { webhook_id, webhook_token, message_id; thread_id: &'a str }
+        => PATCH "webhooks/{webhook_id}/{webhook_token}/messages/{message_id}",
+    /// <https://discord.com/developers/docs/resources/webhook#delete-webhook-message>
+    DeleteWebhookMessage { webhook_id, webhook_token, message_id; thread_id: &'a str }
+        => DELETE "webhooks/{webhook_id}/{webhook_token}/messages/{message_id}",
+
+    // ===== Application =====
+    /// <https://discord.com/developers/docs/resources/application#get-current-application>
+    GetCurrentApplication {} => GET "applications/@me",
+    /// <https://discord.com/developers/docs/resources/application#edit-current-application>
+    EditCurrentApplication {} => PATCH "applications/@me",
+    /// <https://discord.com/developers/docs/resources/application#get-application-activity-instance>
+    GetApplicationActivityInstance { application_id, instance_id }
+        => GET "applications/{application_id}/activity-instances/{instance_id}",
+    /// <https://discord.com/developers/docs/resources/application-role-connection-metadata#get-application-role-connection-metadata-records>
+    GetApplicationRoleConnectionMetadataRecords { application_id }
+        => GET "applications/{application_id}/role-connections/metadata",
+    /// <https://discord.com/developers/docs/resources/application-role-connection-metadata#update-application-role-connection-metadata-records>
+    UpdateApplicationRoleConnectionMetadataRecords { application_id }
+        => PUT "applications/{application_id}/role-connections/metadata",
+
+    // ===== Application Commands =====
+    /// <https://discord.com/developers/docs/interactions/application-commands#get-global-application-commands>
+    GetGlobalApplicationCommands { application_id; with_localizations: bool }
+        => GET "applications/{application_id}/commands",
+    /// <https://discord.com/developers/docs/interactions/application-commands#create-global-application-command>
+    CreateGlobalApplicationCommand { application_id } => POST "applications/{application_id}/commands",
+    /// <https://discord.com/developers/docs/interactions/application-commands#get-global-application-command>
+    GetGlobalApplicationCommand { application_id, command_id }
+        => GET "applications/{application_id}/commands/{command_id}",
+    /// <https://discord.com/developers/docs/interactions/application-commands#edit-global-application-command>
+    EditGlobalApplicationCommand { application_id, command_id }
+        => PATCH "applications/{application_id}/commands/{command_id}",
+    /// <https://discord.com/developers/docs/interactions/application-commands#delete-global-application-command>
+    DeleteGlobalApplicationCommand { application_id, command_id }
+        => DELETE "applications/{application_id}/commands/{command_id}",
+    /// <https://discord.com/developers/docs/interactions/application-commands#bulk-overwrite-global-application-commands>
+    BulkOverwriteGlobalApplicationCommands { application_id }
+        => PUT "applications/{application_id}/commands",
+    /// <https://discord.com/developers/docs/interactions/application-commands#get-guild-application-commands>
+    GetGuildApplicationCommands { application_id, guild_id; with_localizations: bool }
+        => GET "applications/{application_id}/guilds/{guild_id}/commands",
+    /// <https://discord.com/developers/docs/interactions/application-commands#create-guild-application-command>
+    CreateGuildApplicationCommand { application_id, guild_id }
+        => POST "applications/{application_id}/guilds/{guild_id}/commands",
+    /// <https://discord.com/developers/docs/interactions/application-commands#get-guild-application-command>
+    GetGuildApplicationCommand { application_id, guild_id, command_id }
+        => GET "applications/{application_id}/guilds/{guild_id}/commands/{command_id}",
+    /// <https://discord.com/developers/docs/interactions/application-commands#edit-guild-application-command>
+    EditGuildApplicationCommand { application_id, guild_id, command_id }
+        => PATCH "applications/{application_id}/guilds/{guild_id}/commands/{command_id}",
+    /// <https://discord.com/developers/docs/interactions/application-commands#delete-guild-application-command>
+    DeleteGuildApplicationCommand { application_id, guild_id, command_id }
+        => DELETE "applications/{application_id}/guilds/{guild_id}/commands/{command_id}",
+    /// <https://discord.com/developers/docs/interactions/application-commands#bulk-overwrite-guild-application-commands>
+    BulkOverwriteGuildApplicationCommands { application_id, guild_id }
+        => PUT "applications/{application_id}/guilds/{guild_id}/commands",
+    /// <https://discord.com/developers/docs/interactions/application-commands#get-guild-application-command-permissions>
+    GetGuildApplicationCommandPermissions { application_id, guild_id }
+        => GET "applications/{application_id}/guilds/{guild_id}/commands/permissions",
+    /// <https://discord.com/developers/docs/interactions/application-commands#get-application-command-permissions>
+    GetApplicationCommandPermissions { application_id, guild_id, command_id }
+        => GET "applications/{application_id}/guilds/{guild_id}/commands/{command_id}/permissions",
+    /// <https://discord.com/developers/docs/interactions/application-commands#edit-application-command-permissions>
+    EditApplicationCommandPermissions { application_id, guild_id, command_id }
+        => PUT "applications/{application_id}/guilds/{guild_id}/commands/{command_id}/permissions",
+
+    // ===== Interaction Responses =====
+    /// <https://discord.com/developers/docs/interactions/receiving-and-responding#create-interaction-response>
+    CreateInteractionResponse { interaction_id, interaction_token; with_response: bool }
+        => POST "interactions/{interaction_id}/{interaction_token}/callback" [interaction],
+    /// <https://discord.com/developers/docs/interactions/receiving-and-responding#get-original-interaction-response>
+    GetOriginalInteractionResponse { application_id, interaction_token }
+        => GET "webhooks/{application_id}/{interaction_token}/messages/@original" [interaction],
+    /// <https://discord.com/developers/docs/interactions/receiving-and-responding#edit-original-interaction-response>
+    EditOriginalInteractionResponse { application_id, interaction_token }
+        => PATCH "webhooks/{application_id}/{interaction_token}/messages/@original" [interaction],
+    /// <https://discord.com/developers/docs/interactions/receiving-and-responding#delete-original-interaction-response>
+    DeleteOriginalInteractionResponse { application_id, interaction_token }
+        => DELETE "webhooks/{application_id}/{interaction_token}/messages/@original" [interaction],
+    /// <https://discord.com/developers/docs/interactions/receiving-and-responding#create-followup-message>
+    CreateFollowupMessage { application_id, interaction_token }
+        => POST "webhooks/{application_id}/{interaction_token}" [interaction],
+    /// <https://discord.com/developers/docs/interactions/receiving-and-responding#get-followup-message>
+    GetFollowupMessage { application_id, interaction_token, message_id }
+        => GET "webhooks/{application_id}/{interaction_token}/messages/{message_id}" [interaction],
+    /// <https://discord.com/developers/docs/interactions/receiving-and-responding#edit-followup-message>
+    EditFollowupMessage { application_id, interaction_token, message_id }
+        => PATCH "webhooks/{application_id}/{interaction_token}/messages/{message_id}" [interaction],
+    /// <https://discord.com/developers/docs/interactions/receiving-and-responding#delete-followup-message>
+    DeleteFollowupMessage { application_id, interaction_token, message_id }
+        => DELETE "webhooks/{application_id}/{interaction_token}/messages/{message_id}" [interaction],
+
+    // ===== Monetization =====
+    /// <https://discord.com/developers/docs/resources/sku#list-skus>
+    ListSkus { application_id } => GET "applications/{application_id}/skus",
+    /// <https://discord.com/developers/docs/resources/entitlement#list-entitlements>
+    ListEntitlements { application_id; user_id: &'a str, sku_ids: &'a str, before: &'a str, after: &'a str, limit: u8, guild_id: &'a str, exclude_ended: bool }
+        => GET "applications/{application_id}/entitlements",
+    /// <https://discord.com/developers/docs/resources/entitlement#get-entitlement>
+    GetEntitlement { application_id, entitlement_id }
+        => GET "applications/{application_id}/entitlements/{entitlement_id}",
+    /// <https://discord.com/developers/docs/resources/entitlement#consume-an-entitlement>
+    ConsumeEntitlement { application_id, entitlement_id }
+        => POST "applications/{application_id}/entitlements/{entitlement_id}/consume",
+    /// <https://discord.com/developers/docs/resources/entitlement#create-test-entitlement>
+    CreateTestEntitlement { application_id } => POST "applications/{application_id}/entitlements",
+    /// <https://discord.com/developers/docs/resources/entitlement#delete-test-entitlement>
+    DeleteTestEntitlement { application_id, entitlement_id }
+        => DELETE "applications/{application_id}/entitlements/{entitlement_id}",
+    /// <https://discord.com/developers/docs/resources/subscription#list-sku-subscriptions>
+    ListSkuSubscriptions { sku_id; before: &'a str, after: &'a str, limit: u8, user_id: &'a str }
+        => GET "skus/{sku_id}/subscriptions",
+    /// <https://discord.com/developers/docs/resources/subscription#get-sku-subscription>
+    GetSkuSubscription { sku_id, subscription_id }
+        => GET "skus/{sku_id}/subscriptions/{subscription_id}",
+
+    // ===== Soundboard =====
+    /// <https://discord.com/developers/docs/resources/soundboard#send-soundboard-sound>
+    SendSoundboardSound { channel_id } => POST "channels/{channel_id}/send-soundboard-sound",
+    /// <https://discord.com/developers/docs/resources/soundboard#list-default-soundboard-sounds>
+    ListDefaultSoundboardSounds {} => GET "soundboard-default-sounds",
+    /// <https://discord.com/developers/docs/resources/soundboard#list-guild-soundboard-sounds>
+    ListGuildSoundboardSounds { guild_id } => GET "guilds/{guild_id}/soundboard-sounds",
+    /// <https://discord.com/developers/docs/resources/soundboard#get-guild-soundboard-sound>
+    GetGuildSoundboardSound { guild_id, sound_id }
+        => GET "guilds/{guild_id}/soundboard-sounds/{sound_id}",
+    /// <https://discord.com/developers/docs/resources/soundboard#create-guild-soundboard-sound>
+    CreateGuildSoundboardSound { guild_id }
+        => POST "guilds/{guild_id}/soundboard-sounds" [reason],
+    /// <https://discord.com/developers/docs/resources/soundboard#modify-guild-soundboard-sound>
+    ModifyGuildSoundboardSound { guild_id, sound_id }
+        => PATCH "guilds/{guild_id}/soundboard-sounds/{sound_id}" [reason],
+    /// <https://discord.com/developers/docs/resources/soundboard#delete-guild-soundboard-sound>
+    DeleteGuildSoundboardSound { guild_id, sound_id }
+        => DELETE "guilds/{guild_id}/soundboard-sounds/{sound_id}" [reason],
+
+    // ===== Gateway =====
+    /// <https://discord.com/developers/docs/events/gateway#get-gateway>
+    GetGateway {} => GET "gateway",
+    /// <https://discord.com/developers/docs/events/gateway#get-gateway-bot>
+    GetGatewayBot {} => GET "gateway/bot",
+
+    // ===== OAuth2 =====
+    /// <https://discord.com/developers/docs/topics/oauth2#get-current-bot-application-information>
+    GetCurrentBotApplicationInformation {} => GET "oauth2/applications/@me",
+    /// <https://discord.com/developers/docs/topics/oauth2#get-current-authorization-information>
+    GetCurrentAuthorizationInformation {} => GET "oauth2/@me",
+}
+
+impl Route<'_> {
+    /// The parameter that rate limits are scoped to: the channel, guild or webhook of the request.
+    pub fn major_parameter(&self) -> String {
+        let mut major = String::new();
+        for (name, value) in self.params() {
+            match name {
+                "channel_id" | "guild_id" | "webhook_id" if major.is_empty() => {
+                    major.push_str(value)
+                }
+                // every webhook and interaction token has its own limits
+                "webhook_token" | "interaction_token" => {
+                    major.push('/');
+                    major.push_str(value);
+                }
+                _ => {}
+            }
+        }
+        major
+    }
+}
+
+/// Percent-encode a path parameter or header value, e.g. a unicode emoji.
+pub(crate) fn percent_encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b':' | b'@' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+/// Query string of the optional parameters that are set.
+#[derive(Default)]
+struct Query {
+    pairs: Vec<(&'static str, String)>,
+}
+
+impl Query {
+    fn push<T: Display>(&mut self, name: &'static str, value: &Option<T>) {
+        if let Some(value) = value {
+            // `type_` is sent as `type`
+            self.pairs
+                .push((name.trim_end_matches('_'), value.to_string()));
+        }
+    }
+
+    fn finish(self) -> String {
+        if self.pairs.is_empty() {
+            return String::new();
+        }
+        let mut query = url::form_urlencoded::Serializer::for_suffix(String::from("?"), 1);
+        query.extend_pairs(self.pairs);
+        query.finish()
+    }
+}
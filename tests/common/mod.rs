@@ -0,0 +1,53 @@
+//! Mock server fixtures shared by the REST tests
+#![allow(dead_code)]
+
+use disruption::channel::Channel;
+use disruption::{RestClient, RetryPolicy};
+use mockito::ServerGuard;
+use serde_json::{json, Value};
+
+/// Text channel `1`
+pub const CHANNEL: &str = r#"{ "id": "1", "type": 0, "name": "general" }"#;
+
+/// A client for the mock server that returns errors right away instead of retrying.
+pub fn client(server: &ServerGuard) -> RestClient {
+    RestClient::builder("token")
+        .base_url(server.url())
+        .retry_policy(RetryPolicy::none())
+        .build()
+}
+
+/// Fetch channel `1` from the mock server.
+pub async fn channel(server: &mut ServerGuard) -> Channel {
+    server
+        .mock("GET", "/v10/channels/1")
+        .with_body(CHANNEL)
+        .create_async()
+        .await;
+    Channel::from_id(client(server), "1").await.unwrap()
+}
+
+/// A message of channel `1` sent by the bot.
+pub fn message(id: impl ToString, content: &str) -> Value {
+    json!({
+        "id": id.to_string(),
+        "channel_id": "1",
+        "author": { "id": "3", "username": "bot", "discriminator": "0", "avatar": null },
+        "content": content,
+        "timestamp": "2016-03-24T23:15:59.605000+00:00",
+        "edited_timestamp": null,
+        "tts": false,
+        "mention_everyone": false,
+        "mentions": [],
+        "mention_roles": [],
+        "attachments": [],
+        "embeds": [],
+        "pinned": false,
+        "type": 0
+    })
+}
+
+/// A user with the given id.
+pub fn user(id: u64) -> Value {
+    json!({ "id": id.to_string(), "username": format!("user{id}"), "discriminator": "0", "avatar": null })
+}
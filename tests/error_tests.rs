@@ -41,11 +41,98 @@ fn test_rest_api_error() {
     let err = RestError::ApiError {
         status: 404,
         message: "Not Found".to_string(),
+        error: None,
     };
 
     assert_eq!(err.to_string(), "Discord API error (status 404): Not Found");
 }
 
+#[test]
+fn test_rest_error_from_response() {
+    use disruption_types::opcodes::JsonErrorCode;
+
+    let body = br#"{
+        "code": 50035,
+        "message": "Invalid Form Body",
+        "errors": { "content": { "_errors": [{ "code": "BASE_TYPE_MAX_LENGTH", "message": "Must be 2000 or fewer in length." }] } }
+    }"#;
+    let err = RestError::from_response(400, "channels/1/messages", body);
+
+    assert_eq!(err.code(), Some(JsonErrorCode::InvalidFormBody));
+    assert_eq!(
+        err.to_string(),
+        "Discord API error (status 400): Invalid Form Body (code 50035): content: Must be 2000 or fewer in length."
+    );
+
+    let err = RestError::from_response(502, "gateway", b"");
+    assert_eq!(
+        err.to_string(),
+        "Discord API error (status 502): Bad Gateway"
+    );
+    assert_eq!(err.code(), None);
+}
+
+#[test]
+fn test_rest_error_status_mapping() {
+    use disruption_types::opcodes::JsonErrorCode;
+
+    let err = RestError::from_response(
+        401,
+        "users/@me",
+        br#"{"message": "401: Unauthorized", "code": 0}"#,
+    );
+    assert!(matches!(err, RestError::AuthenticationFailed));
+
+    let err = RestError::from_response(
+        403,
+        "channels/1/messages",
+        br#"{"message": "Missing Permissions", "code": 50013}"#,
+    );
+    assert!(
+        matches!(&err, RestError::MissingPermission { message, .. } if message == "Missing Permissions")
+    );
+    assert_eq!(err.code(), Some(JsonErrorCode::MissingPermissions));
+
+    // other 403s are kept with their code
+    let err = RestError::from_response(
+        403,
+        "channels/1/messages",
+        br#"{"message": "Cannot send messages to this user", "code": 50007}"#,
+    );
+    assert!(matches!(err, RestError::ApiError { status: 403, .. }));
+
+    let err = RestError::from_response(
+        404,
+        "channels/1/messages/2",
+        br#"{"message": "Unknown Message", "code": 10008}"#,
+    );
+    assert_eq!(err.to_string(), "Resource not found: message with ID 2");
+    assert_eq!(err.code(), Some(JsonErrorCode::UnknownMessage));
+
+    let err = RestError::from_response(
+        429,
+        "channels/1/messages",
+        br#"{"message": "You are being rate limited.", "retry_after": 1.337, "global": false}"#,
+    );
+    assert!(matches!(err, RestError::RateLimited { retry_after: 2 }));
+}
+
+#[test]
+fn test_rest_validation_error() {
+    use disruption_types::channel::CreateMessageApiType;
+    use disruption_types::validation::Validate;
+
+    let errors = CreateMessageApiType::from("a".repeat(2001))
+        .validate()
+        .unwrap_err();
+    let err: RestError = errors.into();
+
+    assert_eq!(
+        err.to_string(),
+        "Invalid payload: content: 2001 characters exceed the limit of 2000"
+    );
+}
+
 #[test]
 fn test_rest_rate_limited_error() {
     let err = RestError::RateLimited { retry_after: 30 };
@@ -61,6 +148,7 @@ fn test_rest_not_found_error() {
     let err = RestError::NotFound {
         resource_type: "Channel".to_string(),
         id: "123456789".to_string(),
+        error: None,
     };
 
     assert_eq!(
@@ -81,9 +169,22 @@ fn test_rest_invalid_request_error() {
 
 #[test]
 fn test_rest_missing_permission_error() {
-    let err = RestError::MissingPermission("SEND_MESSAGES".to_string());
+    let err = RestError::MissingPermission {
+        message: "Missing Permissions".to_string(),
+        error: None,
+        required: None,
+    };
+    assert_eq!(err.to_string(), "Missing permission: Missing Permissions");
 
-    assert_eq!(err.to_string(), "Missing permission: SEND_MESSAGES");
+    let err = RestError::MissingPermission {
+        message: "Missing Permissions".to_string(),
+        error: None,
+        required: Some("BAN_MEMBERS".to_string()),
+    };
+    assert_eq!(
+        err.to_string(),
+        "Missing permission: Missing Permissions (requires BAN_MEMBERS and a role above the target)"
+    );
 }
 
 #[test]
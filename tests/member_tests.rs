@@ -0,0 +1,339 @@
+//! Tests for managing guild members and bans against a mock server
+
+mod common;
+
+use common::*;
+use disruption::{Pagination, RestError};
+use disruption_types::entities::{
+    BulkGuildBanApiType, CreateGuildBanApiType, ModifyGuildMemberApiType,
+};
+use disruption_types::nullable::Nullable;
+use disruption_types::opcodes::JsonErrorCode;
+use futures::TryStreamExt;
+use mockito::{Matcher, Server};
+use serde_json::{json, Value};
+
+fn member(id: u64) -> Value {
+    json!({
+        "user": user(id),
+        "roles": [],
+        "joined_at": "2016-03-24T23:15:59.605000+00:00",
+        "deaf": false,
+        "mute": false
+    })
+}
+
+fn members(ids: impl Iterator<Item = u64>) -> String {
+    Value::Array(ids.map(member).collect()).to_string()
+}
+
+fn bans(ids: impl Iterator<Item = u64>) -> String {
+    Value::Array(
+        ids.map(|id| json!({ "reason": null, "user": user(id) }))
+            .collect(),
+    )
+    .to_string()
+}
+
+const MISSING_PERMISSIONS: &str = r#"{"message": "Missing Permissions", "code": 50013}"#;
+
+#[tokio::test]
+async fn test_members_are_listed_in_pages() {
+    let mut server = Server::new_async().await;
+    let mocks = [
+        server
+            .mock("GET", "/v10/guilds/1/members")
+            .match_query(Matcher::UrlEncoded("after".into(), "0".into()))
+            .with_body(members(1..=1000))
+            .create_async()
+            .await,
+        server
+            .mock("GET", "/v10/guilds/1/members")
+            .match_query(Matcher::UrlEncoded("after".into(), "1000".into()))
+            .with_body(members(1001..=1002))
+            .create_async()
+            .await,
+    ];
+
+    let ids: Vec<String> = client(&server)
+        .guild_members("1", Pagination::new())
+        .map_ok(|member| member.user.unwrap().id)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(ids.len(), 1002);
+    assert_eq!(ids.last().map(String::as_str), Some("1002"));
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_members_can_not_be_paged_backwards() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/v10/guilds/1/members")
+        .match_query(Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let err = client(&server)
+        .guild_members("1", Pagination::new().before(1000))
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RestError::InvalidRequest(_)));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_search_members() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/v10/guilds/1/members/search")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("query".into(), "user 1".into()),
+            Matcher::UrlEncoded("limit".into(), "10".into()),
+        ]))
+        .with_body(members(1..=1))
+        .create_async()
+        .await;
+
+    let found = client(&server)
+        .search_guild_members("1", "user 1", 10)
+        .await
+        .unwrap();
+    assert_eq!(found.len(), 1);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_modify_member_sends_only_changed_fields() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("PATCH", "/v10/guilds/1/members/2")
+        .match_header("X-Audit-Log-Reason", "muted%20for%20spam")
+        .match_body(Matcher::Json(json!({ "mute": true, "nick": null })))
+        .with_body(member(2).to_string())
+        .create_async()
+        .await;
+
+    let member = ModifyGuildMemberApiType {
+        mute: Nullable::Value(true),
+        nick: Nullable::Null,
+        ..Default::default()
+    };
+    client(&server)
+        .modify_guild_member("1", "2", &member, Some("muted for spam"))
+        .await
+        .unwrap();
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_timeout_member() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("PATCH", "/v10/guilds/1/members/2")
+        .match_body(Matcher::Json(
+            json!({ "communication_disabled_until": null }),
+        ))
+        .with_body(member(2).to_string())
+        .create_async()
+        .await;
+
+    client(&server)
+        .timeout_guild_member("1", "2", None, None)
+        .await
+        .unwrap();
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_missing_permissions_name_the_required_permissions() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("PATCH", "/v10/guilds/1/members/2")
+        .with_status(403)
+        .with_body(MISSING_PERMISSIONS)
+        .create_async()
+        .await;
+    server
+        .mock("PUT", "/v10/guilds/1/members/2/roles/3")
+        .with_status(403)
+        .with_body(MISSING_PERMISSIONS)
+        .create_async()
+        .await;
+    server
+        .mock("DELETE", "/v10/guilds/1/members/2")
+        .with_status(403)
+        .with_body(MISSING_PERMISSIONS)
+        .create_async()
+        .await;
+    server
+        .mock("GET", "/v10/guilds/1/bans")
+        .match_query(Matcher::Any)
+        .with_status(403)
+        .with_body(MISSING_PERMISSIONS)
+        .create_async()
+        .await;
+    server
+        .mock("DELETE", "/v10/guilds/1/bans/2")
+        .with_status(403)
+        .with_body(MISSING_PERMISSIONS)
+        .create_async()
+        .await;
+    let rest = client(&server);
+
+    let member = ModifyGuildMemberApiType {
+        nick: Nullable::Value("nick".into()),
+        deaf: Nullable::Value(true),
+        ..Default::default()
+    };
+    let err = rest
+        .modify_guild_member("1", "2", &member, None)
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Some(JsonErrorCode::MissingPermissions));
+    assert!(matches!(
+        &err,
+        RestError::MissingPermission { message, required: Some(required), .. }
+            if message == "Missing Permissions" && required == "MANAGE_NICKNAMES, DEAFEN_MEMBERS"
+    ));
+
+    let err = rest
+        .add_guild_member_role("1", "2", "3", None)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        &err,
+        RestError::MissingPermission { required: Some(required), .. } if required == "MANAGE_ROLES"
+    ));
+
+    let err = rest
+        .remove_guild_member("1", "2", Some("kick"))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        &err,
+        RestError::MissingPermission { required: Some(required), .. } if required == "KICK_MEMBERS"
+    ));
+
+    let err = rest.get_guild_bans("1", None, 10).await.unwrap_err();
+    assert!(matches!(
+        &err,
+        RestError::MissingPermission { required: Some(required), .. } if required == "BAN_MEMBERS"
+    ));
+    let err = rest.remove_guild_ban("1", "2", None).await.unwrap_err();
+    assert!(matches!(
+        &err,
+        RestError::MissingPermission { required: Some(required), .. } if required == "BAN_MEMBERS"
+    ));
+
+    // changes that need no particular permission do not name any
+    let err = rest
+        .modify_guild_member("1", "2", &ModifyGuildMemberApiType::default(), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        &err,
+        RestError::MissingPermission { required: None, .. }
+    ));
+}
+
+#[tokio::test]
+async fn test_ban_and_unban() {
+    let mut server = Server::new_async().await;
+    let mocks = [
+        server
+            .mock("PUT", "/v10/guilds/1/bans/2")
+            .match_header("X-Audit-Log-Reason", "spam")
+            .match_body(Matcher::Json(json!({ "delete_message_seconds": 3600 })))
+            .with_status(204)
+            .create_async()
+            .await,
+        server
+            .mock("DELETE", "/v10/guilds/1/bans/2")
+            .with_status(204)
+            .create_async()
+            .await,
+    ];
+    let rest = client(&server);
+
+    rest.create_guild_ban(
+        "1",
+        "2",
+        &CreateGuildBanApiType::delete_messages(3600),
+        Some("spam"),
+    )
+    .await
+    .unwrap();
+    rest.remove_guild_ban("1", "2", None).await.unwrap();
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+
+    // bans deleting more than a week of messages are rejected before sending
+    let err = rest
+        .create_guild_ban(
+            "1",
+            "2",
+            &CreateGuildBanApiType::delete_messages(604_801),
+            None,
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, RestError::Validation(_)));
+}
+
+#[tokio::test]
+async fn test_bans_are_listed_backwards() {
+    let mut server = Server::new_async().await;
+    let mocks = [
+        server
+            .mock("GET", "/v10/guilds/1/bans")
+            .match_query(Matcher::UrlEncoded("before".into(), "3000".into()))
+            .with_body(bans(2000..3000))
+            .create_async()
+            .await,
+        server
+            .mock("GET", "/v10/guilds/1/bans")
+            .match_query(Matcher::UrlEncoded("before".into(), "2000".into()))
+            .with_body(bans(1998..2000))
+            .create_async()
+            .await,
+    ];
+
+    let ids: Vec<String> = client(&server)
+        .guild_bans("1", Pagination::new().before(3000))
+        .map_ok(|ban| ban.user.id)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(ids.len(), 1002);
+    assert_eq!(ids.first().map(String::as_str), Some("2999"));
+    assert_eq!(ids.last().map(String::as_str), Some("1998"));
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_bulk_ban() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/v10/guilds/1/bulk-ban")
+        .match_body(Matcher::Json(json!({ "user_ids": ["2", "3"] })))
+        .with_body(r#"{ "banned_users": ["2"], "failed_users": ["3"] }"#)
+        .create_async()
+        .await;
+
+    let response = client(&server)
+        .bulk_guild_ban("1", &BulkGuildBanApiType::new(["2", "3"]), None)
+        .await
+        .unwrap();
+    assert_eq!(response.banned_users, ["2"]);
+    assert_eq!(response.failed_users, ["3"]);
+    mock.assert_async().await;
+}
@@ -0,0 +1,139 @@
+//! Tests for editing, deleting and pinning messages against a mock server
+
+use std::time::Duration;
+
+mod common;
+
+use common::*;
+use disruption_types::timestamp::Timestamp;
+use mockito::{Matcher, Server};
+use serde_json::json;
+
+/// Snowflake id of a message that was sent the given time ago.
+fn snowflake(age: Duration, increment: u64) -> String {
+    let id: u64 = (Timestamp::now() - age)
+        .to_snowflake()
+        .unwrap()
+        .parse()
+        .unwrap();
+    (id + increment).to_string()
+}
+
+#[tokio::test]
+async fn test_message_lifecycle() {
+    let mut server = Server::new_async().await;
+    let channel = channel(&mut server).await;
+    server
+        .mock("GET", "/v10/channels/1/messages/2")
+        .with_body(message("2", "hello").to_string())
+        .create_async()
+        .await;
+    let edit = server
+        .mock("PATCH", "/v10/channels/1/messages/2")
+        .match_body(Matcher::Json(json!({ "content": "edited" })))
+        .with_body(message("2", "edited").to_string())
+        .create_async()
+        .await;
+    let pin = server
+        .mock("PUT", "/v10/channels/1/messages/pins/2")
+        .match_header("x-audit-log-reason", "important")
+        .with_status(204)
+        .create_async()
+        .await;
+    let unpin = server
+        .mock("DELETE", "/v10/channels/1/messages/pins/2")
+        .with_status(204)
+        .create_async()
+        .await;
+    let crosspost = server
+        .mock("POST", "/v10/channels/1/messages/2/crosspost")
+        .with_body(message("2", "edited").to_string())
+        .create_async()
+        .await;
+    let delete = server
+        .mock("DELETE", "/v10/channels/1/messages/2")
+        .match_header("x-audit-log-reason", "cleanup")
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let mut message = channel.fetch_message("2").await.unwrap();
+    assert_eq!(message.content(), "hello");
+    assert_eq!(message.channel().as_ref().unwrap().id(), "1");
+
+    message.edit("edited").await.unwrap();
+    assert_eq!(message.content(), "edited");
+    message.pin(Some("important")).await.unwrap();
+    message.unpin(None).await.unwrap();
+    message.crosspost().await.unwrap();
+    message.delete(Some("cleanup")).await.unwrap();
+
+    edit.assert_async().await;
+    pin.assert_async().await;
+    unpin.assert_async().await;
+    crosspost.assert_async().await;
+    delete.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_edit_is_validated() {
+    let mut server = Server::new_async().await;
+    let channel = channel(&mut server).await;
+    server
+        .mock("GET", "/v10/channels/1/messages/2")
+        .with_body(message("2", "hello").to_string())
+        .create_async()
+        .await;
+    let edit = server
+        .mock("PATCH", "/v10/channels/1/messages/2")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let mut message = channel.fetch_message("2").await.unwrap();
+    assert!(message.edit("a".repeat(2001)).await.is_err());
+    assert_eq!(message.content(), "hello");
+    edit.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_bulk_delete_chunks_and_falls_back() {
+    let mut server = Server::new_async().await;
+    let channel = channel(&mut server).await;
+
+    let recent: Vec<String> = (0..101)
+        .map(|i| snowflake(Duration::from_secs(60), i))
+        .collect();
+    let old = snowflake(Duration::from_secs(15 * 24 * 60 * 60), 0);
+
+    let bulk = server
+        .mock("POST", "/v10/channels/1/messages/bulk-delete")
+        .match_header("x-audit-log-reason", "raid")
+        .match_body(Matcher::Json(json!({ "messages": recent[..100] })))
+        .with_status(204)
+        .expect(1)
+        .create_async()
+        .await;
+    let single = server
+        .mock(
+            "DELETE",
+            Matcher::Regex(format!(
+                "^/v10/channels/1/messages/({}|{old})$",
+                recent[100]
+            )),
+        )
+        .match_header("x-audit-log-reason", "raid")
+        .with_status(204)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let mut ids = recent.clone();
+    ids.push(old.clone());
+    // duplicates are only deleted once
+    ids.push(recent[0].clone());
+    channel.bulk_delete(&ids, Some("raid")).await.unwrap();
+
+    bulk.assert_async().await;
+    single.assert_async().await;
+}
@@ -0,0 +1,268 @@
+//! Tests for paging through list endpoints against a mock server
+
+mod common;
+
+use common::*;
+use disruption::{Pagination, RestError};
+use disruption_types::timestamp::Timestamp;
+use futures::{StreamExt, TryStreamExt};
+use mockito::{Matcher, Server};
+use serde_json::{json, Value};
+
+/// A page of messages with the given ids, newest first as Discord returns them.
+fn page(ids: impl DoubleEndedIterator<Item = u64>) -> String {
+    Value::Array(
+        ids.rev()
+            .map(|id| message(id, &format!("message {id}")))
+            .collect(),
+    )
+    .to_string()
+}
+
+fn query(pairs: &[(&str, &str)]) -> Matcher {
+    Matcher::AllOf(
+        pairs
+            .iter()
+            .map(|(key, value)| Matcher::UrlEncoded(key.to_string(), value.to_string()))
+            .collect(),
+    )
+}
+
+#[tokio::test]
+async fn test_history_pages_backwards_until_limit() {
+    let mut server = Server::new_async().await;
+    let channel = channel(&mut server).await;
+    let first = server
+        .mock("GET", "/v10/channels/1/messages")
+        .match_query(Matcher::UrlEncoded("limit".into(), "100".into()))
+        .with_body(page(1001..=1100))
+        .expect(1)
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", "/v10/channels/1/messages")
+        .match_query(query(&[("before", "1001"), ("limit", "50")]))
+        .with_body(page(951..=1000))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let ids: Vec<String> = channel
+        .messages(Pagination::new().limit(150))
+        .map_ok(|message| message.id().to_owned())
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(ids.len(), 150);
+    assert_eq!(ids.first().unwrap(), "1100");
+    assert_eq!(ids.last().unwrap(), "951");
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_history_pages_forwards_until_snapshot() {
+    let mut server = Server::new_async().await;
+    let channel = channel(&mut server).await;
+    let first = server
+        .mock("GET", "/v10/channels/1/messages")
+        .match_query(query(&[("after", "0"), ("limit", "100")]))
+        .with_body(page(1..=100))
+        .expect(1)
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", "/v10/channels/1/messages")
+        .match_query(query(&[("after", "100"), ("limit", "100")]))
+        .with_body(page(101..=200))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let ids: Vec<String> = channel
+        .messages(Pagination::new().after(0).until_id("150"))
+        .map_ok(|message| message.id().to_owned())
+        .try_collect()
+        .await
+        .unwrap();
+
+    // oldest first, stopping before the snapshot
+    assert_eq!(ids.len(), 149);
+    assert_eq!(ids.first().unwrap(), "1");
+    assert_eq!(ids.last().unwrap(), "149");
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_history_stops_at_time_and_short_page() {
+    let mut server = Server::new_async().await;
+    let channel = channel(&mut server).await;
+    let boundary = Timestamp::from_unix_secs(1_600_000_000);
+    let boundary_id: u64 = boundary.to_snowflake().unwrap().parse().unwrap();
+
+    let mock = server
+        .mock("GET", "/v10/channels/1/messages")
+        .match_query(Matcher::UrlEncoded("limit".into(), "100".into()))
+        .with_body(page(boundary_id - 2..=boundary_id + 2))
+        .expect(1)
+        .create_async()
+        .await;
+    let newer: Vec<_> = channel
+        .messages(Pagination::new().until_time(boundary))
+        .collect()
+        .await;
+    assert_eq!(newer.len(), 2);
+    mock.assert_async().await;
+
+    let around = server
+        .mock("GET", "/v10/channels/1/messages")
+        .match_query(query(&[("around", "5"), ("limit", "100")]))
+        .with_body(page(1..=9))
+        .expect(1)
+        .create_async()
+        .await;
+    let count = channel.messages(Pagination::new().around(5)).count().await;
+    assert_eq!(count, 9);
+    around.assert_async().await;
+}
+
+/// A page of archived threads, archived on consecutive days of March 2016, latest first.
+fn threads(days: &[u32], has_more: bool) -> String {
+    let threads: Vec<Value> = days
+        .iter()
+        .map(|day| {
+            json!({
+                "id": day.to_string(),
+                "type": 11,
+                "thread_metadata": {
+                    "archived": true,
+                    "auto_archive_duration": 60,
+                    "archive_timestamp": format!("2016-03-{day:02}T12:00:00+00:00"),
+                    "locked": false
+                }
+            })
+        })
+        .collect();
+    json!({ "threads": threads, "members": [], "has_more": has_more }).to_string()
+}
+
+#[tokio::test]
+async fn test_archived_threads_page_while_more_are_reported() {
+    let mut server = Server::new_async().await;
+    let mocks = [
+        server
+            .mock("GET", "/v10/channels/1/threads/archived/public")
+            .match_query(Matcher::UrlEncoded("limit".into(), "100".into()))
+            .with_body(threads(&[24, 23], true))
+            .create_async()
+            .await,
+        server
+            .mock("GET", "/v10/channels/1/threads/archived/public")
+            .match_query(query(&[
+                ("before", "2016-03-23T12:00:00+00:00"),
+                ("limit", "100"),
+            ]))
+            .with_body(threads(&[22], false))
+            .create_async()
+            .await,
+    ];
+    let rest = client(&server);
+
+    // a short page does not end the listing while Discord reports more threads
+    let ids: Vec<String> = rest
+        .public_archived_threads("1", Pagination::new())
+        .map_ok(|thread| thread.id)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(ids, ["24", "23", "22"]);
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+
+    // archive timestamps are compared with the time boundary
+    let until = "2016-03-23T00:00:00+00:00".parse::<Timestamp>().unwrap();
+    let ids: Vec<String> = rest
+        .public_archived_threads("1", Pagination::new().until_time(until))
+        .map_ok(|thread| thread.id)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(ids, ["24", "23"]);
+
+    let error = rest
+        .private_archived_threads("1", Pagination::new().after(0))
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap_err();
+    assert!(matches!(error, RestError::InvalidRequest(_)));
+}
+
+/// An audit log with entries of the given ids, newest first as Discord returns them.
+fn audit_log(ids: impl DoubleEndedIterator<Item = u64>) -> String {
+    let entries: Vec<Value> = ids
+        .rev()
+        .map(|id| json!({ "id": id.to_string(), "action_type": 22, "user_id": "3" }))
+        .collect();
+    json!({
+        "application_commands": [],
+        "audit_log_entries": entries,
+        "auto_moderation_rules": [],
+        "guild_scheduled_events": [],
+        "integrations": [],
+        "threads": [],
+        "users": [],
+        "webhooks": []
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn test_audit_log_entries_page_in_both_directions() {
+    let mut server = Server::new_async().await;
+    let mocks = [
+        server
+            .mock("GET", "/v10/guilds/1/audit-logs")
+            .match_query(Matcher::UrlEncoded("limit".into(), "100".into()))
+            .with_body(audit_log(1001..=1100))
+            .create_async()
+            .await,
+        server
+            .mock("GET", "/v10/guilds/1/audit-logs")
+            .match_query(query(&[("before", "1001"), ("limit", "100")]))
+            .with_body(audit_log(991..=1000))
+            .create_async()
+            .await,
+        server
+            .mock("GET", "/v10/guilds/1/audit-logs")
+            .match_query(query(&[("after", "995"), ("limit", "3")]))
+            .with_body(audit_log(996..=998))
+            .create_async()
+            .await,
+    ];
+    let rest = client(&server);
+
+    let ids: Vec<String> = rest
+        .guild_audit_log_entries("1", Pagination::new())
+        .map_ok(|entry| entry.id)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(ids.len(), 110);
+    assert_eq!(ids.first().unwrap(), "1100");
+    assert_eq!(ids.last().unwrap(), "991");
+
+    // paging forwards yields the oldest entries first
+    let ids: Vec<String> = rest
+        .guild_audit_log_entries("1", Pagination::new().after(995).limit(3))
+        .map_ok(|entry| entry.id)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(ids, ["996", "997", "998"]);
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+}
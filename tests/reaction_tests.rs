@@ -0,0 +1,116 @@
+//! Tests for reacting to messages against a mock server
+
+mod common;
+
+use common::*;
+use disruption::channel::Channel;
+use disruption::Pagination;
+use disruption_types::channel::{ReactionType, ReactionTypeApiType};
+use futures::TryStreamExt;
+use mockito::{Matcher, Server, ServerGuard};
+use serde_json::Value;
+
+fn users(ids: impl Iterator<Item = u64>) -> String {
+    Value::Array(ids.map(user).collect()).to_string()
+}
+
+/// Fetch channel `1` with message `2` available to react to.
+async fn channel_with_message(server: &mut ServerGuard) -> Channel {
+    server
+        .mock("GET", "/v10/channels/1/messages/2")
+        .with_body(message(2, "vote!").to_string())
+        .create_async()
+        .await;
+    channel(server).await
+}
+
+#[tokio::test]
+async fn test_reactions_are_encoded() {
+    let mut server = Server::new_async().await;
+    let channel = channel_with_message(&mut server).await;
+    let mocks = [
+        server
+            .mock(
+                "PUT",
+                "/v10/channels/1/messages/2/reactions/%F0%9F%91%8D/@me",
+            )
+            .with_status(204),
+        server
+            .mock(
+                "DELETE",
+                "/v10/channels/1/messages/2/reactions/blob:123/@me",
+            )
+            .with_status(204),
+        server
+            .mock("DELETE", "/v10/channels/1/messages/2/reactions/blob:123/4")
+            .with_status(204),
+        server
+            .mock(
+                "DELETE",
+                "/v10/channels/1/messages/2/reactions/%F0%9F%94%A5",
+            )
+            .with_status(204),
+        server
+            .mock("DELETE", "/v10/channels/1/messages/2/reactions")
+            .with_status(204),
+    ];
+    let mut created = Vec::new();
+    for mock in mocks {
+        created.push(mock.expect(1).create_async().await);
+    }
+
+    let message = channel.fetch_message("2").await.unwrap();
+    let blob: ReactionType = "<:blob:123>".parse().unwrap();
+    message.react('👍').await.unwrap();
+    message.remove_own_reaction(blob.clone()).await.unwrap();
+    message.remove_user_reaction(blob, "4").await.unwrap();
+    message.remove_reactions_for_emoji('🔥').await.unwrap();
+    message.remove_all_reactions().await.unwrap();
+
+    for mock in created {
+        mock.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_reaction_users_are_paginated() {
+    let mut server = Server::new_async().await;
+    let channel = channel_with_message(&mut server).await;
+    let first = server
+        .mock("GET", "/v10/channels/1/messages/2/reactions/blob:123")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("type".into(), "1".into()),
+            Matcher::UrlEncoded("after".into(), "0".into()),
+            Matcher::UrlEncoded("limit".into(), "100".into()),
+        ]))
+        .with_body(users(1..=100))
+        .expect(1)
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", "/v10/channels/1/messages/2/reactions/blob:123")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("type".into(), "1".into()),
+            Matcher::UrlEncoded("after".into(), "100".into()),
+        ]))
+        .with_body(users(101..=120))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let message = channel.fetch_message("2").await.unwrap();
+    let users: Vec<_> = message
+        .reactions(
+            ReactionType::custom("blob", "123"),
+            ReactionTypeApiType::BURST,
+            Pagination::new(),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(users.len(), 120);
+    assert_eq!(users[119].id, "120");
+    first.assert_async().await;
+    second.assert_async().await;
+}
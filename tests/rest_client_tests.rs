@@ -0,0 +1,256 @@
+//! Tests for the REST client against a mock server
+
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use disruption::channel::Channel;
+use disruption::{Error, RestClient, RestError, RetryPolicy, Route};
+use disruption_types::channel::{
+    BulkDeleteMessagesApiType, ChannelTypeApiType, CreateChannelApiType, CreateMessageApiType,
+    EditMessageApiType,
+};
+use disruption_types::entities::CreateRoleApiType;
+use disruption_types::interactions::{
+    InteractionCallbackMessageApiType, InteractionResponseApiType,
+};
+use mockito::{Matcher, Server};
+
+const CHANNEL: &str = r#"{ "id": "1", "type": 0, "name": "general" }"#;
+
+fn client(server: &Server) -> RestClient {
+    RestClient::builder("token")
+        .base_url(server.url())
+        .retry_policy(RetryPolicy::none())
+        .build()
+}
+
+#[tokio::test]
+async fn test_builder_headers_and_url() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/v9/channels/1")
+        .match_header("authorization", "Bot token")
+        .match_header(
+            "user-agent",
+            Matcher::Regex(r"^DiscordBot \(https://github.com/H1ghBre4k3r/disruption, \d+\.\d+\.\d+\) cron/1\.0$".to_string()),
+        )
+        .with_body(CHANNEL)
+        .create_async()
+        .await;
+
+    let rest = RestClient::builder("token")
+        .base_url(format!("{}/", server.url()))
+        .api_version(9)
+        .user_agent_suffix("cron/1.0")
+        .timeout(Duration::from_secs(5))
+        .client(reqwest::Client::new())
+        .build();
+    let channel = Channel::from_id(rest, "1").await.unwrap();
+
+    assert_eq!(channel.id(), "1");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_error_status_is_reported() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/v10/channels/1")
+        .with_status(200)
+        .with_body(CHANNEL)
+        .create_async()
+        .await;
+    let send = server
+        .mock("POST", "/v10/channels/1/messages")
+        .match_body(Matcher::Json(serde_json::json!({ "content": "hi" })))
+        .with_status(403)
+        .with_body(r#"{ "message": "Missing Permissions", "code": 50013 }"#)
+        .create_async()
+        .await;
+
+    let channel = Channel::from_id(client(&server), "1").await.unwrap();
+    let err = channel.say("hi").await.unwrap_err();
+
+    assert!(matches!(
+        err,
+        Error::Rest(RestError::MissingPermission { message, .. }) if message == "Missing Permissions"
+    ));
+    send.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_rate_limited_request_is_retried() {
+    let mut server = Server::new_async().await;
+    let limited = server
+        .mock("GET", "/v10/channels/1")
+        .with_status(429)
+        .with_header("x-ratelimit-bucket", "abcd")
+        .with_header("x-ratelimit-remaining", "0")
+        .with_header("x-ratelimit-reset-after", "0.2")
+        .with_header("retry-after", "0.2")
+        .with_header("x-ratelimit-scope", "user")
+        .with_body(
+            r#"{ "message": "You are being rate limited.", "retry_after": 0.2, "global": false }"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let ok = server
+        .mock("GET", "/v10/channels/1")
+        .with_header("x-ratelimit-bucket", "abcd")
+        .with_header("x-ratelimit-remaining", "4")
+        .with_header("x-ratelimit-reset-after", "1")
+        .with_body(CHANNEL)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let start = Instant::now();
+    let response = client(&server)
+        .request(Route::GetChannel { channel_id: "1" })
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), 200);
+    assert!(start.elapsed() >= Duration::from_millis(200));
+    limited.assert_async().await;
+    ok.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_bucket_is_waited_for() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock(
+            "GET",
+            Matcher::Regex(r"^/v10/channels/1/messages/\d+$".to_string()),
+        )
+        .with_header("x-ratelimit-bucket", "messages")
+        .with_header("x-ratelimit-remaining", "0")
+        .with_header("x-ratelimit-reset-after", "0.3")
+        .with_body("{}")
+        .expect(2)
+        .create_async()
+        .await;
+
+    let rest = client(&server);
+    let start = Instant::now();
+    let (first, second) = tokio::join!(
+        rest.request(Route::GetChannelMessage {
+            channel_id: "1",
+            message_id: "2"
+        }),
+        rest.request(Route::GetChannelMessage {
+            channel_id: "1",
+            message_id: "3"
+        })
+    );
+
+    assert!(first.is_ok() && second.is_ok());
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_transient_failures_are_retried() {
+    let mut server = Server::new_async().await;
+    let unavailable = server
+        .mock("GET", "/v10/channels/1")
+        .with_status(503)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let retries = Arc::new(AtomicU32::new(0));
+    let counter = retries.clone();
+    let rest = RestClient::builder("token")
+        .base_url(server.url())
+        .retry_policy(
+            RetryPolicy::new()
+                .max_attempts(2)
+                .backoff(Duration::from_millis(10), Duration::from_millis(10))
+                .on_retry(move |event| {
+                    assert_eq!(event.method, "GET");
+                    assert_eq!(event.path, "channels/1");
+                    counter.fetch_add(1, Ordering::SeqCst);
+                }),
+        )
+        .build();
+    let err = rest
+        .request(Route::GetChannel { channel_id: "1" })
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, RestError::ApiError { status: 503, .. }));
+    assert_eq!(retries.load(Ordering::SeqCst), 1);
+    unavailable.assert_async().await;
+
+    // a POST may have been processed, so it is not sent again
+    let post = server
+        .mock("POST", "/v10/channels/1/messages")
+        .with_status(502)
+        .expect(1)
+        .create_async()
+        .await;
+    rest.request_json(
+        Route::CreateMessage { channel_id: "1" },
+        &serde_json::json!({ "content": "hi" }),
+    )
+    .await
+    .unwrap_err();
+    post.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_invalid_bodies_are_rejected_before_sending() {
+    let mut server = Server::new_async().await;
+    let mocks = [
+        server
+            .mock("POST", Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await,
+        server
+            .mock("PATCH", Matcher::Any)
+            .expect(0)
+            .create_async()
+            .await,
+    ];
+    let rest = client(&server);
+    let too_long = "a".repeat(2001);
+
+    let errors = [
+        rest.create_message("1", &CreateMessageApiType::from(too_long.clone()))
+            .await
+            .map(drop),
+        rest.edit_message("1", "2", &EditMessageApiType::from(too_long.clone()))
+            .await
+            .map(drop),
+        rest.bulk_delete_messages("1", &BulkDeleteMessagesApiType::new(["2"]), None)
+            .await,
+        rest.create_guild_channel(
+            "1",
+            &CreateChannelApiType::new(&too_long, ChannelTypeApiType::GUILD_TEXT),
+            None,
+        )
+        .await
+        .map(drop),
+        rest.create_guild_role("1", &CreateRoleApiType::new(&too_long), None)
+            .await
+            .map(drop),
+        rest.create_interaction_response(
+            "1",
+            "token",
+            &InteractionResponseApiType::message(InteractionCallbackMessageApiType::new(&too_long)),
+        )
+        .await,
+    ];
+
+    for error in errors {
+        assert!(matches!(error, Err(RestError::Validation(_))));
+    }
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+}
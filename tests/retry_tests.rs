@@ -0,0 +1,91 @@
+//! Tests for the retry policy of REST requests
+
+use std::time::Duration;
+
+use disruption::{RestError, RetryPolicy};
+
+fn server_error(status: u16) -> RestError {
+    RestError::ApiError {
+        status,
+        message: "Service Unavailable".to_string(),
+        error: None,
+    }
+}
+
+#[test]
+fn test_transient_errors() {
+    assert!(RetryPolicy::is_transient(&server_error(502)));
+    assert!(RetryPolicy::is_transient(&server_error(503)));
+    assert!(RetryPolicy::is_transient(&RestError::RateLimited {
+        retry_after: 1
+    }));
+
+    assert!(!RetryPolicy::is_transient(&server_error(400)));
+    assert!(!RetryPolicy::is_transient(&RestError::AuthenticationFailed));
+    assert!(!RetryPolicy::is_transient(&RestError::MissingPermission {
+        message: String::new(),
+        error: None,
+        required: None,
+    }));
+}
+
+#[test]
+fn test_exponential_backoff() {
+    let policy = RetryPolicy::new()
+        .max_attempts(5)
+        .backoff(Duration::from_millis(100), Duration::from_millis(300))
+        .jitter(false);
+    let error = server_error(503);
+
+    let delays: Vec<_> = (1..=5)
+        .map(|attempt| policy.retry_delay(attempt, &error, true))
+        .collect();
+    assert_eq!(
+        delays,
+        vec![
+            Some(Duration::from_millis(100)),
+            Some(Duration::from_millis(200)),
+            Some(Duration::from_millis(300)),
+            Some(Duration::from_millis(300)),
+            None,
+        ]
+    );
+}
+
+#[test]
+fn test_jitter() {
+    let policy = RetryPolicy::new().backoff(Duration::from_secs(1), Duration::from_secs(1));
+
+    for _ in 0..100 {
+        let delay = policy.retry_delay(1, &server_error(503), true).unwrap();
+        assert!(delay >= Duration::from_millis(500) && delay <= Duration::from_secs(1));
+    }
+}
+
+#[test]
+fn test_non_idempotent_requests() {
+    let policy = RetryPolicy::new().jitter(false);
+
+    // the request may have been processed before the gateway failed
+    assert_eq!(policy.retry_delay(1, &server_error(502), false), None);
+
+    // rate limited requests were rejected before they were processed
+    let rate_limited = RestError::RateLimited { retry_after: 2 };
+    assert_eq!(
+        policy.retry_delay(1, &rate_limited, false),
+        Some(Duration::from_secs(2))
+    );
+}
+
+#[test]
+fn test_custom_retry_condition() {
+    let policy = RetryPolicy::new()
+        .jitter(false)
+        .retry_if(|error| matches!(error, RestError::ApiError { status: 409, .. }));
+
+    assert!(policy.retry_delay(1, &server_error(409), true).is_some());
+    assert!(policy.retry_delay(1, &server_error(503), true).is_none());
+    assert!(RetryPolicy::none()
+        .retry_delay(1, &server_error(503), true)
+        .is_none());
+}
@@ -0,0 +1,204 @@
+//! Tests for the routes of the REST API
+
+use disruption::{RestClient, RestError, Route};
+use mockito::{Matcher, Server};
+use reqwest::Method;
+
+#[test]
+fn test_route_method_and_path() {
+    let route = Route::EditMessage {
+        channel_id: "1",
+        message_id: "2",
+    };
+    assert_eq!(route.name(), "EditMessage");
+    assert_eq!(route.method(), Method::PATCH);
+    assert_eq!(route.path(), "channels/1/messages/2");
+
+    let route = Route::GetCurrentUser {};
+    assert_eq!(route.method(), Method::GET);
+    assert_eq!(route.path(), "users/@me");
+
+    let route = Route::ModifyGuildMfaLevel { guild_id: "1" };
+    assert_eq!(route.method(), Method::POST);
+    assert_eq!(route.path(), "guilds/1/mfa");
+    assert!(route.supports_audit_log_reason());
+
+    let route = Route::EditApplicationCommandPermissions {
+        application_id: "1",
+        guild_id: "2",
+        command_id: "3",
+    };
+    assert_eq!(route.method(), Method::PUT);
+    assert_eq!(
+        route.path(),
+        "applications/1/guilds/2/commands/3/permissions"
+    );
+
+    let route = Route::GetGuildTemplate {
+        template_code: "hgM48av5Q69A",
+    };
+    assert_eq!(route.path(), "guilds/templates/hgM48av5Q69A");
+}
+
+#[test]
+fn test_route_query_and_encoding() {
+    let route = Route::GetChannelMessages {
+        channel_id: "1",
+        around: None,
+        before: Some("100"),
+        after: None,
+        limit: Some(50),
+    };
+    assert_eq!(route.path(), "channels/1/messages?before=100&limit=50");
+
+    let route = Route::GetReactions {
+        channel_id: "1",
+        message_id: "2",
+        emoji: "👍",
+        type_: Some(1),
+        after: None,
+        limit: None,
+    };
+    assert_eq!(
+        route.path(),
+        "channels/1/messages/2/reactions/%F0%9F%91%8D?type=1"
+    );
+
+    let route = Route::SearchGuildMembers {
+        guild_id: "1",
+        query: Some("a b&c"),
+        limit: None,
+    };
+    assert_eq!(route.path(), "guilds/1/members/search?query=a+b%26c");
+}
+
+#[test]
+fn test_route_rate_limit_bucket() {
+    let first = Route::CreateReaction {
+        channel_id: "1",
+        message_id: "2",
+        emoji: "👍",
+    };
+    let second = Route::CreateReaction {
+        channel_id: "1",
+        message_id: "3",
+        emoji: "name:4",
+    };
+    assert_eq!(first.name(), "CreateReaction");
+    assert_eq!(first.major_parameter(), "1");
+    assert_eq!(first.major_parameter(), second.major_parameter());
+
+    let route = Route::ExecuteWebhook {
+        webhook_id: "5",
+        webhook_token: "secret",
+        wait: Some(true),
+        thread_id: None,
+    };
+    assert_eq!(route.major_parameter(), "5/secret");
+    assert_eq!(route.path(), "webhooks/5/secret?wait=true");
+}
+
+#[test]
+fn test_route_flags() {
+    assert!(Route::DeleteMessage {
+        channel_id: "1",
+        message_id: "2"
+    }
+    .supports_audit_log_reason());
+    assert!(!Route::CreateMessage { channel_id: "1" }.supports_audit_log_reason());
+
+    let route = Route::CreateInteractionResponse {
+        interaction_id: "1",
+        interaction_token: "token",
+        with_response: None,
+    };
+    assert!(route.is_interaction());
+    assert!(!route.supports_audit_log_reason());
+    assert!(!Route::GetChannel { channel_id: "1" }.is_interaction());
+}
+
+#[tokio::test]
+async fn test_interaction_route_is_sent_without_bot_token() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/v10/interactions/1/token/callback")
+        .match_header("authorization", Matcher::Missing)
+        .with_status(204)
+        .create_async()
+        .await;
+    let deleted = server
+        .mock("DELETE", "/v10/channels/1/messages/2")
+        .match_header("authorization", "Bot token")
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let rest = RestClient::builder("token").base_url(server.url()).build();
+    rest.request_json(
+        Route::CreateInteractionResponse {
+            interaction_id: "1",
+            interaction_token: "token",
+            with_response: None,
+        },
+        &serde_json::json!({ "type": 1 }),
+    )
+    .await
+    .unwrap();
+    rest.request(Route::DeleteMessage {
+        channel_id: "1",
+        message_id: "2",
+    })
+    .await
+    .unwrap();
+
+    mock.assert_async().await;
+    deleted.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_audit_log_reason_is_sent_encoded() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("DELETE", "/v10/guilds/1/roles/2")
+        .match_header("x-audit-log-reason", "Spam%20in%20%23general%20%E2%9C%85")
+        .with_status(204)
+        .create_async()
+        .await;
+    let unsupported = server
+        .mock("POST", "/v10/channels/1/messages")
+        .match_header("x-audit-log-reason", Matcher::Missing)
+        .with_status(200)
+        .with_body("{}")
+        .create_async()
+        .await;
+
+    let rest = RestClient::builder("token").base_url(server.url()).build();
+    rest.delete_guild_role("1", "2", Some("Spam in #general ✅"))
+        .await
+        .unwrap();
+    rest.request_json_with_reason(
+        Route::CreateMessage { channel_id: "1" },
+        &serde_json::json!({ "content": "hi" }),
+        Some("ignored"),
+    )
+    .await
+    .unwrap();
+
+    mock.assert_async().await;
+    unsupported.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_audit_log_reason_is_validated() {
+    let rest = RestClient::builder("token")
+        .base_url("http://127.0.0.1:9")
+        .build();
+    let reason = "a".repeat(513);
+
+    let err = rest.delete_channel("1", Some(&reason)).await.unwrap_err();
+
+    assert!(matches!(
+        err,
+        RestError::Validation(errors) if errors.get("reason").is_some()
+    ));
+}